@@ -144,6 +144,7 @@ fn apply_effects_demo(
     mut commands: Commands,
     query: Query<(Entity, &Speed, &Health), With<Player>>,
     mut state: ResMut<DemoState>,
+    mut exit: MessageWriter<AppExit>,
 ) {
     state.frame += 1;
 
@@ -216,10 +217,11 @@ fn apply_effects_demo(
         // Frame 14: Apply -20% speed debuff
         14 => {
             println!("Frame {}: Applying -20% speed debuff", state.frame);
-            commands.trigger(ApplyStatusEffect {
-                effect: SpeedModifier(ValueModifier::Percent(-20.0)),
+            // Same as the struct form above
+            commands.trigger(ApplyStatusEffect::new(
                 entity,
-            });
+                SpeedModifier(ValueModifier::Percent(-20.0)),
+            ));
         }
         // Frame 16: Show final state
         16 => {
@@ -232,7 +234,7 @@ fn apply_effects_demo(
                 state.frame, health.current, health.max
             );
             println!("=== Demo Complete ===");
-            std::process::exit(0);
+            exit.write(AppExit::Success);
         }
         _ => {}
     }
@@ -0,0 +1,256 @@
+//! One message stream for all effect activity.
+//!
+//! Every application, removal, expiry and rejection, across all component and
+//! effect types, is written as a [`StatusEffectEvent`] message, with the
+//! generic types erased to their names. A single
+//! `MessageReader<StatusEffectEvent>` sees everything, e.g. for a combat log.
+//!
+//! Frequent effects such as per-frame drains can flood the stream. Components
+//! configured
+//! [`without_events`](crate::StatusEffectApplication::without_events) don't
+//! write any.
+
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+use crate::{AppliedEffect, MutableComponent, RejectReason, StatusEffectApplicator, ValueModifier};
+
+/// Type-erased description of one effect on one entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectActivity {
+    /// The entity the effect targeted
+    pub entity: Entity,
+    /// Type name of the targeted component
+    pub component: &'static str,
+    /// Type name of the effect
+    pub effect: Cow<'static, str>,
+    /// Modifier carried by the effect
+    pub modifier: ValueModifier,
+}
+
+impl EffectActivity {
+    /// Describes effect type `E` on component `C`.
+    pub(crate) fn of<C, E>(entity: Entity, modifier: ValueModifier) -> Self {
+        Self {
+            entity,
+            component: std::any::type_name::<C>(),
+            effect: Cow::Borrowed(std::any::type_name::<E>()),
+            modifier,
+        }
+    }
+
+    /// Describes a tracked entry on component `C`.
+    pub(crate) fn tracked<C>(entity: Entity, effect: &AppliedEffect) -> Self {
+        Self {
+            entity,
+            component: std::any::type_name::<C>(),
+            effect: effect.effect.clone(),
+            modifier: effect.modifier,
+        }
+    }
+
+    /// Returns true if this describes effect type `E`.
+    #[must_use]
+    pub fn is<E>(&self) -> bool {
+        self.effect == std::any::type_name::<E>()
+    }
+}
+
+/// Effect activity of any component and effect type.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn combat_log(mut events: MessageReader<StatusEffectEvent>) {
+///     for event in events.read() {
+///         let activity = event.activity();
+///         info!("{:?}: {} on {}", event, activity.effect, activity.entity);
+///     }
+/// }
+/// ```
+#[derive(Message, Clone, Debug, PartialEq)]
+pub enum StatusEffectEvent {
+    /// An effect was applied, with its modifier after scaling adjustments
+    Applied(EffectActivity),
+    /// A tracked effect was removed or dispelled
+    Removed(EffectActivity),
+    /// A [temporary](crate::StatusEffectCommandsExt::apply_temporary) effect
+    /// ran out
+    Expired(EffectActivity),
+    /// An application was rejected for a reason other than a lock or immunity
+    Rejected(EffectActivity, RejectReason),
+    /// An application was ignored because the component is
+    /// [locked](crate::StatLocked) or the entity is [immune](crate::Immunity)
+    Blocked(EffectActivity),
+}
+
+impl StatusEffectEvent {
+    /// Returns the effect this event is about.
+    #[must_use]
+    pub fn activity(&self) -> &EffectActivity {
+        match self {
+            Self::Applied(activity)
+            | Self::Removed(activity)
+            | Self::Expired(activity)
+            | Self::Rejected(activity, _)
+            | Self::Blocked(activity) => activity,
+        }
+    }
+}
+
+/// Passes a rejection to the effect's
+/// [`on_rejected`](StatusEffectApplicator::on_rejected) and the message stream.
+pub(crate) fn reject<C, E>(
+    effect: &E,
+    reason: RejectReason,
+    commands: &mut Commands,
+    entity: Entity,
+    events: Option<&mut Messages<StatusEffectEvent>>,
+) where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    effect.on_rejected(reason, commands, entity);
+    if let Some(events) = events {
+        let activity = EffectActivity::of::<C, E>(entity, effect.modifier());
+        events.write(match reason {
+            RejectReason::Immune => StatusEffectEvent::Blocked(activity),
+            reason => StatusEffectEvent::Rejected(activity, reason),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn max_stacks() -> Option<usize> {
+            Some(2)
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Drain(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Drain {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<StatusEffectEvent>);
+
+    fn collect(mut events: MessageReader<StatusEffectEvent>, mut log: ResMut<Log>) {
+        log.0.extend(events.read().cloned());
+    }
+
+    fn kinds(app: &App) -> Vec<&'static str> {
+        app.world()
+            .resource::<Log>()
+            .0
+            .iter()
+            .map(|event| match event {
+                StatusEffectEvent::Applied(_) => "applied",
+                StatusEffectEvent::Removed(_) => "removed",
+                StatusEffectEvent::Expired(_) => "expired",
+                StatusEffectEvent::Rejected(..) => "rejected",
+                StatusEffectEvent::Blocked(_) => "blocked",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn one_stream_sees_every_type() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::linear().tracked().lockable(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Health, Drain>::new(
+            StatusEffectApplication::linear().without_events(),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            500,
+        )));
+        app.init_resource::<Log>();
+        app.add_systems(Last, collect);
+        let entity = app.world_mut().spawn((Speed(100.0), Health(100.0))).id();
+        app.update();
+
+        let haste = Haste(ValueModifier::Val(10.0));
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, haste)
+            .apply_temporary(entity, haste, 0.5)
+            .apply_effect(entity, haste)
+            .apply_effect(entity, Drain(ValueModifier::Val(-1.0)));
+        app.update();
+        assert_eq!(kinds(&app), ["applied", "applied", "rejected"]);
+        let log = &app.world().resource::<Log>().0;
+        assert!(log[0].activity().is::<Haste>());
+        assert_eq!(log[0].activity().component, std::any::type_name::<Speed>());
+        assert_eq!(log[0].activity().entity, entity);
+        assert_eq!(
+            log[2],
+            StatusEffectEvent::Rejected(
+                EffectActivity::of::<Speed, Haste>(entity, haste.0),
+                RejectReason::StackCap
+            )
+        );
+
+        app.world_mut().resource_mut::<Log>().0.clear();
+        for _ in 0..2 {
+            app.update();
+        }
+        let mut commands = app.world_mut().commands();
+        commands.trigger(RemoveStatusEffect::<Haste>::new(entity));
+        commands.trigger(LockStatusEffect::<Speed>::new(entity));
+        app.update();
+        app.world_mut().commands().apply_effect(entity, haste);
+        app.update();
+        assert_eq!(kinds(&app), ["expired", "removed", "blocked"]);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+    }
+}
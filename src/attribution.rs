@@ -0,0 +1,153 @@
+//! Source entities for attribution.
+//!
+//! With the effect's plugin built
+//! [`with_attribution`](crate::StatusEffectPlugin::with_attribution),
+//! triggering [`ApplyStatusEffectFrom`] instead of [`ApplyStatusEffect`]
+//! applies the effect the same way, and the source entity is handed to
+//! [`StatusEffectApplicator::apply_from`](crate::StatusEffectApplicator::apply_from),
+//! e.g. for damage that depends on who dealt it.
+//! [`apply_effect_from`](crate::StatusEffectCommandsExt::apply_effect_from)
+//! attributes its applications to the source too.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{ApplyStatusEffect, with_override};
+
+/// Applies an effect to an entity on behalf of another.
+///
+/// Needs the effect's plugin built
+/// [`with_attribution`](crate::StatusEffectPlugin::with_attribution).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Burn(ValueModifier);
+///
+/// fn fireball_hit(mut commands: Commands, target: Entity, caster: Entity) {
+///     commands.trigger(ApplyStatusEffectFrom {
+///         effect: Burn(ValueModifier::Val(-12.0)),
+///         entity: target,
+///         source: caster,
+///     });
+/// }
+/// ```
+#[derive(EntityEvent, Clone)]
+pub struct ApplyStatusEffectFrom<E: Event + Clone> {
+    /// The effect to apply
+    pub effect: E,
+    /// The target entity
+    pub entity: Entity,
+    /// The entity the effect comes from
+    pub source: Entity,
+}
+
+/// Source entity of the application currently being triggered.
+///
+/// Only present for the duration of one trigger.
+#[derive(Resource)]
+pub(crate) struct SourceEntity(pub(crate) Entity);
+
+/// Triggers `event` attributed to `source`.
+pub(crate) fn trigger_from<E>(world: &mut World, event: ApplyStatusEffect<E>, source: Entity)
+where
+    E: Event + Clone,
+{
+    with_override(world, SourceEntity(source), |world| world.trigger(event));
+}
+
+fn apply_status_effect_from_observer<E: Event + Clone>(
+    on: On<ApplyStatusEffectFrom<E>>,
+    mut commands: Commands,
+) {
+    let ApplyStatusEffectFrom {
+        effect,
+        entity,
+        source,
+    } = on.event().clone();
+    commands.queue(move |world: &mut World| {
+        trigger_from(world, ApplyStatusEffect { effect, entity }, source);
+    });
+}
+
+/// Registers attributed applications of effect `E` once per app.
+pub(crate) struct AttributionPlugin<E: Event + Clone>(PhantomData<E>);
+
+impl<E: Event + Clone> Default for AttributionPlugin<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: Event + Clone> Plugin for AttributionPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(apply_status_effect_from_observer::<E>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Component)]
+    struct Nemesis;
+
+    /// Deals double damage when it comes from the target's nemesis
+    #[derive(Event, Clone, Copy)]
+    struct Damage(ValueModifier, Entity);
+
+    impl StatusEffectApplicator<Health> for Damage {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn apply_from(&self, component: &mut Health, power: f32, source: Option<Entity>) {
+            let modifier = if source == Some(self.1) {
+                self.0.scaled_by(2.0)
+            } else {
+                self.0
+            };
+            component.0 = modifier.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn source_is_visible_to_the_applicator() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Damage>::default().with_attribution());
+        let nemesis = app.world_mut().spawn(Nemesis).id();
+        let bystander = app.world_mut().spawn_empty().id();
+        let target = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+
+        let hit = Damage(ValueModifier::Val(-10.0), nemesis);
+        for source in [nemesis, bystander] {
+            app.world_mut().commands().trigger(ApplyStatusEffectFrom {
+                effect: hit,
+                entity: target,
+                source,
+            });
+        }
+        // Unattributed
+        app.world_mut().commands().apply_effect(target, hit);
+        app.update();
+        assert_eq!(app.world().get::<Health>(target).unwrap().0, 60.0);
+
+        // Attribution only lasts for its own application
+        assert!(app.world().get_resource::<SourceEntity>().is_none());
+    }
+}
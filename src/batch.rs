@@ -0,0 +1,195 @@
+//! Batched application of many effects at once.
+//!
+//! With [`StatusEffectBatchPlugin`] added, systems that hand out lots of
+//! effects in one frame can [plan](StatusEffectBatchPlan::plan_effect) them on
+//! the [`StatusEffectBatchPlan`] resource instead of going through [`Commands`].
+//! The plan is drained in [`PostUpdate`], triggering every planned
+//! application back to back and flushing once at the end. Anything planned
+//! after that drain is applied in [`Last`], so the plan is empty at the end of
+//! every frame.
+
+use bevy::prelude::*;
+
+use crate::ApplyStatusEffect;
+
+type ApplyFn = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Applications planned for the next drain.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Burn(ValueModifier);
+///
+/// fn meteor_storm(mut plan: ResMut<StatusEffectBatchPlan>, targets: Query<Entity, With<Name>>) {
+///     for target in &targets {
+///         plan.plan_effect(target, Burn(ValueModifier::Val(-25.0)));
+///     }
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct StatusEffectBatchPlan {
+    planned: Vec<(Entity, ApplyFn)>,
+}
+
+impl StatusEffectBatchPlan {
+    /// Plans `effect` for `entity`.
+    ///
+    /// Effects for entities despawned before the drain are dropped.
+    pub fn plan_effect<E: Event + Clone>(&mut self, entity: Entity, effect: E) -> &mut Self {
+        let apply: ApplyFn = Box::new(move |world: &mut World| {
+            if world.get_entity(entity).is_ok() {
+                world.trigger(ApplyStatusEffect { effect, entity });
+            }
+        });
+        self.planned.push((entity, apply));
+        self
+    }
+
+    /// Returns the number of planned applications.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.planned.len()
+    }
+
+    /// Returns true if nothing is planned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.planned.is_empty()
+    }
+
+    /// Drops every application planned for `entity`.
+    pub(crate) fn forget(&mut self, entity: Entity) {
+        self.planned.retain(|(planned, _)| *planned != entity);
+    }
+}
+
+/// Applies every planned effect and clears the plan.
+fn drain_status_effect_batch_plan(world: &mut World) {
+    let Some(mut plan) = world.get_resource_mut::<StatusEffectBatchPlan>() else {
+        return;
+    };
+    let planned = std::mem::take(&mut plan.planned);
+    if planned.is_empty() {
+        return;
+    }
+    for (_, apply) in planned {
+        apply(world);
+    }
+    world.flush();
+}
+
+/// Creates the [`StatusEffectBatchPlan`] and drains it every frame.
+///
+/// Not added by [`StatusEffectPlugin`](crate::StatusEffectPlugin); add it
+/// once to plan effects.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(StatusEffectBatchPlugin);
+/// ```
+pub struct StatusEffectBatchPlugin;
+
+impl Plugin for StatusEffectBatchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StatusEffectBatchPlan>();
+        app.add_systems(PostUpdate, drain_status_effect_batch_plan);
+        app.add_systems(Last, drain_status_effect_batch_plan);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Burn(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Burn {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Shatter(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for Shatter {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn planned_effects_apply_after_one_update() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Burn>::default());
+        app.add_plugins(StatusEffectPlugin::<Armor, Shatter>::default());
+        app.add_plugins(StatusEffectBatchPlugin);
+        let targets: Vec<Entity> = (0..3)
+            .map(|_| app.world_mut().spawn((Health(100.0), Armor(50.0))).id())
+            .collect();
+        let despawned = app.world_mut().spawn(Health(100.0)).id();
+
+        let mut plan = app.world_mut().resource_mut::<StatusEffectBatchPlan>();
+        for &target in &targets {
+            plan.plan_effect(target, Burn(ValueModifier::Val(-10.0)))
+                .plan_effect(target, Shatter(ValueModifier::Percent(-50.0)));
+        }
+        plan.plan_effect(despawned, Burn(ValueModifier::Val(-10.0)));
+        assert_eq!(plan.len(), 7);
+        app.world_mut().despawn(despawned);
+
+        app.update();
+        for &target in &targets {
+            assert_eq!(app.world().get::<Health>(target).unwrap().0, 90.0);
+            assert_eq!(app.world().get::<Armor>(target).unwrap().0, 25.0);
+        }
+        assert!(app.world().resource::<StatusEffectBatchPlan>().is_empty());
+    }
+
+    #[test]
+    fn effects_planned_after_the_drain_apply_the_same_frame() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Burn>::default());
+        app.add_plugins(StatusEffectBatchPlugin);
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        app.add_systems(
+            PostUpdate,
+            (move |mut plan: ResMut<StatusEffectBatchPlan>| {
+                plan.plan_effect(entity, Burn(ValueModifier::Val(-5.0)));
+            })
+            .after(drain_status_effect_batch_plan),
+        );
+
+        app.update();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 95.0);
+        assert!(app.world().resource::<StatusEffectBatchPlan>().is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+//! Per-effect breakdown of a tracked stat.
+//!
+//! [`stat_breakdown`] replays an entity's [`AppliedEffects<C>`] on their base
+//! one at a time and records what each entry added, e.g. for a character
+//! sheet listing "Speed 100, Haste +20, Slow -12".
+
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+use crate::{
+    AppliedEffects, MutableComponent, PercentSum, StatValue, StatusEffectApplication, ValueModifier,
+};
+
+/// What one tracked effect added to a stat.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectContribution {
+    /// Type name of the effect
+    pub effect: Cow<'static, str>,
+    /// Modifier carried by the effect, before any
+    /// [fading](crate::AppliedEffect::weight)
+    pub modifier: ValueModifier,
+    /// Change to the stat when the effect was replayed in order
+    pub contribution: f32,
+}
+
+impl EffectContribution {
+    /// Returns the effect's type name without its module path, for display.
+    #[must_use]
+    pub fn name(&self) -> ShortName<'_> {
+        ShortName(&self.effect)
+    }
+}
+
+/// A stat's base value, what each tracked effect added, and the result.
+///
+/// The contributions plus [`adjustment`](Self::adjustment) sum to
+/// `value - base`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatBreakdown {
+    /// Type name of the stat component
+    pub stat: &'static str,
+    /// Value before the first tracked effect
+    pub base: f32,
+    /// Tracked effects in application order
+    pub contributions: Vec<EffectContribution>,
+    /// Change made by the component's clamp and rounding
+    pub adjustment: f32,
+    /// Final stat value
+    pub value: f32,
+}
+
+/// Breaks down stat `C` on `entity` into its base and per-effect contributions.
+///
+/// Each contribution is measured on the value left by the effects before it,
+/// so a percent applied after a flat bonus also scales that bonus. Entities
+/// without tracked effects report their current value as the base. Returns
+/// `None` if the entity doesn't have `C`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// impl StatValue for Speed {
+///     fn stat_value(&self) -> f32 { self.0 }
+///     fn set_stat_value(&mut self, value: f32) { self.0 = value; }
+/// }
+///
+/// fn character_sheet(world: &World, player: Entity) {
+///     let Some(speed) = stat_breakdown::<Speed>(world, player) else {
+///         return;
+///     };
+///     info!("Base speed {}", speed.base);
+///     for entry in &speed.contributions {
+///         info!("  {} {:+}", entry.name(), entry.contribution);
+///     }
+///     info!("Speed {}", speed.value);
+/// }
+/// ```
+#[must_use]
+pub fn stat_breakdown<C: MutableComponent + StatValue>(
+    world: &World,
+    entity: Entity,
+) -> Option<StatBreakdown> {
+    let current = world.get::<C>(entity)?.stat_value();
+    let stat = std::any::type_name::<C>();
+    let config = world.get_resource::<StatusEffectApplication<C>>();
+    let applied = world
+        .get::<AppliedEffects<C>>(entity)
+        .zip(config)
+        .filter(|(applied, _)| !applied.is_empty());
+    let Some((applied, config)) = applied else {
+        return Some(StatBreakdown {
+            stat,
+            base: current,
+            contributions: Vec::new(),
+            adjustment: 0.0,
+            value: current,
+        });
+    };
+
+    let mut value = applied.base;
+    let mut adjustment = 0.0;
+    let contributions = applied
+        .effects
+        .iter()
+        .zip(config.replay_steps(applied, &mut PercentSum::default()))
+        .map(|(effect, (raw, finished))| {
+            let before = std::mem::replace(&mut value, finished);
+            adjustment += finished - raw;
+            EffectContribution {
+                effect: effect.effect.clone(),
+                modifier: effect.modifier,
+                contribution: raw - before,
+            }
+        })
+        .collect();
+    Some(StatBreakdown {
+        stat,
+        base: applied.base,
+        contributions,
+        adjustment,
+        value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Slow(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Slow {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn breakdown(config: StatusEffectApplication<Speed>, percent: f32) -> StatBreakdown {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(config.clone()));
+        app.add_plugins(StatusEffectPlugin::<Speed, Slow>::new(config));
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+        assert_eq!(
+            stat_breakdown::<Speed>(app.world(), entity).unwrap().value,
+            100.0
+        );
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Haste(ValueModifier::Val(20.0)))
+            .apply_effect(entity, Haste(ValueModifier::Percent(percent)))
+            .apply_effect(entity, Slow(ValueModifier::Val(-10.0)));
+        app.update();
+        let breakdown = stat_breakdown::<Speed>(app.world(), entity).unwrap();
+        assert_eq!(breakdown.value, app.world().get::<Speed>(entity).unwrap().0);
+        breakdown
+    }
+
+    #[test]
+    fn lists_each_effect_in_order() {
+        let breakdown = breakdown(StatusEffectApplication::linear().tracked(), 50.0);
+
+        assert_eq!(breakdown.stat, std::any::type_name::<Speed>());
+        assert_eq!(breakdown.base, 100.0);
+        assert_eq!(breakdown.value, 170.0);
+        assert_eq!(breakdown.adjustment, 0.0);
+        let entries: Vec<_> = breakdown
+            .contributions
+            .iter()
+            .map(|entry| (entry.name().to_string(), entry.modifier, entry.contribution))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                ("Haste".to_string(), ValueModifier::Val(20.0), 20.0),
+                ("Haste".to_string(), ValueModifier::Percent(50.0), 60.0),
+                ("Slow".to_string(), ValueModifier::Val(-10.0), -10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rounding_shows_as_adjustment() {
+        let breakdown = breakdown(StatusEffectApplication::linear().tracked().rounded(), 33.0);
+
+        // 120 * 1.33 - 10 = 149.6, rounded to 150
+        assert_eq!(breakdown.value, 150.0);
+        let sum: f32 = breakdown
+            .contributions
+            .iter()
+            .map(|entry| entry.contribution)
+            .sum();
+        assert!((sum - 49.6).abs() < 0.001);
+        assert!((breakdown.adjustment - 0.4).abs() < 0.001);
+        assert!((breakdown.base + sum + breakdown.adjustment - breakdown.value).abs() < 0.001);
+    }
+}
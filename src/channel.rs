@@ -0,0 +1,186 @@
+//! Thread-safe effect submission for code outside the ECS.
+//!
+//! With [`StatusEffectChannelPlugin`] added, scripting VMs, network threads
+//! and other callers without [`Commands`] can clone the [`StatusEffectSender`]
+//! resource and push effects into it by type name. A system drains the
+//! channel at the start of every frame and applies
+//! the effects through the [registry](crate::StatusEffectRegistry), so effect
+//! types must implement
+//! [`from_modifier`](crate::StatusEffectApplicator::from_modifier).
+
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{StatusEffectRegistry, ValueModifier};
+
+/// An effect named by its type, for callers that can't name the Rust type.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SerializableEffect {
+    /// Type name of the effect, as in [`std::any::type_name`]
+    pub effect: String,
+    /// Modifier the effect is built from
+    pub modifier: ValueModifier,
+}
+
+impl SerializableEffect {
+    /// Describes effect type `E` built from `modifier`.
+    #[must_use]
+    pub fn of<E>(modifier: ValueModifier) -> Self {
+        Self {
+            effect: std::any::type_name::<E>().to_string(),
+            modifier,
+        }
+    }
+}
+
+/// Sending half of the effect channel.
+///
+/// Clone it out of the world and hand it to other threads.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Burn(ValueModifier);
+///
+/// fn start_script_vm(sender: Res<StatusEffectSender>, player: Single<Entity, With<Name>>) {
+///     let sender = sender.clone();
+///     let player = *player;
+///     std::thread::spawn(move || {
+///         sender.send(player, SerializableEffect::of::<Burn>(ValueModifier::Val(-5.0)));
+///     });
+/// }
+/// ```
+#[derive(Resource, Clone)]
+pub struct StatusEffectSender(Sender<(Entity, SerializableEffect)>);
+
+impl StatusEffectSender {
+    /// Queues `effect` for `entity`, applied at the start of the next frame.
+    ///
+    /// Returns false if the app has shut down.
+    pub fn send(&self, entity: Entity, effect: SerializableEffect) -> bool {
+        self.0.send((entity, effect)).is_ok()
+    }
+}
+
+/// Receiving half of the effect channel.
+#[derive(Resource)]
+struct StatusEffectReceiver(Mutex<Receiver<(Entity, SerializableEffect)>>);
+
+/// Applies every effect sent since the last drain.
+///
+/// Effects for despawned entities are dropped; unregistered or
+/// non-rebuildable effect types are dropped with a warning.
+fn drain_status_effect_channel(world: &mut World) {
+    let received: Vec<_> = {
+        let Some(receiver) = world.get_resource::<StatusEffectReceiver>() else {
+            return;
+        };
+        let Ok(receiver) = receiver.0.lock() else {
+            return;
+        };
+        receiver.try_iter().collect()
+    };
+    for (entity, effect) in received {
+        if world.get_entity(entity).is_err() {
+            continue;
+        }
+        let apply = world
+            .get_resource::<StatusEffectRegistry>()
+            .and_then(|registry| registry.apply_modifier_fn(&effect.effect));
+        strict_assert!(
+            apply.is_some(),
+            "Channel effect {} is not registered",
+            effect.effect
+        );
+        if !apply.is_some_and(|apply| apply(world, entity, effect.modifier)) {
+            log_warn!(
+                "Dropping {} from channel: effect is not registered or not rebuildable",
+                effect.effect
+            );
+            continue;
+        }
+        world.flush();
+    }
+}
+
+/// Creates the effect channel and drains it in [`PreUpdate`].
+///
+/// Not added by [`StatusEffectPlugin`](crate::StatusEffectPlugin); add it
+/// once to get a [`StatusEffectSender`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(StatusEffectChannelPlugin);
+/// ```
+pub struct StatusEffectChannelPlugin;
+
+impl Plugin for StatusEffectChannelPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = mpsc::channel();
+        app.insert_resource(StatusEffectSender(sender));
+        app.insert_resource(StatusEffectReceiver(Mutex::new(receiver)));
+        app.add_systems(PreUpdate, drain_status_effect_channel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Burn(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Burn {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    #[test]
+    fn effects_sent_from_another_thread_apply_next_update() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Burn>::default());
+        app.add_plugins(StatusEffectChannelPlugin);
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        let despawned = app.world_mut().spawn(Health(100.0)).id();
+        app.world_mut().despawn(despawned);
+
+        let sender = app.world().resource::<StatusEffectSender>().clone();
+        std::thread::spawn(move || {
+            let burn = SerializableEffect::of::<Burn>(ValueModifier::Val(-15.0));
+            assert!(sender.send(despawned, burn.clone()));
+            assert!(sender.send(entity, burn));
+        })
+        .join()
+        .unwrap();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 100.0);
+
+        app.update();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 85.0);
+    }
+}
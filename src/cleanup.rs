@@ -0,0 +1,269 @@
+//! Despawn cleanup of central effect state.
+//!
+//! Per-entity state such as [`AppliedEffects`](crate::AppliedEffects) or
+//! [`TemporaryEffects`](crate::TemporaryEffects) lives in components and goes
+//! away with the entity. Applications waiting in resources don't:
+//! [`StatusEffectCleanupPlugin`] drops the despawned entity's entries from the
+//! [`StatusEffectQueue`], the [`StatusEffectThrottle`] backlog and the
+//! [`StatusEffectBatchPlan`] as soon as it's despawned. The
+//! [`StatusEffectRecorder`](crate::StatusEffectRecorder) log is history and
+//! keeps its entries.
+//!
+//! Every component with a [`StatusEffectPlugin`](crate::StatusEffectPlugin)
+//! requires a private marker that the plugin's single observer watches, so it
+//! runs once per despawned entity however many components the entity has.
+//! Entities queued or backlogged before they have any such component, waiting
+//! for an auto-insert, get the marker when the entry is made.
+
+use bevy::prelude::*;
+
+use crate::{StatusEffectBatchPlan, StatusEffectQueue, StatusEffectThrottle};
+
+/// Marks entities whose central effect state is cleared on despawn.
+#[derive(Component, Default)]
+pub(crate) struct DespawnCleanup;
+
+/// Requires [`DespawnCleanup`] on every entity with component `C`.
+pub(crate) fn require_cleanup<C: Component>(app: &mut App) {
+    // Fails only when another effect on `C` already registered it
+    let _ = app.try_register_required_components::<C, DespawnCleanup>();
+}
+
+/// Marks `entity` for cleanup when it has no component requiring the marker
+/// yet, so a queued or backlogged entry waiting for an auto-insert is still
+/// forgotten on despawn.
+pub(crate) fn watch(commands: &mut Commands, entity: Entity) {
+    if let Ok(mut entity) = commands.get_entity(entity) {
+        entity.try_insert_if_new(DespawnCleanup);
+    }
+}
+
+/// Present once the despawn observer is registered.
+#[derive(Resource, Default)]
+struct CleanupObserver;
+
+/// Drops the despawned entity's entries from the central resources.
+fn clear_despawned(
+    on: On<Despawn, DespawnCleanup>,
+    queue: Option<ResMut<StatusEffectQueue>>,
+    throttle: Option<ResMut<StatusEffectThrottle>>,
+    plan: Option<ResMut<StatusEffectBatchPlan>>,
+) {
+    let entity = on.entity;
+    if let Some(mut queue) = queue {
+        queue.forget(entity);
+    }
+    if let Some(mut throttle) = throttle {
+        throttle.forget(entity);
+    }
+    if let Some(mut plan) = plan {
+        plan.forget(entity);
+    }
+}
+
+/// Clears central effect state of despawned entities.
+///
+/// Added by every [`StatusEffectPlugin`](crate::StatusEffectPlugin). Adding it
+/// yourself as well, before or after them, is harmless: the observer is only
+/// registered once.
+///
+/// Batch plan entries for entities that never got the marker are skipped by
+/// the drain instead.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(StatusEffectCleanupPlugin);
+/// ```
+pub struct StatusEffectCleanupPlugin;
+
+impl Plugin for StatusEffectCleanupPlugin {
+    fn build(&self, app: &mut App) {
+        if app.world().contains_resource::<CleanupObserver>() {
+            return;
+        }
+        app.init_resource::<CleanupObserver>();
+        app.add_observer(clear_despawned);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LastAppliedFrame;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    impl StatValue for Health {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Burn(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Burn {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Slow(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Slow {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn despawn_clears_central_state() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Burn>::new(
+            StatusEffectApplication::linear()
+                .tracked()
+                .deferred()
+                .once_per_frame(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Speed, Slow>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(StatusEffectBatchPlugin);
+        let doomed = app.world_mut().spawn((Health(100.0), Speed(10.0))).id();
+        let survivor = app.world_mut().spawn((Health(100.0), Speed(10.0))).id();
+        app.update();
+
+        // Tracked
+        app.world_mut()
+            .commands()
+            .apply_effect(doomed, Slow(ValueModifier::Percent(-50.0)));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(doomed).unwrap().0, 5.0);
+
+        // Scheduled for the drain, backlogged and planned, with a cooldown
+        app.insert_resource(StatusEffectThrottle::new(2));
+        let burn = Burn(ValueModifier::Val(-10.0));
+        for entity in [doomed, survivor, doomed] {
+            app.world_mut().trigger(ApplyStatusEffect {
+                effect: burn,
+                entity,
+            });
+        }
+        app.world_mut().flush();
+        let mut plan = app.world_mut().resource_mut::<StatusEffectBatchPlan>();
+        plan.plan_effect(doomed, burn).plan_effect(survivor, burn);
+        assert!(app.world().get::<LastAppliedFrame<Burn>>(doomed).is_some());
+        let queue = app.world().resource::<StatusEffectQueue>();
+        assert_eq!(queue.pending_effects(doomed).len(), 1);
+        assert_eq!(app.world().resource::<StatusEffectThrottle>().len(), 1);
+
+        app.world_mut().despawn(doomed);
+        let queue = app.world().resource::<StatusEffectQueue>();
+        assert!(queue.pending_effects(doomed).is_empty());
+        assert_eq!(queue.pending_effects(survivor).len(), 1);
+        assert!(app.world().resource::<StatusEffectThrottle>().is_empty());
+        assert_eq!(app.world().resource::<StatusEffectBatchPlan>().len(), 1);
+
+        // One observer for both component plugins
+        assert_eq!(cleanup_observers(&mut app), 1);
+
+        app.update();
+        assert!(app.world().get::<Health>(survivor).unwrap().0 < 100.0);
+    }
+
+    fn cleanup_observers(app: &mut App) -> usize {
+        let marker = app.world().component_id::<DespawnCleanup>().unwrap();
+        app.world_mut()
+            .query::<&bevy::ecs::observer::Observer>()
+            .iter(app.world())
+            .filter(|observer| observer.descriptor().components().contains(&marker))
+            .count()
+    }
+
+    #[test]
+    fn adding_the_plugin_after_effect_plugins_is_harmless() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Burn>::default());
+        app.add_plugins(StatusEffectCleanupPlugin);
+        app.add_plugins(StatusEffectPlugin::<Speed, Slow>::default());
+        app.update();
+        assert_eq!(cleanup_observers(&mut app), 1);
+    }
+
+    #[test]
+    fn despawn_clears_entries_waiting_for_auto_insert() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Burn>::new(
+            StatusEffectApplication::linear().deferred(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Speed, Slow>::default());
+        let survivor = app.world_mut().spawn(Speed(10.0)).id();
+        let queued = app.world_mut().spawn_empty().id();
+        let backlogged = app.world_mut().spawn_empty().id();
+        app.update();
+
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: Burn(ValueModifier::Val(-10.0)),
+            entity: queued,
+        });
+        app.insert_resource(StatusEffectThrottle::new(1));
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: Slow(ValueModifier::Val(-1.0)),
+            entity: survivor,
+        });
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: Slow(ValueModifier::Val(-1.0)),
+            entity: backlogged,
+        });
+        app.world_mut().flush();
+        let queue = app.world().resource::<StatusEffectQueue>();
+        assert_eq!(queue.pending_effects(queued).len(), 1);
+        assert_eq!(app.world().resource::<StatusEffectThrottle>().len(), 1);
+
+        app.world_mut().despawn(queued);
+        app.world_mut().despawn(backlogged);
+        let queue = app.world().resource::<StatusEffectQueue>();
+        assert!(queue.pending_effects(queued).is_empty());
+        assert!(app.world().resource::<StatusEffectThrottle>().is_empty());
+    }
+}
@@ -0,0 +1,624 @@
+//! Command extensions for applying status effects.
+
+use std::borrow::Cow;
+
+use bevy::ecs::bundle::Bundle;
+use bevy::ecs::observer::On;
+use bevy::prelude::*;
+
+use crate::{
+    ApplyStatusEffect, CrossStatApplicator, MutableComponent, RemoveEffectEverywhere,
+    RemoveItemStats, StatGrant, StatValue, StatusEffectApplicator, attribution, item, mirror,
+    temporary, tracking, trigger_with_power,
+};
+
+/// Status effect helpers on [`Commands`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct SpeedModifier(ValueModifier);
+///
+/// fn haste(mut commands: Commands, player: Single<Entity, With<Name>>) {
+///     commands.apply_effect(*player, SpeedModifier(ValueModifier::Percent(30.0)));
+/// }
+/// ```
+pub trait StatusEffectCommandsExt {
+    /// Triggers [`ApplyStatusEffect`] for `effect` on `entity`.
+    fn apply_effect<E: Event + Clone>(&mut self, entity: Entity, effect: E) -> &mut Self;
+
+    /// Applies `effect` to the target of the event an observer is handling.
+    ///
+    /// For entity observers the target is the observer's own entity, so this
+    /// applies the effect to "whoever was hit".
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(EntityEvent)]
+    /// struct Hit {
+    ///     entity: Entity,
+    /// }
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Stagger(ValueModifier);
+    ///
+    /// fn stagger_on_hit(on: On<Hit>, mut commands: Commands) {
+    ///     commands.apply_effect_to_target(&on, Stagger(ValueModifier::Percent(-30.0)));
+    /// }
+    /// ```
+    fn apply_effect_to_target<T: EntityEvent, B: Bundle, E: Event + Clone>(
+        &mut self,
+        on: &On<T, B>,
+        effect: E,
+    ) -> &mut Self;
+
+    /// Applies `effect` to `entity` on behalf of `source`, scaled by the
+    /// source's stat `S`.
+    ///
+    /// The modifier comes from
+    /// [`modifier_from_source`](StatusEffectApplicator::modifier_from_source)
+    /// and the effect is rebuilt with
+    /// [`from_modifier`](StatusEffectApplicator::from_modifier). If the source
+    /// or its `S` is missing, or the effect can't be rebuilt, the effect
+    /// applies with its base modifier. Either way, the application is
+    /// attributed to `source`, as with
+    /// [`ApplyStatusEffectFrom`](crate::ApplyStatusEffectFrom).
+    fn apply_effect_from<C, S, E>(
+        &mut self,
+        source: Entity,
+        entity: Entity,
+        effect: E,
+    ) -> &mut Self
+    where
+        C: MutableComponent,
+        S: Component + StatValue,
+        E: StatusEffectApplicator<C>;
+
+    /// Applies `effect` to `entity` with `power` instead of the component's
+    /// configured power.
+    ///
+    /// Only this application is affected; the stored
+    /// [config](crate::StatusEffectApplication) is left as it is.
+    fn apply_effect_scaled<E: Event + Clone>(
+        &mut self,
+        entity: Entity,
+        effect: E,
+        power: f32,
+    ) -> &mut Self;
+
+    /// Applies `fraction` of `entity`'s stat `S` to another of its stats
+    /// through a [`CrossStatApplicator<S>`].
+    ///
+    /// `S` is read when the command runs. Without it, the effect contributes
+    /// zero.
+    fn apply_cross_stat<S: Component + StatValue>(
+        &mut self,
+        entity: Entity,
+        fraction: f32,
+    ) -> &mut Self;
+
+    /// Applies `effect` to `entity` now and removes it again after `duration`
+    /// seconds.
+    ///
+    /// Removal takes the tracked stack this application created off the
+    /// entity, leaving other stacks of `E` alone, so the component must be
+    /// [tracked](crate::StatusEffectApplication::tracked). Despawning the
+    /// entity cancels the removal.
+    fn apply_temporary<E: Event + Clone>(
+        &mut self,
+        entity: Entity,
+        effect: E,
+        duration: f32,
+    ) -> &mut Self;
+
+    /// Like [`apply_temporary`](Self::apply_temporary), but the effect
+    /// weakens as it runs out, scaled by its remaining fraction of `duration`.
+    ///
+    /// The stat is rebuilt every frame while the effect fades, and the effect
+    /// contributes nothing on its last frame. Needs a
+    /// [tracked](crate::StatusEffectApplication::tracked) component.
+    fn apply_fading<E: Event + Clone>(
+        &mut self,
+        entity: Entity,
+        effect: E,
+        duration: f32,
+    ) -> &mut Self;
+
+    /// Pauses the countdowns of `entity`'s [temporary](Self::apply_temporary)
+    /// effects of type `E`.
+    ///
+    /// The effects stay applied until [resumed](Self::resume_effect) and run
+    /// out. Does nothing if none are pending, e.g. because they already expired.
+    /// [`TimedStatusEffect`](crate::TimedStatusEffect)s on untracked components
+    /// aren't temporary effects and keep counting down.
+    fn pause_effect<E: Event>(&mut self, entity: Entity) -> &mut Self;
+
+    /// Resumes countdowns paused with [`pause_effect`](Self::pause_effect).
+    ///
+    /// Like it, only reaches temporary effects, not the
+    /// [`TimedEffects`](crate::TimedEffects) of untracked components.
+    fn resume_effect<E: Event>(&mut self, entity: Entity) -> &mut Self;
+
+    /// Removes tracked effects of type `E` from every entity and rebuilds
+    /// their stats from base.
+    ///
+    /// Only entities with [`AppliedEffects`](crate::AppliedEffects) are visited.
+    fn remove_effect_everywhere<E: Event>(&mut self) -> &mut Self;
+
+    /// Applies equivalents of `from`'s tracked effects to `to`.
+    ///
+    /// Each entry is rebuilt through the
+    /// [registry](crate::StatusEffectRegistry) from its current, possibly
+    /// [faded](crate::AppliedEffect::weight), modifier and applied with the
+    /// power it originally overrode, if any. Effects that can't be rebuilt,
+    /// because their type lacks
+    /// [`from_modifier`](StatusEffectApplicator::from_modifier), are skipped.
+    /// Timers, reasons and item sources aren't copied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// fn mimic(mut commands: Commands, caster: Entity, target: Entity) {
+    ///     // Copy the target's buffs onto the caster
+    ///     commands.copy_effects(target, caster);
+    /// }
+    /// ```
+    fn copy_effects(&mut self, from: Entity, to: Entity) -> &mut Self;
+
+    /// Removes every tracked effect from `entity` and sets its stats back to
+    /// their bases, across all [tracked](crate::StatusEffectApplication::tracked)
+    /// components.
+    ///
+    /// Components are looked up in the [registry](crate::StatusEffectRegistry),
+    /// so stats that aren't tracked are left as they are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// fn respawn(mut commands: Commands, player: Single<Entity, With<Name>>) {
+    ///     commands.reset_all_stats(*player);
+    /// }
+    /// ```
+    fn reset_all_stats(&mut self, entity: Entity) -> &mut Self;
+
+    /// Applies an item's `grants` to `entity`, tagged with `item` as their
+    /// [`source`](crate::AppliedEffect::source).
+    ///
+    /// Stats the same item granted before are removed first, so re-equipping
+    /// replaces rather than stacks. Effect types are looked up in the
+    /// [registry](crate::StatusEffectRegistry) and must implement
+    /// [`from_modifier`](StatusEffectApplicator::from_modifier).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct SpeedModifier(ValueModifier);
+    ///
+    /// fn equip_boots(mut commands: Commands, player: Single<Entity, With<Name>>) {
+    ///     let grants = [StatGrant::of::<SpeedModifier>(ValueModifier::Percent(20.0))];
+    ///     commands.apply_item_stats(*player, "boots_of_speed", &grants);
+    /// }
+    /// ```
+    fn apply_item_stats(
+        &mut self,
+        entity: Entity,
+        item: impl Into<Cow<'static, str>>,
+        grants: &[StatGrant],
+    ) -> &mut Self;
+
+    /// Removes the tracked effects [granted](Self::apply_item_stats) by `item`
+    /// from `entity` and rebuilds their stats from base.
+    fn remove_item_stats(
+        &mut self,
+        entity: Entity,
+        item: impl Into<Cow<'static, str>>,
+    ) -> &mut Self;
+}
+
+impl StatusEffectCommandsExt for Commands<'_, '_> {
+    fn apply_effect<E: Event + Clone>(&mut self, entity: Entity, effect: E) -> &mut Self {
+        self.trigger(ApplyStatusEffect { effect, entity });
+        self
+    }
+
+    fn apply_effect_to_target<T: EntityEvent, B: Bundle, E: Event + Clone>(
+        &mut self,
+        on: &On<T, B>,
+        effect: E,
+    ) -> &mut Self {
+        self.apply_effect(on.event_target(), effect)
+    }
+
+    fn apply_effect_from<C, S, E>(&mut self, source: Entity, entity: Entity, effect: E) -> &mut Self
+    where
+        C: MutableComponent,
+        S: Component + StatValue,
+        E: StatusEffectApplicator<C>,
+    {
+        self.queue(move |world: &mut World| {
+            let effect = world
+                .get::<S>(source)
+                .and_then(|stat| E::from_modifier(effect.modifier_from_source(stat.stat_value())))
+                .unwrap_or(effect);
+            attribution::trigger_from(world, ApplyStatusEffect { effect, entity }, source);
+        });
+        self
+    }
+
+    fn apply_effect_scaled<E: Event + Clone>(
+        &mut self,
+        entity: Entity,
+        effect: E,
+        power: f32,
+    ) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            trigger_with_power(world, ApplyStatusEffect { effect, entity }, power);
+        });
+        self
+    }
+
+    fn apply_cross_stat<S: Component + StatValue>(
+        &mut self,
+        entity: Entity,
+        fraction: f32,
+    ) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            let source = world.get::<S>(entity).map_or(0.0, S::stat_value);
+            world.trigger(ApplyStatusEffect {
+                effect: CrossStatApplicator::<S>::new(fraction, source),
+                entity,
+            });
+        });
+        self
+    }
+
+    fn apply_temporary<E: Event + Clone>(
+        &mut self,
+        entity: Entity,
+        effect: E,
+        duration: f32,
+    ) -> &mut Self {
+        let expiry = temporary::next_expiry();
+        self.queue(move |world: &mut World| {
+            temporary::trigger_expiring(world, ApplyStatusEffect { effect, entity }, expiry);
+        });
+        if let Ok(mut entity_commands) = self.get_entity(entity) {
+            entity_commands.queue(temporary::schedule_removal::<E>(duration, false, expiry));
+        }
+        self
+    }
+
+    fn apply_fading<E: Event + Clone>(
+        &mut self,
+        entity: Entity,
+        effect: E,
+        duration: f32,
+    ) -> &mut Self {
+        let expiry = temporary::next_expiry();
+        self.queue(move |world: &mut World| {
+            temporary::trigger_expiring(world, ApplyStatusEffect { effect, entity }, expiry);
+        });
+        if let Ok(mut entity_commands) = self.get_entity(entity) {
+            entity_commands.queue(temporary::schedule_removal::<E>(duration, true, expiry));
+        }
+        self
+    }
+
+    fn pause_effect<E: Event>(&mut self, entity: Entity) -> &mut Self {
+        if let Ok(mut entity_commands) = self.get_entity(entity) {
+            entity_commands.queue_silenced(temporary::set_paused::<E>(true));
+        }
+        self
+    }
+
+    fn resume_effect<E: Event>(&mut self, entity: Entity) -> &mut Self {
+        if let Ok(mut entity_commands) = self.get_entity(entity) {
+            entity_commands.queue_silenced(temporary::set_paused::<E>(false));
+        }
+        self
+    }
+
+    fn remove_effect_everywhere<E: Event>(&mut self) -> &mut Self {
+        self.trigger(RemoveEffectEverywhere::<E>::default());
+        self
+    }
+
+    fn copy_effects(&mut self, from: Entity, to: Entity) -> &mut Self {
+        self.queue(move |world: &mut World| mirror::copy_effects(world, from, to));
+        self
+    }
+
+    fn reset_all_stats(&mut self, entity: Entity) -> &mut Self {
+        self.queue(move |world: &mut World| tracking::reset_all_stats(world, entity));
+        self
+    }
+
+    fn apply_item_stats(
+        &mut self,
+        entity: Entity,
+        item: impl Into<Cow<'static, str>>,
+        grants: &[StatGrant],
+    ) -> &mut Self {
+        let item = item.into();
+        let grants = grants.to_vec();
+        self.queue(move |world: &mut World| {
+            item::apply_item_stats(world, entity, item, &grants);
+        });
+        self
+    }
+
+    fn remove_item_stats(
+        &mut self,
+        entity: Entity,
+        item: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.trigger(RemoveItemStats::new(entity, item));
+        self
+    }
+}
+
+/// Status effect helpers on [`EntityCommands`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct SpeedModifier(ValueModifier);
+///
+/// fn spawn_slowed_enemy(mut commands: Commands) {
+///     commands
+///         .spawn(Enemy)
+///         .apply_effect(SpeedModifier(ValueModifier::Percent(-20.0)))
+///         .insert(Name::new("Slowed enemy"));
+/// }
+/// ```
+pub trait StatusEffectEntityCommandsExt {
+    /// Triggers [`ApplyStatusEffect`] for `effect` on this entity.
+    fn apply_effect<E: Event + Clone>(&mut self, effect: E) -> &mut Self;
+}
+
+impl StatusEffectEntityCommandsExt for EntityCommands<'_> {
+    fn apply_effect<E: Event + Clone>(&mut self, effect: E) -> &mut Self {
+        let entity = self.id();
+        self.commands().apply_effect(entity, effect);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default, Clone)]
+    struct Speed(f32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::default());
+        app
+    }
+
+    #[test]
+    fn commands_apply_effect() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(10.0)))
+            .apply_effect(entity, SpeedEffect(ValueModifier::Percent(50.0)));
+        app.update();
+
+        assert!((app.world().get::<Speed>(entity).unwrap().0 - 165.0).abs() < 0.001);
+    }
+
+    #[derive(Component)]
+    struct Attack(f32);
+
+    impl StatValue for Attack {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    /// Slow authored for 100 attack
+    #[derive(Event, Clone, Copy)]
+    struct Frostbolt(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Frostbolt {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+
+        fn modifier_from_source(&self, source_stat: f32) -> ValueModifier {
+            self.0.scaled_by(source_stat / 100.0)
+        }
+    }
+
+    #[test]
+    fn commands_apply_effect_from_scales_by_source_stat() {
+        let mut app = app();
+        app.add_plugins(StatusEffectPlugin::<Speed, Frostbolt>::default());
+        let strong = app.world_mut().spawn(Attack(200.0)).id();
+        let weak = app.world_mut().spawn(Attack(100.0)).id();
+        let unarmed = app.world_mut().spawn_empty().id();
+        let targets: Vec<Entity> = (0..3)
+            .map(|_| app.world_mut().spawn(Speed(100.0)).id())
+            .collect();
+
+        let bolt = Frostbolt(ValueModifier::Val(-10.0));
+        app.world_mut()
+            .commands()
+            .apply_effect_from::<Speed, Attack, _>(strong, targets[0], bolt)
+            .apply_effect_from::<Speed, Attack, _>(weak, targets[1], bolt)
+            .apply_effect_from::<Speed, Attack, _>(unarmed, targets[2], bolt);
+        app.update();
+
+        let speed = |entity| app.world().get::<Speed>(entity).unwrap().0;
+        assert_eq!(speed(targets[0]), 80.0);
+        assert_eq!(speed(targets[1]), 90.0);
+        // No attack to scale by: base modifier
+        assert_eq!(speed(targets[2]), 90.0);
+    }
+
+    #[derive(EntityEvent)]
+    struct Hit {
+        entity: Entity,
+    }
+
+    #[test]
+    fn commands_apply_effect_to_target_in_entity_observer() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        let bystander = app.world_mut().spawn(Speed(100.0)).id();
+        app.world_mut()
+            .entity_mut(entity)
+            .observe(|on: On<Hit>, mut commands: Commands| {
+                commands.apply_effect_to_target(&on, SpeedEffect(ValueModifier::Percent(-50.0)));
+            });
+        app.update();
+
+        app.world_mut().trigger(Hit { entity });
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 50.0);
+        assert_eq!(app.world().get::<Speed>(bystander).unwrap().0, 100.0);
+    }
+
+    #[test]
+    fn commands_apply_effect_scaled_overrides_power_once() {
+        let mut app = app();
+        let linear = app.world_mut().spawn(Speed(40.0)).id();
+        let sqrt = app.world_mut().spawn(Speed(40.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect_scaled(
+                linear,
+                SpeedEffect(ValueModifier::Val(30.0)),
+                scaling::LINEAR,
+            )
+            .apply_effect_scaled(sqrt, SpeedEffect(ValueModifier::Val(30.0)), scaling::SQRT);
+        app.update();
+        assert_eq!(app.world().get::<Speed>(linear).unwrap().0, 70.0);
+        // sqrt(40^2 + 30^2)
+        assert_eq!(app.world().get::<Speed>(sqrt).unwrap().0, 50.0);
+
+        // The stored config is untouched
+        let config = app.world().resource::<StatusEffectApplication<Speed>>();
+        assert_eq!(config.power, scaling::LINEAR);
+        app.world_mut()
+            .commands()
+            .apply_effect(sqrt, SpeedEffect(ValueModifier::Val(30.0)));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(sqrt).unwrap().0, 80.0);
+    }
+
+    #[test]
+    fn commands_apply_effect_scaled_keeps_power_through_auto_insert() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().explicit_auto_insert(),
+        ));
+        app.insert_resource(AutoInsert::new(Speed(100.0)));
+        let present = app.world_mut().spawn(Speed(100.0)).id();
+        let missing = app.world_mut().spawn(Marker).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect_scaled(present, SpeedEffect(ValueModifier::Percent(300.0)), 0.5)
+            .apply_effect_scaled(missing, SpeedEffect(ValueModifier::Percent(300.0)), 0.5);
+        app.update();
+
+        // 100 * 4^0.5 either way, not 100 * 4 for the auto-inserted one
+        assert_eq!(app.world().get::<Speed>(present).unwrap().0, 200.0);
+        assert_eq!(app.world().get::<Speed>(missing).unwrap().0, 200.0);
+    }
+
+    #[test]
+    fn entity_commands_apply_effect_chains() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .entity(entity)
+            .apply_effect(SpeedEffect(ValueModifier::Val(20.0)))
+            .insert(Marker)
+            .apply_effect(SpeedEffect(ValueModifier::Percent(-50.0)));
+        app.update();
+
+        assert!(app.world().get::<Marker>(entity).is_some());
+        assert!((app.world().get::<Speed>(entity).unwrap().0 - 60.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn entity_commands_apply_effect_on_new_entity_auto_inserts() {
+        let mut app = app();
+
+        let entity = app
+            .world_mut()
+            .commands()
+            .spawn(Marker)
+            .apply_effect(SpeedEffect(ValueModifier::Val(5.0)))
+            .id();
+        app.update();
+        app.update();
+
+        assert!((app.world().get::<Speed>(entity).unwrap().0 - 5.0).abs() < 0.001);
+    }
+}
@@ -0,0 +1,126 @@
+//! World context for effects whose strength depends on their surroundings.
+//!
+//! Applicators can't query the world themselves. Instead the observer hands
+//! [`StatusEffectApplicator::modifier_with_context`](crate::StatusEffectApplicator::modifier_with_context)
+//! an [`EffectContext`] with helper queries, e.g. to count nearby allies for a
+//! rally aura.
+
+use bevy::prelude::*;
+
+/// Read-only view of the world around an effect's target.
+///
+/// Positions come from [`GlobalTransform`]; entities without one are never
+/// counted as nearby.
+pub struct EffectContext<'a, 'w, 's> {
+    entity: Entity,
+    positions: &'a Query<'w, 's, (Entity, &'static GlobalTransform)>,
+}
+
+impl<'a, 'w, 's> EffectContext<'a, 'w, 's> {
+    pub(crate) fn new(
+        entity: Entity,
+        positions: &'a Query<'w, 's, (Entity, &'static GlobalTransform)>,
+    ) -> Self {
+        Self { entity, positions }
+    }
+
+    /// Returns the entity the effect is applied to.
+    #[must_use]
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns the target's world position, if it has one.
+    #[must_use]
+    pub fn position(&self) -> Option<Vec3> {
+        self.positions
+            .get(self.entity)
+            .ok()
+            .map(|(_, transform)| transform.translation())
+    }
+
+    /// Iterates other entities within `radius` of the target.
+    pub fn nearby(&self, radius: f32) -> impl Iterator<Item = Entity> + '_ {
+        let origin = self.position();
+        self.positions
+            .iter()
+            .filter(move |&(entity, transform)| {
+                entity != self.entity
+                    && origin.is_some_and(|origin| {
+                        origin.distance_squared(transform.translation()) <= radius * radius
+                    })
+            })
+            .map(|(entity, _)| entity)
+    }
+
+    /// Returns the number of other entities within `radius` of the target.
+    #[must_use]
+    pub fn count_within(&self, radius: f32) -> usize {
+        self.nearby(radius).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Attack(f32);
+
+    /// +10 attack per ally within 5 units, at least once
+    #[derive(Event, Clone, Copy)]
+    struct Rally(ValueModifier);
+
+    impl StatusEffectApplicator<Attack> for Rally {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Attack, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+
+        fn modifier_with_context(&self, ctx: &EffectContext) -> ValueModifier {
+            match ctx.count_within(5.0) {
+                0 => self.0,
+                allies => self.0.scaled_by(allies as f32),
+            }
+        }
+    }
+
+    fn rally(ally_positions: &[Vec3]) -> f32 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Attack, Rally>::default());
+        let entity = app
+            .world_mut()
+            .spawn((Attack(100.0), GlobalTransform::IDENTITY))
+            .id();
+        for &position in ally_positions {
+            app.world_mut()
+                .spawn(GlobalTransform::from_translation(position));
+        }
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Rally(ValueModifier::Val(10.0)));
+        app.update();
+        app.world().get::<Attack>(entity).unwrap().0
+    }
+
+    #[test]
+    fn nearby_allies_multiply_rally() {
+        let allies = [Vec3::X, Vec3::Y * 2.0, Vec3::NEG_Z * 4.0];
+        assert_eq!(rally(&allies), 130.0);
+
+        // Too far away to count
+        assert_eq!(rally(&[Vec3::X * 50.0]), 110.0);
+        assert_eq!(rally(&[]), 110.0);
+    }
+}
@@ -0,0 +1,81 @@
+//! Partial counters to active modifiers.
+
+use crate::{EPSILON, ValueModifier};
+
+/// Returns the modifier that undoes `fraction` of `active`'s effect, e.g. for
+/// cleanses that halve a slow instead of removing it.
+///
+/// Counters are computed for linear application on the value `active` left
+/// behind:
+/// - **Val**: the opposite flat, `Val(-v * fraction)`
+/// - **Percent**: the percent moving the multiplier from `1 + p/100` to
+///   `1 + (1 - fraction) * p/100`. Since it applies to the already modified
+///   value, it isn't `-p * fraction`: halving a -50% slow takes +50%
+/// - **Multiply**: likewise, as a multiplier
+///
+/// A `fraction` of 1 cancels the modifier, above 1 overshoots it. Reductions
+/// of 100% or more, `Set` and `Negate` leave nothing proportional to undo and
+/// give a no-op `Val(0.0)`.
+///
+/// # Example
+///
+/// ```rust
+/// use msg_status_effect::prelude::*;
+///
+/// let slow = ValueModifier::Percent(-50.0);
+/// let counter = counter_modifier(slow, 0.5);
+/// // 100 slowed to 50, then back up to 75: half the slow remains
+/// assert_eq!(counter.apply(slow.apply(100.0)), 75.0);
+/// ```
+#[must_use]
+pub fn counter_modifier(active: ValueModifier, fraction: f32) -> ValueModifier {
+    let counter_factor = |factor: f32| {
+        let remaining = 1.0 + (factor - 1.0) * (1.0 - fraction);
+        (factor > EPSILON).then(|| remaining / factor)
+    };
+    match active {
+        ValueModifier::Val(v) => ValueModifier::Val(-v * fraction),
+        ValueModifier::Percent(p) => counter_factor(1.0 + p / 100.0)
+            .map_or(ValueModifier::Val(0.0), |factor| {
+                ValueModifier::Percent((factor - 1.0) * 100.0)
+            }),
+        ValueModifier::Multiply(factor) => {
+            counter_factor(factor).map_or(ValueModifier::Val(0.0), ValueModifier::Multiply)
+        }
+        ValueModifier::Set(_) | ValueModifier::Negate => ValueModifier::Val(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_halves_a_slow() {
+        let slow = ValueModifier::Percent(-50.0);
+        let counter = counter_modifier(slow, 0.5);
+        assert_eq!(counter, ValueModifier::Percent(50.0));
+        // Restores 25% of the base speed, half of what the slow took
+        let slowed = slow.apply(100.0);
+        assert_eq!(counter.apply(slowed) - slowed, 25.0);
+
+        // Flats counter directly
+        let drain = ValueModifier::Val(-40.0);
+        assert_eq!(counter_modifier(drain, 0.5), ValueModifier::Val(20.0));
+        assert_eq!(counter_modifier(drain, 0.5).apply(drain.apply(100.0)), 80.0);
+
+        // Multipliers and full counters
+        let halve = ValueModifier::Multiply(0.5);
+        assert_eq!(counter_modifier(halve, 0.5), ValueModifier::Multiply(1.5));
+        assert_eq!(counter_modifier(slow, 1.0), ValueModifier::Percent(100.0));
+
+        // Nothing proportional to undo
+        for active in [
+            ValueModifier::Percent(-100.0),
+            ValueModifier::Set(0.0),
+            ValueModifier::Negate,
+        ] {
+            assert_eq!(counter_modifier(active, 0.5), ValueModifier::Val(0.0));
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Effects derived from another stat on the same entity.
+//!
+//! [`CrossStatApplicator<S>`] applies a fraction of the entity's stat `S` to
+//! the component it targets, e.g. "gain speed equal to 10% of armor". Apply it
+//! with [`apply_cross_stat`](crate::StatusEffectCommandsExt::apply_cross_stat),
+//! which reads `S` when the command runs.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{MutableComponent, StatValue, StatusEffectApplicator, ValueModifier};
+
+/// Adds `fraction` of stat `S` to the targeted stat.
+///
+/// The modifier is resolved from the source stat up front; a missing source
+/// contributes zero.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Armor(f32);
+///
+/// impl StatValue for Armor {
+///     fn stat_value(&self) -> f32 { self.0 }
+///     fn set_stat_value(&mut self, value: f32) { self.0 = value; }
+/// }
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// impl StatValue for Speed {
+///     fn stat_value(&self) -> f32 { self.0 }
+///     fn set_stat_value(&mut self, value: f32) { self.0 = value; }
+/// }
+///
+/// fn app(app: &mut App) {
+///     app.add_plugins(StatusEffectPlugin::<Speed, CrossStatApplicator<Armor>>::default());
+/// }
+///
+/// fn juggernaut(mut commands: Commands, entity: Entity) {
+///     // Speed equal to 10% of armor
+///     commands.apply_cross_stat::<Armor>(entity, 0.1);
+/// }
+/// ```
+#[derive(Event)]
+pub struct CrossStatApplicator<S: Component> {
+    /// Fraction of the source stat applied
+    pub fraction: f32,
+    /// Modifier resolved from the source stat
+    pub modifier: ValueModifier,
+    _marker: PhantomData<S>,
+}
+
+impl<S: Component> CrossStatApplicator<S> {
+    /// Applies `fraction` of a source stat currently at `source_stat`.
+    #[must_use]
+    pub fn new(fraction: f32, source_stat: f32) -> Self {
+        Self {
+            fraction,
+            modifier: ValueModifier::Val(fraction * source_stat),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S: Component> Clone for CrossStatApplicator<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Component> Copy for CrossStatApplicator<S> {}
+
+impl<C, S> StatusEffectApplicator<C> for CrossStatApplicator<S>
+where
+    C: MutableComponent + StatValue,
+    S: Component,
+{
+    fn modifier(&self) -> ValueModifier {
+        self.modifier
+    }
+
+    fn apply(&self, component: &mut C, power: f32) {
+        let value = self.modifier.apply_scaled(component.stat_value(), power);
+        component.set_stat_value(value);
+    }
+
+    fn scaled_by(&self, factor: f32) -> Self {
+        Self {
+            modifier: self.modifier.scaled_by(factor),
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    impl StatValue for Armor {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[test]
+    fn grants_a_fraction_of_the_source_stat() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, CrossStatApplicator<Armor>>::default());
+        let armored = app.world_mut().spawn((Speed(100.0), Armor(200.0))).id();
+        let unarmored = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_cross_stat::<Armor>(armored, 0.1)
+            .apply_cross_stat::<Armor>(unarmored, 0.1);
+        app.update();
+
+        assert_eq!(app.world().get::<Speed>(armored).unwrap().0, 120.0);
+        assert_eq!(app.world().get::<Armor>(armored).unwrap().0, 200.0);
+        assert_eq!(app.world().get::<Speed>(unarmored).unwrap().0, 100.0);
+    }
+}
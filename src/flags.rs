@@ -0,0 +1,197 @@
+//! Effect categories as bitflags, for fast dispel filtering.
+//!
+//! Each effect reports its categories through
+//! [`StatusEffectApplicator::flags`](crate::StatusEffectApplicator::flags), and
+//! tracked applications keep them in their [`AppliedEffect`](crate::AppliedEffect)
+//! entry. A [`Dispel`] removes every tracked effect sharing a flag with its
+//! mask, which is a single bitwise AND per entry.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AppliedEffects, MutableComponent, PercentSum, RebalanceOnRemove, StatusEffectApplication,
+    StatusEffectEvent, tracking,
+};
+
+bitflags! {
+    /// Categories of an effect.
+    ///
+    /// The low bits hold common categories. Games can define their own with
+    /// [`from_bits_retain`](Self::from_bits_retain), e.g.
+    /// `EffectFlags::from_bits_retain(1 << 16)`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(transparent)]
+    pub struct EffectFlags: u32 {
+        /// Beneficial effect
+        const BUFF = 1;
+        /// Harmful effect
+        const DEBUFF = 1 << 1;
+        /// Magical effect
+        const MAGIC = 1 << 2;
+        /// Physical effect
+        const PHYSICAL = 1 << 3;
+        /// Curse
+        const CURSE = 1 << 4;
+        /// Poison
+        const POISON = 1 << 5;
+        /// Disease
+        const DISEASE = 1 << 6;
+    }
+}
+
+/// Removes tracked effects sharing any flag with `mask` from an entity.
+///
+/// The affected stats are rebuilt from their base with the remaining effects.
+/// Effects with no flags are never dispelled, and an empty mask dispels
+/// nothing. Untracked components can't be rebuilt and are left as they are.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn cleanse(mut commands: Commands, entity: Entity) {
+///     commands.trigger(Dispel {
+///         entity,
+///         mask: EffectFlags::MAGIC | EffectFlags::CURSE,
+///     });
+/// }
+/// ```
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct Dispel {
+    /// The entity to dispel effects from
+    pub entity: Entity,
+    /// Categories to dispel
+    pub mask: EffectFlags,
+}
+
+fn dispel_observer<C: MutableComponent>(
+    on: On<Dispel>,
+    config: Res<StatusEffectApplication<C>>,
+    mut q: Query<(
+        &mut C,
+        &mut AppliedEffects<C>,
+        Option<&RebalanceOnRemove<C>>,
+        Option<&mut PercentSum<C>>,
+    )>,
+    mut events: Option<ResMut<Messages<StatusEffectEvent>>>,
+) {
+    let Ok((mut component, mut applied, policy, mut sum)) = q.get_mut(on.entity) else {
+        return;
+    };
+    let before = applied.len();
+    let removed: Vec<_> = applied
+        .effects
+        .extract_if(.., |effect| effect.flags.intersects(on.mask))
+        .collect();
+    tracking::write_removed(&config, events.as_deref_mut(), on.entity, &removed);
+    if applied.len() != before
+        && tracking::rebuild_after_removal(
+            &config,
+            component.bypass_change_detection(),
+            &mut applied,
+            policy,
+            sum.as_deref_mut(),
+        )
+    {
+        component.set_changed();
+    }
+}
+
+/// Registers dispels for tracked component `C` once per app.
+pub(crate) struct DispelPlugin<C: MutableComponent>(PhantomData<C>);
+
+impl<C: MutableComponent> Default for DispelPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for DispelPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(dispel_observer::<C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    /// Speed effect with fixed categories
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier, EffectFlags);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn flags(&self) -> EffectFlags {
+            self.1
+        }
+    }
+
+    #[test]
+    fn dispel_removes_only_matching_effects() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        let haste = EffectFlags::MAGIC | EffectFlags::BUFF;
+        let sprint = EffectFlags::PHYSICAL | EffectFlags::BUFF;
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(30.0), haste))
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(20.0), sprint))
+            .apply_effect(
+                entity,
+                SpeedEffect(ValueModifier::Val(10.0), EffectFlags::empty()),
+            );
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 160.0);
+
+        app.world_mut().commands().trigger(Dispel {
+            entity,
+            mask: EffectFlags::MAGIC | EffectFlags::CURSE,
+        });
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 130.0);
+
+        // Unflagged effects never match, not even a full mask
+        app.world_mut().commands().trigger(Dispel {
+            entity,
+            mask: EffectFlags::all(),
+        });
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert!(applied.effects[0].flags.is_empty());
+    }
+}
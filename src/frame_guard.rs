@@ -0,0 +1,135 @@
+//! Once-per-frame application guard.
+//!
+//! Components configured with
+//! [`StatusEffectApplication::once_per_frame`](crate::StatusEffectApplication::once_per_frame)
+//! apply each effect type at most once per entity per frame. Repeats within the
+//! same frame, e.g. from several overlapping colliders, are dropped. The frame
+//! of the last application is kept in a [`LastAppliedFrame<E>`] component,
+//! which is required by `C` so it's present before the first application.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+/// Frame in which effect type `E` was last applied to this entity.
+#[derive(Component)]
+pub struct LastAppliedFrame<E: Send + Sync + 'static> {
+    frame: Option<u32>,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Send + Sync + 'static> LastAppliedFrame<E> {
+    /// Returns the frame of the last application, if any.
+    #[must_use]
+    pub fn frame(&self) -> Option<u32> {
+        self.frame
+    }
+
+    /// Records an application in `frame`.
+    ///
+    /// Returns false if one was already recorded in that frame.
+    pub(crate) fn mark(&mut self, frame: u32) -> bool {
+        if self.frame == Some(frame) {
+            return false;
+        }
+        self.frame = Some(frame);
+        true
+    }
+}
+
+impl<E: Send + Sync + 'static> Default for LastAppliedFrame<E> {
+    fn default() -> Self {
+        Self {
+            frame: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Spikes(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Spikes {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Shrapnel(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Shrapnel {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn repeats_per_frame(&self) -> bool {
+            true
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Spikes>::new(
+            StatusEffectApplication::linear().once_per_frame(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Health, Shrapnel>::new(
+            StatusEffectApplication::linear().once_per_frame(),
+        ));
+        app
+    }
+
+    #[test]
+    fn double_trigger_applies_once_per_frame() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+
+        for _ in 0..2 {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Spikes(ValueModifier::Val(-10.0)));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 90.0);
+
+        // The guard resets next frame
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Spikes(ValueModifier::Val(-10.0)));
+        app.update();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 80.0);
+    }
+
+    #[test]
+    fn opted_out_effects_apply_every_time() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+
+        for _ in 0..3 {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Shrapnel(ValueModifier::Val(-5.0)));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 85.0);
+    }
+}
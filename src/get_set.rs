@@ -0,0 +1,145 @@
+//! Prebuilt applicator for computed stats.
+//!
+//! Some stats aren't a plain field, e.g. a total made of a base and a bonus.
+//! [`GetSetApplicator`] reads and writes such a stat through a getter and
+//! setter pair, so a [`ValueModifier`] applies to the logical value.
+
+use bevy::prelude::*;
+
+use crate::{MutableComponent, StatusEffectApplicator, ValueModifier};
+
+/// Applies a modifier to the stat read by `get` and written by `set`.
+///
+/// The pair must be consistent: `get` after `set(value)` returns `value`.
+/// Debug builds warn when it doesn't.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Damage {
+///     base: f32,
+///     bonus: f32,
+/// }
+///
+/// fn total(damage: &Damage) -> f32 {
+///     damage.base + damage.bonus
+/// }
+///
+/// // Changes to the total land in the bonus
+/// fn set_total(damage: &mut Damage, value: f32) {
+///     damage.bonus = value - damage.base;
+/// }
+///
+/// fn enrage(mut commands: Commands, entity: Entity) {
+///     commands.apply_effect(
+///         entity,
+///         GetSetApplicator::new(ValueModifier::Percent(50.0), total, set_total),
+///     );
+/// }
+/// ```
+#[derive(Event)]
+pub struct GetSetApplicator<C: MutableComponent> {
+    /// Modifier applied to the stat
+    pub modifier: ValueModifier,
+    /// Reads the stat
+    pub get: fn(&C) -> f32,
+    /// Writes the stat
+    pub set: fn(&mut C, f32),
+}
+
+impl<C: MutableComponent> GetSetApplicator<C> {
+    /// Applies `modifier` to the stat behind `get` and `set`.
+    #[must_use]
+    pub fn new(modifier: ValueModifier, get: fn(&C) -> f32, set: fn(&mut C, f32)) -> Self {
+        Self { modifier, get, set }
+    }
+}
+
+impl<C: MutableComponent> Clone for GetSetApplicator<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: MutableComponent> Copy for GetSetApplicator<C> {}
+
+impl<C: MutableComponent> StatusEffectApplicator<C> for GetSetApplicator<C> {
+    fn modifier(&self) -> ValueModifier {
+        self.modifier
+    }
+
+    fn apply(&self, component: &mut C, power: f32) {
+        let value = self.modifier.apply_scaled((self.get)(component), power);
+        (self.set)(component, value);
+        #[cfg(debug_assertions)]
+        {
+            let read = (self.get)(component);
+            if (read - value).abs() > crate::EPSILON * value.abs().max(1.0) {
+                log_warn!(
+                    "Inconsistent getter/setter on {}: set {value}, read back {read}",
+                    std::any::type_name::<C>()
+                );
+            }
+        }
+    }
+
+    fn scaled_by(&self, factor: f32) -> Self {
+        Self::new(self.modifier.scaled_by(factor), self.get, self.set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// Attack power derived from strength and a weapon bonus
+    #[derive(Component, Default)]
+    struct Attack {
+        strength: f32,
+        weapon: f32,
+    }
+
+    fn total(attack: &Attack) -> f32 {
+        attack.strength * 2.0 + attack.weapon
+    }
+
+    fn set_total(attack: &mut Attack, value: f32) {
+        attack.weapon = value - attack.strength * 2.0;
+    }
+
+    #[test]
+    fn applies_to_the_derived_stat() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Attack, GetSetApplicator<Attack>>::default());
+        let entity = app
+            .world_mut()
+            .spawn(Attack {
+                strength: 30.0,
+                weapon: 40.0,
+            })
+            .id();
+
+        app.world_mut().commands().apply_effect(
+            entity,
+            GetSetApplicator::new(ValueModifier::Percent(50.0), total, set_total),
+        );
+        app.update();
+        let attack = app.world().get::<Attack>(entity).unwrap();
+        assert_eq!(total(attack), 150.0);
+        assert_eq!(attack.strength, 30.0);
+        assert_eq!(attack.weapon, 90.0);
+
+        app.world_mut().commands().apply_effect(
+            entity,
+            GetSetApplicator::new(ValueModifier::Val(-20.0), total, set_total),
+        );
+        app.update();
+        assert_eq!(total(app.world().get::<Attack>(entity).unwrap()), 130.0);
+    }
+}
@@ -0,0 +1,215 @@
+//! Debug visualization of effect magnitude with gizmos.
+//!
+//! Enabled by the `gizmos` feature. [`StatusEffectGizmosPlugin<C>`] draws a
+//! horizontal bar above every entity with tracked effects on `C`, sized by
+//! the net change from the base value: green for buffs, red for debuffs.
+
+use std::marker::PhantomData;
+
+use bevy::color::palettes::css::{LIME, RED};
+use bevy::prelude::*;
+
+use crate::{AppliedEffects, MutableComponent, StatusEffectApplication};
+
+/// Bar drawn for an entity's net effect on one stat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectBar {
+    /// Bar length in world units
+    pub length: f32,
+    /// Green for a net buff, red for a net debuff
+    pub color: Color,
+}
+
+impl EffectBar {
+    /// Computes the bar for a stat that moved from `base` to `current`.
+    ///
+    /// `scale` converts stat points to world units. Returns `None` when the
+    /// net change is zero, so unaffected entities draw nothing.
+    #[must_use]
+    pub fn new(base: f32, current: f32, scale: f32) -> Option<Self> {
+        let net = current - base;
+        if net.abs() <= crate::EPSILON {
+            return None;
+        }
+        Some(Self {
+            length: net.abs() * scale,
+            color: if net > 0.0 { LIME.into() } else { RED.into() },
+        })
+    }
+}
+
+/// Placement of the effect bars for component `C`.
+#[derive(Resource)]
+pub struct EffectBarSettings<C: MutableComponent> {
+    /// Offset from the entity's position to the bar's center
+    pub offset: Vec3,
+    /// World units per stat point
+    pub scale: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> Default for EffectBarSettings<C> {
+    fn default() -> Self {
+        Self {
+            offset: Vec3::Y,
+            scale: 0.01,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Computes the effect bar for an entity with tracked effects on `C`.
+///
+/// Returns `None` if the component isn't tracked or the net change is zero.
+#[must_use]
+pub fn effect_bar<C: MutableComponent>(
+    config: &StatusEffectApplication<C>,
+    settings: &EffectBarSettings<C>,
+    component: &C,
+    applied: &AppliedEffects<C>,
+) -> Option<EffectBar> {
+    let current = config.stat_value(component)?;
+    EffectBar::new(applied.base, current, settings.scale)
+}
+
+/// Draws effect bars above entities with tracked effects on `C`.
+pub fn draw_effect_bars<C: MutableComponent>(
+    mut gizmos: Gizmos,
+    config: Res<StatusEffectApplication<C>>,
+    settings: Res<EffectBarSettings<C>>,
+    q: Query<(&GlobalTransform, &C, &AppliedEffects<C>)>,
+) {
+    for (transform, component, applied) in &q {
+        let Some(bar) = effect_bar(&config, &settings, component, applied) else {
+            continue;
+        };
+        let center = transform.translation() + settings.offset;
+        let half = Vec3::X * bar.length / 2.0;
+        gizmos.line(center - half, center + half, bar.color);
+    }
+}
+
+/// Draws gizmo bars for the net effect on component `C`.
+///
+/// Requires `C` to be [tracked](StatusEffectApplication::tracked) and bevy's
+/// gizmo plugin to be active.
+pub struct StatusEffectGizmosPlugin<C: MutableComponent> {
+    /// Offset from the entity's position to the bar's center
+    pub offset: Vec3,
+    /// World units per stat point
+    pub scale: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> Default for StatusEffectGizmosPlugin<C> {
+    fn default() -> Self {
+        let settings = EffectBarSettings::<C>::default();
+        Self {
+            offset: settings.offset,
+            scale: settings.scale,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: MutableComponent> Plugin for StatusEffectGizmosPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EffectBarSettings::<C> {
+            offset: self.offset,
+            scale: self.scale,
+            _marker: PhantomData,
+        });
+        app.add_systems(PostUpdate, draw_effect_bars::<C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn effect_bar_data_for_buffed_and_debuffed_entities() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.insert_resource(EffectBarSettings::<Speed> {
+            scale: 0.1,
+            ..default()
+        });
+
+        let buffed = app.world_mut().spawn(Speed(100.0)).id();
+        let debuffed = app.world_mut().spawn(Speed(100.0)).id();
+        let neutral = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        for (entity, modifier) in [
+            (buffed, ValueModifier::Val(20.0)),
+            (debuffed, ValueModifier::Percent(-30.0)),
+            (neutral, ValueModifier::Val(10.0)),
+            (neutral, ValueModifier::Val(-10.0)),
+        ] {
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: SpeedEffect(modifier),
+                entity,
+            });
+        }
+        app.update();
+
+        let world = app.world();
+        let config = world.resource::<StatusEffectApplication<Speed>>();
+        let settings = world.resource::<EffectBarSettings<Speed>>();
+        let bar = |entity: Entity| {
+            effect_bar(
+                config,
+                settings,
+                world.get::<Speed>(entity).unwrap(),
+                world.get::<AppliedEffects<Speed>>(entity).unwrap(),
+            )
+        };
+
+        let buff = bar(buffed).unwrap();
+        assert!((buff.length - 2.0).abs() < 0.001);
+        assert_eq!(buff.color, Color::from(LIME));
+
+        let debuff = bar(debuffed).unwrap();
+        assert!((debuff.length - 3.0).abs() < 0.001);
+        assert_eq!(debuff.color, Color::from(RED));
+
+        // Effects that cancel out draw nothing
+        assert!(bar(neutral).is_none());
+    }
+
+    #[test]
+    fn effect_bar_zero_change_draws_nothing() {
+        assert!(EffectBar::new(50.0, 50.0, 1.0).is_none());
+    }
+}
@@ -0,0 +1,343 @@
+//! Effects that grant components instead of modifying values.
+//!
+//! Some effects are really "while active, this entity has `T`" (`Flying`,
+//! `Invisible`). [`GrantComponentEffect<T>`] inserts `T` and keeps it for as
+//! long as at least one source's grant is active. Grants end when their
+//! duration runs out or on [`RevokeComponentEffect<T>`].
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+/// A single source's active grant.
+#[derive(Debug, Clone)]
+pub struct ComponentGrant {
+    /// Entity that granted the component
+    pub source: Entity,
+    timer: Option<Timer>,
+}
+
+impl ComponentGrant {
+    /// Returns the seconds left on a timed grant, or `None` if it never expires.
+    #[must_use]
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.timer.as_ref().map(Timer::remaining_secs)
+    }
+}
+
+/// Active grants of component `T` on this entity, one per source.
+///
+/// `T` is removed once the last grant ends, unless the entity already had it
+/// before the first grant.
+#[derive(Component)]
+pub struct ComponentGrants<T: Component> {
+    grants: Vec<ComponentGrant>,
+    inserted: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Component> ComponentGrants<T> {
+    /// Returns the active grants.
+    #[must_use]
+    pub fn grants(&self) -> &[ComponentGrant] {
+        &self.grants
+    }
+
+    /// Returns the number of sources currently granting `T`.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.grants.len()
+    }
+
+    /// Returns true if no source grants `T`.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.grants.is_empty()
+    }
+}
+
+/// Grants component `T` to an entity on behalf of a source.
+///
+/// Granting again from the same source refreshes its duration.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Clone)]
+/// struct Flying;
+///
+/// fn cast_levitate(mut commands: Commands, caster: Entity, target: Entity) {
+///     commands.trigger(GrantComponentEffect::for_secs(target, caster, Flying, 5.0));
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugins(GrantComponentPlugin::<Flying>::default());
+/// }
+/// ```
+#[derive(EntityEvent, Clone)]
+pub struct GrantComponentEffect<T: Component + Clone> {
+    /// The entity receiving the component
+    pub entity: Entity,
+    /// The entity granting the component
+    pub source: Entity,
+    /// The component to insert
+    pub component: T,
+    /// Grant duration in seconds, or `None` to grant until revoked
+    pub duration: Option<f32>,
+}
+
+impl<T: Component + Clone> GrantComponentEffect<T> {
+    /// Grants `component` until revoked by `source`.
+    #[must_use]
+    pub fn new(entity: Entity, source: Entity, component: T) -> Self {
+        Self {
+            entity,
+            source,
+            component,
+            duration: None,
+        }
+    }
+
+    /// Grants `component` for the given number of seconds.
+    #[must_use]
+    pub fn for_secs(entity: Entity, source: Entity, component: T, secs: f32) -> Self {
+        Self {
+            entity,
+            source,
+            component,
+            duration: Some(secs),
+        }
+    }
+}
+
+/// Ends `source`'s grant of component `T` on an entity.
+#[derive(EntityEvent)]
+pub struct RevokeComponentEffect<T: Component> {
+    /// The entity holding the grant
+    pub entity: Entity,
+    /// The source whose grant ends
+    pub source: Entity,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Component> RevokeComponentEffect<T> {
+    /// Ends `source`'s grant of `T` on `entity`.
+    #[must_use]
+    pub fn new(entity: Entity, source: Entity) -> Self {
+        Self {
+            entity,
+            source,
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn grant_component_observer<T: Component + Clone>(
+    on: On<GrantComponentEffect<T>>,
+    mut commands: Commands,
+) {
+    let Ok(mut entity_commands) = commands.get_entity(on.entity) else {
+        return;
+    };
+    let source = on.source;
+    let component = on.component.clone();
+    let grant = ComponentGrant {
+        source,
+        timer: on
+            .duration
+            .map(|secs| Timer::from_seconds(secs, TimerMode::Once)),
+    };
+
+    // Merged at apply time so grants from the same flush share one tracker
+    entity_commands.queue(move |mut entity: EntityWorldMut| {
+        if let Some(mut grants) = entity.get_mut::<ComponentGrants<T>>() {
+            match grants.grants.iter_mut().find(|g| g.source == source) {
+                Some(existing) => *existing = grant,
+                None => grants.grants.push(grant),
+            }
+            return;
+        }
+        let inserted = !entity.contains::<T>();
+        entity.insert(ComponentGrants::<T> {
+            grants: vec![grant],
+            inserted,
+            _marker: PhantomData,
+        });
+        if inserted {
+            entity.insert(component);
+        }
+    });
+}
+
+fn revoke_component_observer<T: Component>(
+    on: On<RevokeComponentEffect<T>>,
+    mut q: Query<&mut ComponentGrants<T>>,
+    mut commands: Commands,
+) {
+    let Ok(mut grants) = q.get_mut(on.entity) else {
+        return;
+    };
+    grants.grants.retain(|grant| grant.source != on.source);
+    if grants.is_empty() {
+        end_grants(&mut commands, on.entity, &grants);
+    }
+}
+
+/// Ends expired timed grants.
+fn tick_component_grants<T: Component>(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut ComponentGrants<T>)>,
+) {
+    for (entity, mut grants) in &mut q {
+        let had_grants = !grants.is_empty();
+        grants.grants.retain_mut(|grant| {
+            grant
+                .timer
+                .as_mut()
+                .is_none_or(|timer| !timer.tick(time.delta()).is_finished())
+        });
+        if had_grants && grants.is_empty() {
+            end_grants(&mut commands, entity, &grants);
+        }
+    }
+}
+
+fn end_grants<T: Component>(commands: &mut Commands, entity: Entity, grants: &ComponentGrants<T>) {
+    let mut entity_commands = commands.entity(entity);
+    entity_commands.remove::<ComponentGrants<T>>();
+    if grants.inserted {
+        entity_commands.remove::<T>();
+    }
+}
+
+/// Registers component grants for `T`.
+pub struct GrantComponentPlugin<T: Component + Clone>(PhantomData<T>);
+
+impl<T: Component + Clone> Default for GrantComponentPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Component + Clone> Plugin for GrantComponentPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(grant_component_observer::<T>);
+        app.add_observer(revoke_component_observer::<T>);
+        app.add_systems(Update, tick_component_grants::<T>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[derive(Component, Clone)]
+    struct Flying;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(GrantComponentPlugin::<Flying>::default());
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app
+    }
+
+    #[test]
+    fn grant_persists_until_all_sources_expire() {
+        let mut app = app();
+        let wings = app.world_mut().spawn_empty().id();
+        let potion = app.world_mut().spawn_empty().id();
+        let entity = app.world_mut().spawn_empty().id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .trigger(GrantComponentEffect::for_secs(entity, wings, Flying, 0.5));
+        app.world_mut()
+            .commands()
+            .trigger(GrantComponentEffect::for_secs(entity, potion, Flying, 1.0));
+        app.update();
+        assert!(app.world().get::<Flying>(entity).is_some());
+        assert_eq!(
+            app.world()
+                .get::<ComponentGrants<Flying>>(entity)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Wings expire, the potion keeps the entity flying
+        app.update();
+        app.update();
+        assert!(app.world().get::<Flying>(entity).is_some());
+        assert_eq!(
+            app.world()
+                .get::<ComponentGrants<Flying>>(entity)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        app.update();
+        app.update();
+        assert!(app.world().get::<Flying>(entity).is_none());
+        assert!(app.world().get::<ComponentGrants<Flying>>(entity).is_none());
+    }
+
+    #[test]
+    fn revoking_last_source_removes_component() {
+        let mut app = app();
+        let source = app.world_mut().spawn_empty().id();
+        let other = app.world_mut().spawn_empty().id();
+        let entity = app.world_mut().spawn_empty().id();
+
+        app.world_mut()
+            .commands()
+            .trigger(GrantComponentEffect::new(entity, source, Flying));
+        app.world_mut()
+            .commands()
+            .trigger(GrantComponentEffect::new(entity, other, Flying));
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .trigger(RevokeComponentEffect::<Flying>::new(entity, source));
+        app.update();
+        assert!(app.world().get::<Flying>(entity).is_some());
+
+        app.world_mut()
+            .commands()
+            .trigger(RevokeComponentEffect::<Flying>::new(entity, other));
+        app.update();
+        assert!(app.world().get::<Flying>(entity).is_none());
+    }
+
+    #[test]
+    fn pre_existing_component_is_kept() {
+        let mut app = app();
+        let source = app.world_mut().spawn_empty().id();
+        let entity = app.world_mut().spawn(Flying).id();
+
+        app.world_mut()
+            .commands()
+            .trigger(GrantComponentEffect::new(entity, source, Flying));
+        app.update();
+        app.world_mut()
+            .commands()
+            .trigger(RevokeComponentEffect::<Flying>::new(entity, source));
+        app.update();
+
+        assert!(app.world().get::<Flying>(entity).is_some());
+        assert!(app.world().get::<ComponentGrants<Flying>>(entity).is_none());
+    }
+}
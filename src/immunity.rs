@@ -0,0 +1,218 @@
+//! Category immunity.
+//!
+//! While an entity has [`Immunity`], every new effect sharing a
+//! [flag](crate::EffectFlags) with its mask is rejected, whatever component it
+//! targets. [`CleanseAndProtect`] combines a [`Dispel`] with a timed
+//! immunity for the same categories, e.g. "remove all debuffs and block new
+//! ones for 5 seconds". [`ImmunityPlugin`] handles [`CleanseAndProtect`] and
+//! lets timed immunities run out.
+
+use bevy::prelude::*;
+
+use crate::{Dispel, EffectFlags};
+
+/// Rejects new effects sharing any flag with `mask` on this entity.
+///
+/// Insert it directly or trigger [`CleanseAndProtect`]. Applying a new one
+/// replaces the old. Effects with no flags are never blocked. Timed
+/// immunities only run out with [`ImmunityPlugin`] added.
+#[derive(Component, Clone, Debug)]
+pub struct Immunity {
+    /// Categories to block
+    pub mask: EffectFlags,
+    timer: Option<Timer>,
+}
+
+impl Immunity {
+    /// Blocks `mask` until the marker is removed.
+    #[must_use]
+    pub fn new(mask: EffectFlags) -> Self {
+        Self { mask, timer: None }
+    }
+
+    /// Blocks `mask` for the given number of seconds.
+    #[must_use]
+    pub fn for_secs(mask: EffectFlags, secs: f32) -> Self {
+        Self {
+            mask,
+            timer: Some(Timer::from_seconds(secs, TimerMode::Once)),
+        }
+    }
+
+    /// Returns the seconds left, or `None` if it never expires.
+    #[must_use]
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.timer.as_ref().map(Timer::remaining_secs)
+    }
+
+    /// Returns true if an effect with `flags` is blocked.
+    #[must_use]
+    pub fn blocks(&self, flags: EffectFlags) -> bool {
+        flags.intersects(self.mask)
+    }
+}
+
+/// Dispels categories from an entity and makes it immune to them for a while.
+///
+/// Needs [`ImmunityPlugin`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn holy_shield(mut commands: Commands, entity: Entity) {
+///     // Remove every debuff and block new ones for 5 seconds
+///     commands.trigger(CleanseAndProtect::new(entity, EffectFlags::DEBUFF, 5.0));
+/// }
+/// ```
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct CleanseAndProtect {
+    /// The entity to protect
+    pub entity: Entity,
+    /// Categories to dispel and block
+    pub mask: EffectFlags,
+    /// Immunity duration in seconds
+    pub duration: f32,
+}
+
+impl CleanseAndProtect {
+    /// Dispels `mask` from `entity` and blocks it for `secs` seconds.
+    #[must_use]
+    pub fn new(entity: Entity, mask: EffectFlags, secs: f32) -> Self {
+        Self {
+            entity,
+            mask,
+            duration: secs,
+        }
+    }
+}
+
+fn cleanse_and_protect_observer(on: On<CleanseAndProtect>, mut commands: Commands) {
+    let Ok(mut entity) = commands.get_entity(on.entity) else {
+        return;
+    };
+    entity.insert(Immunity::for_secs(on.mask, on.duration));
+    commands.trigger(Dispel {
+        entity: on.entity,
+        mask: on.mask,
+    });
+}
+
+/// Removes immunities whose duration has run out.
+fn tick_immunities(time: Res<Time>, mut commands: Commands, mut q: Query<(Entity, &mut Immunity)>) {
+    for (entity, mut immunity) in &mut q {
+        let Some(timer) = immunity.timer.as_mut() else {
+            continue;
+        };
+        if timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<Immunity>();
+        }
+    }
+}
+
+/// Registers the [`CleanseAndProtect`] observer and the [`Immunity`] timer.
+///
+/// Not added by [`StatusEffectPlugin`](crate::StatusEffectPlugin); add it
+/// once for cleanses and timed immunities.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(ImmunityPlugin);
+/// ```
+pub struct ImmunityPlugin;
+
+impl Plugin for ImmunityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(cleanse_and_protect_observer);
+        app.add_systems(Update, tick_immunities);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    /// Speed effect with fixed categories
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier, EffectFlags);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn flags(&self) -> EffectFlags {
+            self.1
+        }
+    }
+
+    fn speed(app: &mut App, entity: Entity, amount: f32, flags: EffectFlags) -> f32 {
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(amount), flags));
+        app.update();
+        app.world().get::<Speed>(entity).unwrap().0
+    }
+
+    #[test]
+    fn protect_blocks_category_until_expiry() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(ImmunityPlugin);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            500,
+        )));
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        assert_eq!(speed(&mut app, entity, -30.0, EffectFlags::DEBUFF), 70.0);
+        app.world_mut()
+            .trigger(CleanseAndProtect::new(entity, EffectFlags::DEBUFF, 1.0));
+        app.world_mut().flush();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+
+        // Debuffs are rejected, other categories still apply
+        assert_eq!(speed(&mut app, entity, -30.0, EffectFlags::DEBUFF), 100.0);
+        assert_eq!(speed(&mut app, entity, 10.0, EffectFlags::BUFF), 110.0);
+        assert_eq!(speed(&mut app, entity, 5.0, EffectFlags::empty()), 115.0);
+        assert!(app.world().get::<Immunity>(entity).is_some());
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!(app.world().get::<Immunity>(entity).is_none());
+        assert_eq!(speed(&mut app, entity, -30.0, EffectFlags::DEBUFF), 85.0);
+    }
+}
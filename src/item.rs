@@ -0,0 +1,237 @@
+//! Equipment stats granted from data.
+//!
+//! Loot tables describe an item's stats as a list of [`StatGrant`]s.
+//! [`apply_item_stats`](crate::StatusEffectCommandsExt::apply_item_stats)
+//! applies them through the [registry](crate::StatusEffectRegistry) and tags
+//! each tracked entry with the item id as its
+//! [`source`](crate::AppliedEffect::source), so
+//! [`remove_item_stats`](crate::StatusEffectCommandsExt::remove_item_stats)
+//! takes off exactly that item's entries. Targets must be
+//! [tracked](crate::StatusEffectApplication::tracked) to be removable.
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{
+    AppliedEffects, MutableComponent, PercentSum, RebalanceOnRemove, SerializableEffect,
+    StatusEffectApplication, StatusEffectEvent, StatusEffectRegistry, tracking, with_override,
+};
+
+/// One stat an item grants: an effect type name and its modifier.
+pub type StatGrant = SerializableEffect;
+
+/// Source of the application currently being triggered.
+///
+/// Only present for the duration of one trigger.
+#[derive(Resource)]
+pub(crate) struct ApplySource(pub(crate) Cow<'static, str>);
+
+/// Removes every tracked effect granted by `item` from an entity.
+///
+/// The affected stats are rebuilt from their base with the remaining effects.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn unequip(mut commands: Commands, entity: Entity) {
+///     commands.trigger(RemoveItemStats::new(entity, "boots_of_speed"));
+/// }
+/// ```
+#[derive(EntityEvent, Clone, Debug)]
+pub struct RemoveItemStats {
+    /// The entity to remove the item's stats from
+    pub entity: Entity,
+    /// Id of the item
+    pub item: Cow<'static, str>,
+}
+
+impl RemoveItemStats {
+    /// Removes the stats `item` granted to `entity`.
+    #[must_use]
+    pub fn new(entity: Entity, item: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            entity,
+            item: item.into(),
+        }
+    }
+}
+
+/// Applies `grants` to `entity` on behalf of `item`, replacing the stats it
+/// granted before.
+pub(crate) fn apply_item_stats(
+    world: &mut World,
+    entity: Entity,
+    item: Cow<'static, str>,
+    grants: &[StatGrant],
+) {
+    if world.get_entity(entity).is_err() {
+        return;
+    }
+    world.trigger(RemoveItemStats::new(entity, item.clone()));
+    with_override(world, ApplySource(item.clone()), |world| {
+        for grant in grants {
+            let apply = world
+                .get_resource::<StatusEffectRegistry>()
+                .and_then(|registry| registry.apply_modifier_fn(&grant.effect));
+            strict_assert!(
+                apply.is_some(),
+                "Item {item} grants unregistered effect {}",
+                grant.effect
+            );
+            if !apply.is_some_and(|apply| apply(world, entity, grant.modifier)) {
+                log_warn!(
+                    "Skipping {} from item {item}: effect is not registered or not rebuildable",
+                    grant.effect
+                );
+            }
+        }
+    });
+}
+
+fn remove_item_stats_observer<C: MutableComponent>(
+    on: On<RemoveItemStats>,
+    config: Res<StatusEffectApplication<C>>,
+    mut q: Query<(
+        &mut C,
+        &mut AppliedEffects<C>,
+        Option<&RebalanceOnRemove<C>>,
+        Option<&mut PercentSum<C>>,
+    )>,
+    mut events: Option<ResMut<Messages<StatusEffectEvent>>>,
+) {
+    let Ok((mut component, mut applied, policy, mut sum)) = q.get_mut(on.entity) else {
+        return;
+    };
+    let removed: Vec<_> = applied
+        .effects
+        .extract_if(.., |effect| effect.source == on.item)
+        .collect();
+    if removed.is_empty() {
+        return;
+    }
+    tracking::write_removed(&config, events.as_deref_mut(), on.entity, &removed);
+    if tracking::rebuild_after_removal(
+        &config,
+        component.bypass_change_detection(),
+        &mut applied,
+        policy,
+        sum.as_deref_mut(),
+    ) {
+        component.set_changed();
+    }
+}
+
+/// Registers item stat removal for tracked component `C` once per app.
+pub(crate) struct ItemStatsPlugin<C: MutableComponent>(PhantomData<C>);
+
+impl<C: MutableComponent> Default for ItemStatsPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for ItemStatsPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(remove_item_stats_observer::<C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    macro_rules! stat {
+        ($name:ident, $effect:ident) => {
+            #[derive(Component, Default)]
+            struct $name(f32);
+
+            impl StatValue for $name {
+                fn stat_value(&self) -> f32 {
+                    self.0
+                }
+
+                fn set_stat_value(&mut self, value: f32) {
+                    self.0 = value;
+                }
+            }
+
+            #[derive(Event, Clone, Copy)]
+            struct $effect(ValueModifier);
+
+            impl StatusEffectApplicator<$name> for $effect {
+                fn modifier(&self) -> ValueModifier {
+                    self.0
+                }
+
+                fn apply(&self, component: &mut $name, power: f32) {
+                    component.0 = self.0.apply_scaled(component.0, power);
+                }
+
+                fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+                    Some(Self(modifier))
+                }
+            }
+        };
+    }
+
+    stat!(Speed, SpeedEffect);
+    stat!(Armor, ArmorEffect);
+
+    fn stats(app: &App, entity: Entity) -> (f32, f32) {
+        let world = app.world();
+        (
+            world.get::<Speed>(entity).unwrap().0,
+            world.get::<Armor>(entity).unwrap().0,
+        )
+    }
+
+    #[test]
+    fn unequip_reverts_exactly_the_item_stats() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let entity = app.world_mut().spawn((Speed(100.0), Armor(50.0))).id();
+        app.update();
+
+        let boots = [
+            StatGrant::of::<SpeedEffect>(ValueModifier::Val(20.0)),
+            StatGrant::of::<ArmorEffect>(ValueModifier::Percent(50.0)),
+        ];
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(5.0)))
+            .apply_item_stats(entity, "boots", &boots);
+        app.update();
+        assert_eq!(stats(&app, entity), (125.0, 75.0));
+
+        // Re-equipping replaces instead of stacking
+        app.world_mut()
+            .commands()
+            .apply_item_stats(entity, "boots", &boots);
+        app.update();
+        assert_eq!(stats(&app, entity), (125.0, 75.0));
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.len(), 2);
+        assert_eq!(applied.effects[1].source, "boots");
+
+        app.world_mut()
+            .commands()
+            .remove_item_stats(entity, "boots");
+        app.update();
+        assert_eq!(stats(&app, entity), (105.0, 50.0));
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert!(applied.effects[0].source.is_empty());
+    }
+}
@@ -0,0 +1,83 @@
+//! Level-scaled effects.
+//!
+//! Effects can grow with the target's [`Level`] through
+//! [`StatusEffectApplicator::modifier_for_level`](crate::StatusEffectApplicator::modifier_for_level).
+//! Targets without a `Level` count as level 1.
+
+use bevy::prelude::*;
+
+/// Level or tier of an entity, read when effects are applied to it.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Health(f32);
+///
+/// fn spawn_elite(mut commands: Commands) {
+///     commands.spawn((Health(500.0), Level(10)));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Level(pub u32);
+
+impl Default for Level {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    /// Heals 10% more per level above 1
+    #[derive(Event, Clone, Copy)]
+    struct Heal(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Heal {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+
+        fn modifier_for_level(&self, level: u32) -> ValueModifier {
+            self.0.scaled_by(1.0 + 0.1 * level.saturating_sub(1) as f32)
+        }
+    }
+
+    #[test]
+    fn higher_level_targets_get_larger_effects() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Heal>::default());
+        let novice = app.world_mut().spawn((Health(100.0), Level(1))).id();
+        let veteran = app.world_mut().spawn((Health(100.0), Level(10))).id();
+        let unleveled = app.world_mut().spawn(Health(100.0)).id();
+
+        for entity in [novice, veteran, unleveled] {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Heal(ValueModifier::Val(20.0)));
+        }
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(novice).unwrap().0, 120.0);
+        assert_eq!(app.world().get::<Health>(veteran).unwrap().0, 138.0);
+        assert_eq!(app.world().get::<Health>(unleveled).unwrap().0, 120.0);
+    }
+}
@@ -50,23 +50,242 @@
 //! }
 //! ```
 
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
+use bevy::diagnostic::FrameCount;
 use bevy::ecs::component::Mutable;
 use bevy::ecs::observer::On;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+// Logging goes through these so the `no-log` feature can compile it out. The
+// no-op versions still type-check their arguments without formatting them.
+#[cfg(not(feature = "no-log"))]
+macro_rules! log_warn {
+    ($($arg:tt)*) => { bevy::log::warn!($($arg)*) };
+}
+
+#[cfg(feature = "no-log")]
+macro_rules! log_warn {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+
+#[cfg(not(feature = "no-log"))]
+macro_rules! log_warn_once {
+    ($($arg:tt)*) => { bevy::log::warn_once!($($arg)*) };
+}
+
+#[cfg(feature = "no-log")]
+macro_rules! log_warn_once {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+
+#[cfg(not(feature = "no-log"))]
+macro_rules! log_error {
+    ($($arg:tt)*) => { bevy::log::error!($($arg)*) };
+}
+
+#[cfg(feature = "no-log")]
+macro_rules! log_error {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+
+// With the `strict` feature, invalid effect configurations fail a
+// `debug_assert!` instead of passing silently. Release builds never panic.
+#[cfg(feature = "strict")]
+macro_rules! strict_assert {
+    ($($arg:tt)*) => { debug_assert!($($arg)*) };
+}
+
+#[cfg(not(feature = "strict"))]
+macro_rules! strict_assert {
+    ($($arg:tt)*) => {{}};
+}
+
+mod activity;
+mod attribution;
+mod batch;
+mod breakdown;
+mod channel;
+mod cleanup;
+mod commands;
+mod context;
+mod counter;
+mod cross_stat;
+mod flags;
+mod frame_guard;
+mod get_set;
+#[cfg(feature = "gizmos")]
+mod gizmos;
+mod grant;
+mod immunity;
+mod item;
+mod level;
+mod lock;
+mod mirror;
+mod multi_target;
+mod percent_sum;
+mod periodic;
+mod piecewise;
+mod pipeline;
+mod pool;
+mod preview;
+mod queue;
+#[cfg(feature = "rand")]
+mod random;
+mod reason;
+mod rebalance;
+mod recording;
+mod registry;
+#[cfg(feature = "render")]
+mod render;
+mod requirement;
+mod save;
+mod scaling_modifier;
+mod scaling_scope;
+mod scaling_table;
+mod since_last;
+mod slots;
+mod snapshot;
+mod stacks;
+mod stat_change;
+mod temporary;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod threshold;
+mod throttle;
+mod timed;
+mod tracking;
+mod transform_scale;
+
+pub use activity::{EffectActivity, StatusEffectEvent};
+pub use attribution::ApplyStatusEffectFrom;
+pub use batch::{StatusEffectBatchPlan, StatusEffectBatchPlugin};
+pub use breakdown::{EffectContribution, StatBreakdown, stat_breakdown};
+pub use channel::{SerializableEffect, StatusEffectChannelPlugin, StatusEffectSender};
+pub use cleanup::StatusEffectCleanupPlugin;
+pub use commands::{StatusEffectCommandsExt, StatusEffectEntityCommandsExt};
+pub use context::EffectContext;
+pub use counter::counter_modifier;
+pub use cross_stat::CrossStatApplicator;
+pub use flags::{Dispel, EffectFlags};
+pub use frame_guard::LastAppliedFrame;
+pub use get_set::GetSetApplicator;
+#[cfg(feature = "gizmos")]
+pub use gizmos::{
+    EffectBar, EffectBarSettings, StatusEffectGizmosPlugin, draw_effect_bars, effect_bar,
+};
+pub use grant::{
+    ComponentGrant, ComponentGrants, GrantComponentEffect, GrantComponentPlugin,
+    RevokeComponentEffect,
+};
+pub use immunity::{CleanseAndProtect, Immunity, ImmunityPlugin};
+pub use item::{RemoveItemStats, StatGrant};
+pub use level::Level;
+pub use lock::{LockStatusEffect, StatLocked};
+pub use multi_target::{MultiTargetEffect, MultiTargetPlugin};
+pub use percent_sum::{PercentCombineMode, PercentSum};
+pub use periodic::{
+    ApplyPeriodicEffect, PeriodicEffect, PeriodicEffects, TickWeightFn, tick_fractions, weights,
+};
+pub use piecewise::PiecewiseScaling;
+pub use pipeline::{PipelineContext, Resistance, apply_pipeline};
+pub use pool::{CurrentMax, CurrentMaxApplicator, MaxHealthApplicator};
+pub use preview::{equilibrium, next_stack_value};
+pub use queue::{PendingEffect, StatusEffectDrain, StatusEffectQueue, drain_status_effect_queue};
+#[cfg(feature = "rand")]
+pub use random::{StatusEffectRng, apply_to_random};
+pub use reason::ApplyStatusEffectReason;
+pub use rebalance::RebalanceOnRemove;
+pub use recording::{RecordedEffect, StatusEffectLog, StatusEffectRecorder, replay};
+pub use registry::{ApplyModifierFn, ReadStatFn, StatusEffectRegistry};
+#[cfg(feature = "render")]
+pub use render::{ActiveEffectsSummary, StatusEffectRenderPlugin, update_active_effects_summaries};
+pub use save::{
+    ComponentSave, ScalingSave, StatusEffectSave, TrackerSave, export_world_status_effects,
+    import_world_status_effects,
+};
+pub use scaling_modifier::{ScalingModifierEffect, ScalingMultiplier};
+pub use scaling_scope::with_scaling;
+pub use scaling_table::ScalingTable;
+pub use since_last::LastAppliedTime;
+pub use slots::EffectSlots;
+pub use snapshot::{StatDelta, StatSnapshot, collect_effective, diff_stats, snapshot_stats};
+pub use stacks::{ActiveStacks, StackPolicy};
+pub use stat_change::StatChanged;
+pub use temporary::TemporaryEffects;
+#[cfg(feature = "test-util")]
+pub use test_util::apply_sync;
+pub use threshold::ThresholdApplicator;
+pub use throttle::{StatusEffectThrottle, StatusEffectThrottlePlugin};
+pub use timed::{TimedEffects, TimedStatusEffect};
+pub use tracking::{
+    AppliedEffect, AppliedEffects, RemoveEffectEverywhere, RemoveStatusEffect, StatValue,
+};
+pub use transform_scale::{MIN_SCALE, ScaleAxis, TransformScaleApplicator};
+
+use attribution::SourceEntity;
+use item::ApplySource;
+use reason::ApplyReason;
+use requirement::ComponentPresence;
+use slots::SlotCheck;
+use temporary::ExpiryTag;
+use tracking::StatAccessor;
+
 pub mod prelude {
     pub use bevy::ecs::observer::On;
     pub use bevy_enum_event::EnumEvent;
 
     pub use crate::{
-        ApplyStatusEffect, MutableComponent, StatusEffectApplication, StatusEffectApplicator,
-        StatusEffectPlugin, ValueModifier, scaling, status_effect_observer,
+        AppliedEffects, ApplyPeriodicEffect, ApplyStatusEffect, ApplyStatusEffectFrom,
+        ApplyStatusEffectReason, AutoInsert, AutoInsertMode, CleanseAndProtect,
+        CrossStatApplicator, CurrentMax, CurrentMaxApplicator, DifficultyScale, Dispel,
+        EffectActivity, EffectContext, EffectFlags, EffectSlots, GetSetApplicator,
+        GrantComponentEffect, GrantComponentPlugin, Immunity, ImmunityPlugin, Level,
+        LockStatusEffect, MaxHealthApplicator, MultiTargetEffect, MultiTargetPlugin,
+        MutableComponent, PercentCombineMode, PercentSum, PiecewiseScaling, RebalanceOnRemove,
+        RejectReason, RemoveItemStats, RemoveStatusEffect, RevokeComponentEffect, ScaleAxis,
+        ScalingModifierEffect, ScalingMultiplier, SerializableEffect, StackPolicy, StatChanged,
+        StatGrant, StatLocked, StatValue, StatusEffectApplication, StatusEffectApplicator,
+        StatusEffectApplied, StatusEffectBatchPlan, StatusEffectBatchPlugin,
+        StatusEffectChannelPlugin, StatusEffectCleanupPlugin, StatusEffectCommandsExt,
+        StatusEffectEntityCommandsExt, StatusEffectEvent, StatusEffectLog, StatusEffectObservers,
+        StatusEffectPlugin, StatusEffectQueue, StatusEffectRecorder, StatusEffectRegistry,
+        StatusEffectSave, StatusEffectSender, StatusEffectThrottle, StatusEffectThrottlePlugin,
+        ThresholdApplicator, TimedStatusEffect, TransformScaleApplicator, ValueModifier,
+        collect_effective, counter_modifier, diff_stats, equilibrium, next_stack_value, scaling,
+        snapshot_stats, stat_breakdown, status_effect_observer, weights, with_scaling,
     };
 }
 
+/// Float tolerance for clamps and zero checks.
+///
+/// Results within `EPSILON` of zero are treated as zero, so float error on
+/// stats near zero can't leave tiny negative or positive residues.
+pub const EPSILON: f32 = 1e-6;
+
+/// Clamps values at or below [`EPSILON`] to zero.
+#[inline]
+fn clamp_to_zero(value: f32) -> f32 {
+    if value <= EPSILON { 0.0 } else { value }
+}
+
+/// Bit pattern of `value`, with `-0.0` folded into `0.0`.
+#[inline]
+pub(crate) fn value_bits(value: f32) -> u32 {
+    if value == 0.0 { 0 } else { value.to_bits() }
+}
+
+/// Flips the sign of `value`, keeping zero a positive zero.
+#[inline]
+fn negate(value: f32) -> f32 {
+    if value == 0.0 { 0.0 } else { -value }
+}
+
 /// Power scaling presets for common use cases.
 ///
 /// Power controls how multiple effects combine:
@@ -89,6 +308,162 @@ pub mod scaling {
 
     /// Cube scaling (strong increasing returns)
     pub const CUBE: f32 = 3.0;
+
+    /// Applies many flat and percent modifiers to `base` in a single pass.
+    ///
+    /// Equivalent to applying every flat with
+    /// [`apply_scaled`](crate::ValueModifier::apply_scaled) and then every
+    /// percent, but computed in closed form:
+    /// - **Flats**: `(base^(1/p) ± Σ |val|^(1/p))^p`, clamped to 0 once at the end
+    /// - **Percents**: `Π multiplier^p`, summed as logarithms
+    ///
+    /// Accumulating in `f64` keeps long lists of small percents from drifting
+    /// the way repeated `f32` multiplication does. Since flats are clamped only
+    /// at the end, a subtraction that would hit zero midway can be offset by a
+    /// later addition, unlike in the iterative version. Empty slices return
+    /// `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use msg_status_effect::scaling;
+    ///
+    /// // sqrt(40^2 + 30^2) = 50, then +50% and -20% under sqrt scaling
+    /// let result = scaling::effective_value(40.0, &[30.0], &[50.0, -20.0], scaling::SQRT);
+    /// let expected = 50.0 * (1.5f32 * 0.8).sqrt();
+    /// assert!((result - expected).abs() < 0.001);
+    ///
+    /// assert_eq!(scaling::effective_value(100.0, &[], &[], scaling::SQRT), 100.0);
+    /// ```
+    #[must_use]
+    pub fn effective_value(base: f32, flats: &[f32], percents: &[f32], power: f32) -> f32 {
+        if flats.is_empty() && percents.is_empty() {
+            return base;
+        }
+
+        // Stats are expected to be positive; negative bases keep their sign
+        let sign = if base < 0.0 { -1.0 } else { 1.0 };
+        let power = f64::from(power);
+        let inv_p = 1.0 / power;
+
+        let mut value = f64::from(base.abs());
+        if !flats.is_empty() {
+            let sum = flats.iter().fold(value.powf(inv_p), |sum, &val| {
+                let term = f64::from(val.abs()).powf(inv_p);
+                if val >= 0.0 { sum + term } else { sum - term }
+            });
+            value = if sum <= f64::from(super::EPSILON) {
+                0.0
+            } else {
+                sum.powf(power)
+            };
+        }
+
+        if !percents.is_empty() {
+            let mut log_sum = 0.0;
+            for &percent in percents {
+                if 1.0 + percent / 100.0 <= super::EPSILON {
+                    return 0.0;
+                }
+                log_sum += (f64::from(percent) / 100.0).ln_1p();
+            }
+            value *= (log_sum * power).exp();
+        }
+
+        (value * sign) as f32
+    }
+
+    /// A curve from an effect's accumulated raw magnitude to the stat value.
+    ///
+    /// Generalizes power scaling: flat modifiers add in raw space, so `Val(v)`
+    /// on `current` gives `f(f⁻¹(current) + f⁻¹(v))`, and percents and
+    /// multipliers scale the raw magnitude. With `Power(p)`, `f(x) = x^p`,
+    /// which is exactly [`apply_scaled`](crate::ValueModifier::apply_scaled).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use msg_status_effect::{ValueModifier, scaling::ScalingFn};
+    ///
+    /// // At most 5 stacks count, however many are applied
+    /// let capped = ScalingFn::Custom(|stacks| stacks.min(5.0));
+    /// let value = (0..8).fold(0.0, |value, _| {
+    ///     ValueModifier::Val(1.0).apply_with_curve(value, capped)
+    /// });
+    /// assert_eq!(value, 5.0);
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    pub enum ScalingFn {
+        /// `x^p`, the same as a plain power
+        Power(f32),
+        /// `ln(1 + x)`, diminishing returns without a ceiling
+        Log,
+        /// Any non-decreasing curve with `f(0) = 0`, inverted numerically
+        Custom(fn(f32) -> f32),
+    }
+
+    impl Default for ScalingFn {
+        fn default() -> Self {
+            Self::Power(LINEAR)
+        }
+    }
+
+    impl From<f32> for ScalingFn {
+        fn from(power: f32) -> Self {
+            Self::Power(power)
+        }
+    }
+
+    impl ScalingFn {
+        /// Maps a raw magnitude to a stat value.
+        #[must_use]
+        pub fn eval(&self, raw: f32) -> f32 {
+            match self {
+                Self::Power(power) => raw.powf(*power),
+                Self::Log => raw.ln_1p(),
+                Self::Custom(f) => f(raw),
+            }
+        }
+
+        /// Returns the smallest raw magnitude reaching `value`.
+        ///
+        /// Custom curves are inverted by bisection. Values a custom curve never
+        /// reaches map to `f32::MAX`.
+        #[must_use]
+        pub fn inverse(&self, value: f32) -> f32 {
+            match self {
+                Self::Power(power) => value.powf(1.0 / power),
+                Self::Log => value.exp_m1(),
+                Self::Custom(f) => invert(*f, value),
+            }
+        }
+    }
+
+    /// Finds the smallest `x >= 0` with `f(x) >= value`.
+    fn invert(f: fn(f32) -> f32, value: f32) -> f32 {
+        if f(0.0) >= value {
+            return 0.0;
+        }
+        let mut high = 1.0_f32;
+        while f(high) < value {
+            high *= 2.0;
+            if !high.is_finite() {
+                return f32::MAX;
+            }
+        }
+        let mut low = if high > 1.0 { high / 2.0 } else { 0.0 };
+        loop {
+            let mid = low + (high - low) / 2.0;
+            if mid <= low || mid >= high {
+                return high;
+            }
+            if f(mid) < value {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+    }
 }
 
 /// Modifier for numeric values, supporting both flat and percentage-based changes.
@@ -97,6 +472,9 @@ pub mod scaling {
 /// - `Val(10.0)` adds 10 to the value
 /// - `Percent(50.0)` means +50% = 1.5x multiplier
 /// - `Percent(-10.0)` means -10% = 0.9x multiplier
+/// - `Multiply(2.0)` multiplies the value by 2, the same as `Percent(100.0)`
+/// - `Set(0.0)` sets the value to 0, whatever it was
+/// - `Negate` flips the sign of the value
 ///
 /// # Scaling
 ///
@@ -121,12 +499,18 @@ pub mod scaling {
 /// assert!((result - 122.47).abs() < 0.1);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
-#[reflect(Debug, PartialEq)]
+#[reflect(Debug, PartialEq, Default, Serialize, Deserialize)]
 pub enum ValueModifier {
     /// Flat additive value (e.g., +10 speed)
     Val(f32),
     /// Percentage change in points (e.g., 50 = +50% = 1.5x, -10 = -10% = 0.9x)
     Percent(f32),
+    /// Direct multiplier (e.g., 2.0 = 2x, 0.5 = half); negative factors clamp to 0
+    Multiply(f32),
+    /// Absolute override, ignoring the current value and power (e.g., freeze sets speed to 0)
+    Set(f32),
+    /// Flips the sign of the value, ignoring power (e.g., 100 -> -100)
+    Negate,
 }
 
 impl ValueModifier {
@@ -155,6 +539,9 @@ impl ValueModifier {
         match self {
             Self::Val(v) => current + v,
             Self::Percent(p) => current * (1.0 + p / 100.0),
+            Self::Multiply(factor) => current * factor,
+            Self::Set(target) => *target,
+            Self::Negate => negate(current),
         }
     }
 
@@ -173,6 +560,9 @@ impl ValueModifier {
     /// - **Val (addition)**: `(current^(1/p) + val^(1/p))^p`
     /// - **Val (subtraction)**: `(current^(1/p) - val^(1/p))^p` (clamped to 0)
     /// - **Percent**: `current * multiplier^p` where `multiplier = 1 + percent/100`
+    /// - **Multiply**: `current * factor^p`
+    /// - **Set**: `target`; neither the current value nor power apply
+    /// - **Negate**: `-current`; power doesn't apply to a sign flip
     ///
     /// # Examples
     ///
@@ -180,16 +570,34 @@ impl ValueModifier {
     /// - `Val(30)` on 40: `sqrt(40^2 + 30^2) = 50`
     /// - `Val(-30)` on 40: `sqrt(40^2 - 30^2) = ~26.46`
     /// - `Percent(50)` on 100: `100 * sqrt(1.5) = ~122.47`
+    /// - `Multiply(2)` on 100: `100 * sqrt(2) = ~141.42`
     ///
     /// # Negative Current Values
     ///
     /// Game stats should be positive. Negative current values trigger a warning
-    /// and are treated as positive, with the sign restored at the end.
+    /// and are treated as positive, with the sign restored at the end. `Negate`
+    /// is the exception: it flips negative values back without warning.
+    ///
+    /// # Tolerance
+    ///
+    /// Values within [`EPSILON`] of zero are treated as zero: tiny negative
+    /// inputs don't warn, and clamps and results snap to exactly `0.0`.
     #[must_use]
     pub fn apply_scaled(&self, current: f32, power: f32) -> f32 {
+        let current = if current.abs() <= EPSILON {
+            0.0
+        } else {
+            current
+        };
+        match self {
+            Self::Negate => return negate(current),
+            Self::Set(target) => return *target,
+            _ => {}
+        }
+
         // Game stats should be positive; warn and handle gracefully if not
         let (abs_current, sign) = if current < 0.0 {
-            warn!(
+            log_warn!(
                 "Negative current value {} in apply_scaled; game stats should be positive",
                 current
             );
@@ -209,17 +617,109 @@ impl ValueModifier {
                     (current_term + val_term).powf(power)
                 } else {
                     // Subtracting: (current^(1/p) - val^(1/p))^p, clamped to 0
-                    (current_term - val_term).max(0.0).powf(power)
+                    clamp_to_zero(current_term - val_term).powf(power)
                 }
             }
             Self::Percent(p) => {
                 // Convert percentage points to multiplier: 50 -> 1.5, -10 -> 0.9
-                let multiplier = (1.0 + p / 100.0).max(0.0);
+                let multiplier = clamp_to_zero(1.0 + p / 100.0);
                 abs_current * multiplier.powf(power)
             }
+            Self::Multiply(factor) => abs_current * clamp_to_zero(*factor).powf(power),
+            Self::Set(_) | Self::Negate => abs_current,
+        };
+
+        clamp_to_zero(result) * sign
+    }
+
+    /// Applies this modifier along `curve` instead of a plain power.
+    ///
+    /// `Power` curves are the same as [`apply_scaled`](Self::apply_scaled).
+    /// Otherwise the current value and flat values are mapped back through
+    /// the curve, combined, and mapped forward again; see
+    /// [`ScalingFn`](scaling::ScalingFn). Negative values, `Set` and `Negate`
+    /// are handled as in `apply_scaled`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use msg_status_effect::{ValueModifier, scaling::ScalingFn};
+    ///
+    /// // ln(1 + (e^2 - 1) + (e^1 - 1))
+    /// let value = ValueModifier::Val(1.0).apply_with_curve(2.0, ScalingFn::Log);
+    /// let expected = (2f32.exp_m1() + 1f32.exp_m1()).ln_1p();
+    /// assert!((value - expected).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub fn apply_with_curve(&self, current: f32, curve: scaling::ScalingFn) -> f32 {
+        if let scaling::ScalingFn::Power(power) = curve {
+            return self.apply_scaled(current, power);
+        }
+        let current = if current.abs() <= EPSILON {
+            0.0
+        } else {
+            current
+        };
+        match self {
+            Self::Negate => return negate(current),
+            Self::Set(target) => return *target,
+            _ => {}
+        }
+
+        let (abs_current, sign) = if current < 0.0 {
+            log_warn!(
+                "Negative current value {} in apply_with_curve; game stats should be positive",
+                current
+            );
+            (current.abs(), -1.0)
+        } else {
+            (current, 1.0)
+        };
+
+        let raw = curve.inverse(abs_current);
+        let result = match self {
+            Self::Val(v) => {
+                let term = curve.inverse(v.abs());
+                if *v >= 0.0 {
+                    curve.eval(raw + term)
+                } else {
+                    curve.eval(clamp_to_zero(raw - term))
+                }
+            }
+            Self::Percent(p) => curve.eval(raw * clamp_to_zero(1.0 + p / 100.0)),
+            Self::Multiply(factor) => curve.eval(raw * clamp_to_zero(*factor)),
+            Self::Set(_) | Self::Negate => abs_current,
         };
 
-        result * sign
+        clamp_to_zero(result) * sign
+    }
+
+    /// Returns the flat points that, applied to `base` at `power`, give
+    /// `value`: the inverse of `Val(points).apply_scaled(base, power)`.
+    ///
+    /// Converts a scaled stat back to linear-equivalent points, e.g. to
+    /// combine it with systems that add linearly. Negative when `value` is
+    /// below `base`. A base of zero returns `value`, since every power adds
+    /// the first points linearly. Powers of zero or less have no inverse and
+    /// fall back to `value - base`. Expects non-negative stats.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use msg_status_effect::{ValueModifier, scaling};
+    ///
+    /// let scaled = ValueModifier::Val(30.0).apply_scaled(100.0, scaling::SQRT);
+    /// let points = ValueModifier::to_linear_equivalent(scaled, 100.0, scaling::SQRT);
+    /// assert!((points - 30.0).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn to_linear_equivalent(value: f32, base: f32, power: f32) -> f32 {
+        if power <= 0.0 {
+            return value - base;
+        }
+        let inv_p = 1.0 / power;
+        let diff = value.max(0.0).powf(inv_p) - base.max(0.0).powf(inv_p);
+        diff.abs().powf(power).copysign(diff)
     }
 
     /// Returns the flat value if this is a Val modifier, otherwise 0.
@@ -228,7 +728,7 @@ impl ValueModifier {
     pub fn flat_value(&self) -> f32 {
         match self {
             Self::Val(v) => *v,
-            Self::Percent(_) => 0.0,
+            Self::Percent(_) | Self::Multiply(_) | Self::Set(_) | Self::Negate => 0.0,
         }
     }
 
@@ -237,11 +737,42 @@ impl ValueModifier {
     #[must_use]
     pub fn percent_value(&self) -> f32 {
         match self {
-            Self::Val(_) => 0.0,
+            Self::Val(_) | Self::Multiply(_) | Self::Set(_) | Self::Negate => 0.0,
             Self::Percent(p) => *p,
         }
     }
 
+    /// Returns the factor if this is a Multiply modifier, otherwise 1.
+    #[inline]
+    #[must_use]
+    pub fn multiply_value(&self) -> f32 {
+        match self {
+            Self::Multiply(factor) => *factor,
+            Self::Val(_) | Self::Percent(_) | Self::Set(_) | Self::Negate => 1.0,
+        }
+    }
+
+    /// Returns the target if this is a Set modifier.
+    #[inline]
+    #[must_use]
+    pub fn set_value(&self) -> Option<f32> {
+        match self {
+            Self::Set(target) => Some(*target),
+            Self::Val(_) | Self::Percent(_) | Self::Multiply(_) | Self::Negate => None,
+        }
+    }
+
+    /// Returns the signed strength in flat or percentage points, counting a
+    /// multiplier as its percent equivalent: positive for buffs, negative for
+    /// debuffs. Overrides and sign flips have no direction and count as 0.
+    pub(crate) fn signed_points(&self) -> f32 {
+        match self {
+            Self::Val(v) | Self::Percent(v) => *v,
+            Self::Multiply(factor) => (factor - 1.0) * 100.0,
+            Self::Set(_) | Self::Negate => 0.0,
+        }
+    }
+
     /// Returns true if this is a flat value modifier.
     #[inline]
     #[must_use]
@@ -256,13 +787,145 @@ impl ValueModifier {
         matches!(self, Self::Percent(_))
     }
 
+    /// Returns true if this is a direct multiplier.
+    #[inline]
+    #[must_use]
+    pub fn is_multiply(&self) -> bool {
+        matches!(self, Self::Multiply(_))
+    }
+
+    /// Returns true if this modifier overrides the value.
+    #[inline]
+    #[must_use]
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    /// Returns true if this modifier flips the sign of the value.
+    #[inline]
+    #[must_use]
+    pub fn is_negate(&self) -> bool {
+        matches!(self, Self::Negate)
+    }
+
+    /// Returns true if both modifiers are the same variant with bit-identical
+    /// values, for detecting duplicate stacks.
+    ///
+    /// `0.0` and `-0.0` count as the same value. NaN modifiers are never the
+    /// same as anything, themselves included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use msg_status_effect::ValueModifier;
+    ///
+    /// assert!(ValueModifier::Val(10.0).same_effect(&ValueModifier::Val(10.0)));
+    /// assert!(!ValueModifier::Val(10.0).same_effect(&ValueModifier::Percent(10.0)));
+    /// assert!(!ValueModifier::Val(f32::NAN).same_effect(&ValueModifier::Val(f32::NAN)));
+    /// ```
+    #[must_use]
+    pub fn same_effect(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Val(a), Self::Val(b))
+            | (Self::Percent(a), Self::Percent(b))
+            | (Self::Multiply(a), Self::Multiply(b))
+            | (Self::Set(a), Self::Set(b)) => !a.is_nan() && value_bits(*a) == value_bits(*b),
+            (Self::Negate, Self::Negate) => true,
+            _ => false,
+        }
+    }
+
+    /// Feeds the variant and value bits into `state`.
+    ///
+    /// Consistent with [`same_effect`](Self::same_effect): modifiers that are
+    /// the same effect hash the same, so they can key a dedup map.
+    pub fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        let value = match self {
+            Self::Val(v) | Self::Percent(v) | Self::Multiply(v) | Self::Set(v) => *v,
+            Self::Negate => 0.0,
+        };
+        value_bits(value).hash(state);
+    }
+
     /// Returns a new modifier with the value scaled by the given factor.
+    ///
+    /// A multiplier scales its distance from 1, like the equivalent percent:
+    /// `Multiply(1.5)` scaled by 0.5 is `Multiply(1.25)`. `Set` and `Negate`
+    /// are absolute, not magnitudes, and are returned as is: an effect that
+    /// sets a stat to 0 or a fixed cap keeps its target under difficulty and
+    /// meta-buff scaling.
     #[inline]
     #[must_use]
     pub fn scaled_by(&self, factor: f32) -> Self {
         match self {
             Self::Val(v) => Self::Val(v * factor),
             Self::Percent(p) => Self::Percent(p * factor),
+            Self::Multiply(m) => Self::Multiply(1.0 + (m - 1.0) * factor),
+            Self::Set(_) | Self::Negate => *self,
+        }
+    }
+
+    /// Expresses this modifier as a percentage of `base`.
+    ///
+    /// `Val(v)` becomes `Percent(v / base * 100)`, the percent that moves
+    /// `base` by the same amount. Percents are returned as is and multipliers
+    /// as their percent equivalent, whatever the base. Returns `None`
+    /// for `Set`, `Negate` and a `base` within [`EPSILON`] of zero, which no
+    /// percent can move.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use msg_status_effect::ValueModifier;
+    ///
+    /// assert_eq!(ValueModifier::Val(10.0).as_percent_of(100.0), Some(ValueModifier::Percent(10.0)));
+    /// assert_eq!(ValueModifier::Val(10.0).as_percent_of(0.0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_percent_of(&self, base: f32) -> Option<Self> {
+        match self {
+            Self::Val(_) if base.abs() <= EPSILON => None,
+            Self::Val(v) => Some(Self::Percent(v / base * 100.0)),
+            Self::Percent(_) => Some(*self),
+            Self::Multiply(factor) => Some(Self::Percent((factor - 1.0) * 100.0)),
+            Self::Set(_) | Self::Negate => None,
+        }
+    }
+
+    /// Returns the portion of this per-second modifier that applies over `dt` seconds.
+    ///
+    /// Use this for continuous effects applied every frame (drains, regeneration)
+    /// so the total change per second is independent of the frame rate:
+    /// - **Val**: linear, `Val(v * dt)`
+    /// - **Percent**: exponential, the multiplier becomes `(1 + p/100)^dt`
+    /// - **Multiply**: exponential, the factor becomes `factor^dt`
+    /// - **Set** and **Negate**: can't be split and are returned as is
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use msg_status_effect::ValueModifier;
+    ///
+    /// // Drain 10 per second, half a second elapsed
+    /// assert_eq!(ValueModifier::Val(-10.0).scaled_by_dt(0.5), ValueModifier::Val(-5.0));
+    ///
+    /// // +21% per second applied as two half-second steps is +10% each
+    /// let step = ValueModifier::Percent(21.0).scaled_by_dt(0.5);
+    /// assert!((step.percent_value() - 10.0).abs() < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn scaled_by_dt(&self, dt: f32) -> Self {
+        match self {
+            Self::Val(v) => Self::Val(v * dt),
+            Self::Percent(p) => {
+                let multiplier = clamp_to_zero(1.0 + p / 100.0);
+                Self::Percent((multiplier.powf(dt) - 1.0) * 100.0)
+            }
+            Self::Multiply(factor) => Self::Multiply(clamp_to_zero(*factor).powf(dt)),
+            Self::Set(_) | Self::Negate => *self,
         }
     }
 }
@@ -274,6 +937,9 @@ impl Default for ValueModifier {
 }
 
 /// Trait alias for mutable components that can have effects applied.
+///
+/// Covers both table and sparse-set storage. Auto-insertion, change detection
+/// and tracking behave the same for either.
 pub trait MutableComponent: Component<Mutability = Mutable> {}
 impl<C: Component<Mutability = Mutable>> MutableComponent for C {}
 
@@ -302,18 +968,89 @@ impl<C: Component<Mutability = Mutable>> MutableComponent for C {}
 /// let config = StatusEffectApplication::<Health>::with_power(0.7);
 /// assert!((config.power - 0.7).abs() < 0.001);
 /// ```
-#[derive(Resource)]
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
 pub struct StatusEffectApplication<C: MutableComponent> {
     /// Power scaling for effect application
     pub power: f32,
+    /// `(positive, negative)` powers replacing `power` by the modifier's sign
+    sign_powers: Option<(f32, f32)>,
+    /// Queue applications and apply them once per frame in dependency order
+    pub deferred: bool,
+    /// Merge identical queued applications before applying them
+    pub coalesce: bool,
+    /// Apply each effect type at most once per entity per frame
+    pub once_per_frame: bool,
+    /// Schedule deferred applications drain in, `PostUpdate` if unset
+    #[reflect(ignore)]
+    schedule: Option<InternedScheduleLabel>,
+    /// Inclusive bounds applied values are clamped to
+    bounds: Option<(f32, f32)>,
+    /// Round applied values to the nearest integer
+    round: bool,
+    /// Sum percent modifiers in a `PercentSum<C>` instead of multiplying
+    additive_percents: bool,
+    /// How percent reductions combine when percents aren't all additive
+    reductions: PercentCombineMode,
+    /// Per-segment powers replacing `power` for this component
+    piecewise: Option<PiecewiseScaling>,
+    /// Curve replacing `power` for this component
+    #[reflect(ignore)]
+    curve: Option<scaling::ScalingFn>,
+    /// Where the value comes from when a missing component is auto-inserted
+    pub auto_insert: AutoInsertMode,
+    /// Record applications in an `AppliedEffects<C>` component
+    tracked: bool,
+    /// Pass the time since each effect type's last application to the effect
+    since_last: bool,
+    /// Write [`StatusEffectEvent`] messages for activity on this component
+    events: bool,
+    /// Trigger [`StatChanged<C>`] once per frame when the stat moved
+    report_changes: bool,
+    /// Handle [`LockStatusEffect<C>`] and expire timed locks
+    lockable: bool,
+    /// Handle [`ScalingModifierEffect<C>`] and expire timed multipliers
+    amplifiable: bool,
+    /// Accept percent modifiers below -100% in strict mode
+    excess_reductions: bool,
+    /// Stat accessors, present when the component's value is readable
+    #[reflect(ignore)]
+    stat: Option<StatAccessor<C>>,
     /// Phantom data for the component type
+    #[reflect(ignore)]
     _marker: PhantomData<C>,
 }
 
 impl<C: MutableComponent> Default for StatusEffectApplication<C> {
     fn default() -> Self {
+        Self::with_power(scaling::LINEAR)
+    }
+}
+
+impl<C: MutableComponent> Clone for StatusEffectApplication<C> {
+    fn clone(&self) -> Self {
         Self {
-            power: scaling::LINEAR,
+            power: self.power,
+            sign_powers: self.sign_powers,
+            deferred: self.deferred,
+            coalesce: self.coalesce,
+            once_per_frame: self.once_per_frame,
+            schedule: self.schedule,
+            bounds: self.bounds,
+            round: self.round,
+            additive_percents: self.additive_percents,
+            reductions: self.reductions,
+            piecewise: self.piecewise.clone(),
+            curve: self.curve,
+            auto_insert: self.auto_insert,
+            tracked: self.tracked,
+            since_last: self.since_last,
+            events: self.events,
+            report_changes: self.report_changes,
+            lockable: self.lockable,
+            amplifiable: self.amplifiable,
+            excess_reductions: self.excess_reductions,
+            stat: self.stat,
             _marker: PhantomData,
         }
     }
@@ -325,133 +1062,557 @@ impl<C: MutableComponent> StatusEffectApplication<C> {
     pub fn with_power(power: f32) -> Self {
         Self {
             power,
+            sign_powers: None,
+            deferred: false,
+            coalesce: false,
+            once_per_frame: false,
+            schedule: None,
+            bounds: None,
+            round: false,
+            additive_percents: false,
+            reductions: PercentCombineMode::Multiplicative,
+            piecewise: None,
+            curve: None,
+            auto_insert: AutoInsertMode::Default,
+            tracked: false,
+            since_last: false,
+            events: true,
+            report_changes: false,
+            lockable: false,
+            amplifiable: false,
+            excess_reductions: false,
+            stat: None,
             _marker: PhantomData,
         }
     }
 
-    /// Creates a config with square root scaling (diminishing returns).
+    /// Creates a config with a power blended between `p0` and `p1`.
+    ///
+    /// The power is `lerp(p0, p1, t)`, with `t` clamped to `[0, 1]`. Useful for
+    /// sliders that morph between two curves, e.g. sqrt to linear.
     #[must_use]
-    pub fn sqrt() -> Self {
-        Self::with_power(scaling::SQRT)
+    pub fn with_blended_power(p0: f32, p1: f32, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self::with_power(p0 + (p1 - p0) * t)
     }
 
-    /// Creates a config with cube root scaling (strong diminishing returns).
+    /// Reads the component's value through its [`StatValue`] impl.
+    ///
+    /// Lets [`StatusEffectApplied`] report
+    /// [`scaling_loss`](StatusEffectApplied::scaling_loss). Implied by
+    /// [`tracked`](Self::tracked).
     #[must_use]
-    pub fn cube_root() -> Self {
-        Self::with_power(scaling::CUBE_ROOT)
+    pub fn with_stat_value(mut self) -> Self
+    where
+        C: StatValue,
+    {
+        self.stat = Some(StatAccessor::of());
+        self
     }
 
-    /// Creates a config with linear scaling (no diminishing returns).
+    /// Tracks applications in an [`AppliedEffects<C>`] component.
+    ///
+    /// The tracker stores the stat value from before the first effect and every
+    /// modifier applied since, read through the component's [`StatValue`] impl.
     #[must_use]
-    pub fn linear() -> Self {
-        Self::with_power(scaling::LINEAR)
+    pub fn tracked(mut self) -> Self
+    where
+        C: StatValue,
+    {
+        self.tracked = true;
+        self.with_stat_value()
     }
 
-    /// Creates a config with square scaling (increasing returns).
+    /// Clamps applied values to `[min, max]`.
+    ///
+    /// Runs after the scaled apply and before rounding; see the
+    /// [pipeline](apply_pipeline) for the full order.
     #[must_use]
-    pub fn square() -> Self {
-        Self::with_power(scaling::SQUARE)
+    pub fn clamped(mut self, min: f32, max: f32) -> Self
+    where
+        C: StatValue,
+    {
+        self.bounds = Some((min, max));
+        self.with_stat_value()
     }
-}
 
-/// Trait linking effect types to their target components.
-///
-/// Implement this trait to define how an effect modifies a specific component.
-///
-/// # Example
-///
-/// ```rust
-/// use bevy::prelude::*;
-/// use msg_status_effect::prelude::*;
-///
-/// #[derive(Component)]
-/// struct Speed(f32);
-///
-/// #[derive(Event, Clone, Copy)]
-/// struct SpeedModifier(ValueModifier);
-///
-/// impl StatusEffectApplicator<Speed> for SpeedModifier {
-///     fn modifier(&self) -> ValueModifier {
-///         self.0
-///     }
-///
-///     fn apply(&self, component: &mut Speed, power: f32) {
-///         component.0 = self.0.apply_scaled(component.0, power);
-///     }
-/// }
-///
-/// // Test the implementation
-/// let effect = SpeedModifier(ValueModifier::Percent(50.0));
-/// let mut speed = Speed(100.0);
-/// effect.apply(&mut speed, 1.0);
-/// assert!((speed.0 - 150.0).abs() < 0.001);
-/// ```
-pub trait StatusEffectApplicator<C: MutableComponent>: Event + Clone {
-    /// Get the value modifier from the effect.
-    fn modifier(&self) -> ValueModifier;
+    /// Clamps applied values to optional bounds, e.g. `(Some(0.0), None)` to
+    /// keep a stat from going negative.
+    ///
+    /// A missing side is unbounded, and both `None` removes the bounds.
+    /// Replaces any bounds set with [`clamped`](Self::clamped).
+    #[must_use]
+    pub fn with_bounds(mut self, min: Option<f32>, max: Option<f32>) -> Self
+    where
+        C: StatValue,
+    {
+        if min.is_none() && max.is_none() {
+            self.bounds = None;
+            return self;
+        }
+        self.clamped(min.unwrap_or(f32::MIN), max.unwrap_or(f32::MAX))
+    }
 
-    /// Apply the effect to the component with the given power scaling.
-    fn apply(&self, component: &mut C, power: f32);
-}
+    /// Rounds applied values to the nearest integer, after clamping.
+    #[must_use]
+    pub fn rounded(mut self) -> Self
+    where
+        C: StatValue,
+    {
+        self.round = true;
+        self.with_stat_value()
+    }
 
-/// Generic event wrapper for applying status effects to entities.
-///
-/// Similar to `Enter<T>`/`Exit<T>` from bevy_fsm, this wrapper provides
-/// type-safe effect application.
-///
-/// # Usage
+    /// Sums percent modifiers instead of multiplying them.
+    ///
+    /// The value becomes `base * (1 + sum / 100)`, with the sum kept in a
+    /// [`PercentSum<C>`]. Apply the negated percent to remove one again.
+    /// Percents apply through the component's [`StatValue`] impl rather than
+    /// the effect's [`apply`](StatusEffectApplicator::apply) or
+    /// [`apply_from`](StatusEffectApplicator::apply_from), so custom logic
+    /// there is skipped for them.
+    #[must_use]
+    pub fn additive_percents(mut self) -> Self
+    where
+        C: StatValue,
+    {
+        self.additive_percents = true;
+        self.with_stat_value()
+    }
+
+    /// Chooses how percent reductions combine, leaving buffs multiplicative.
+    ///
+    /// With [`PercentCombineMode::Additive`], reductions add up in a
+    /// [`PercentSum<C>`] like [`additive_percents`](Self::additive_percents)
+    /// does for every percent, so two -50% slows reach zero instead of 25%.
+    /// Tracked stats are rebuilt with the same rule. Summed reductions skip
+    /// the effect's [`apply`](StatusEffectApplicator::apply) the same way.
+    #[must_use]
+    pub fn combine_reductions(mut self, mode: PercentCombineMode) -> Self
+    where
+        C: StatValue,
+    {
+        self.reductions = mode;
+        self.with_stat_value()
+    }
+
+    /// Returns true if `modifier` goes into the [`PercentSum<C>`] instead of
+    /// applying directly.
+    pub(crate) fn sums_percent(&self, modifier: ValueModifier) -> bool {
+        match modifier {
+            ValueModifier::Percent(p) => {
+                self.additive_percents
+                    || (p < 0.0 && self.reductions == PercentCombineMode::Additive)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if any percents go into a [`PercentSum<C>`].
+    fn uses_percent_sum(&self) -> bool {
+        self.additive_percents || self.reductions == PercentCombineMode::Additive
+    }
+
+    /// Scales applications along `curve` instead of a single power.
+    ///
+    /// Each segment of the stat's value range gets its own power, and effects
+    /// crossing a breakpoint are split there; see [`PiecewiseScaling`].
+    /// Effects with their own power, from
+    /// [`kind_powers`](StatusEffectApplicator::kind_powers) or a power
+    /// override, ignore the curve. Applies through the component's
+    /// [`StatValue`] impl rather than the effect's
+    /// [`apply`](StatusEffectApplicator::apply). Replaces any curve set with
+    /// [`with_curve`](Self::with_curve).
+    #[must_use]
+    pub fn piecewise(mut self, curve: PiecewiseScaling) -> Self
+    where
+        C: StatValue,
+    {
+        self.piecewise = Some(curve);
+        self.curve = None;
+        self.with_stat_value()
+    }
+
+    /// Scales applications along `curve` instead of a plain power, e.g.
+    /// [`ScalingFn::Log`](scaling::ScalingFn::Log) or a custom cap.
+    ///
+    /// [`ScalingFn::Power`](scaling::ScalingFn::Power) only sets
+    /// [`power`](Self::power). Other curves replace any
+    /// [`piecewise`](Self::piecewise) one and, like it, are ignored by
+    /// effects with their own power and apply through the component's
+    /// [`StatValue`] impl.
+    #[must_use]
+    pub fn with_curve(mut self, curve: scaling::ScalingFn) -> Self
+    where
+        C: StatValue,
+    {
+        if let scaling::ScalingFn::Power(power) = curve {
+            self.power = power;
+            self.curve = None;
+            return self;
+        }
+        self.curve = Some(curve);
+        self.piecewise = None;
+        self.with_stat_value()
+    }
+
+    /// Returns the curve applications scale along.
+    ///
+    /// `Power(power)` unless set with [`with_curve`](Self::with_curve),
+    /// including for [`piecewise`](Self::piecewise) configs.
+    #[must_use]
+    pub fn scaling(&self) -> scaling::ScalingFn {
+        self.curve.unwrap_or(scaling::ScalingFn::Power(self.power))
+    }
+
+    /// Scales positive and negative modifiers with separate powers, e.g.
+    /// diminishing buffs and linear debuffs.
+    ///
+    /// The sign is the modifier's direction: `Percent(-50)` and
+    /// `Multiply(0.5)` are negative. Modifiers without one, `Set`, `Negate`
+    /// and zero modifiers, keep [`power`](Self::power). Effects with their
+    /// own power, and piecewise or custom curves, take precedence.
+    #[must_use]
+    pub fn with_sign_powers(mut self, positive_power: f32, negative_power: f32) -> Self {
+        self.sign_powers = Some((positive_power, negative_power));
+        self
+    }
+
+    /// Returns the power for positive modifiers, `power` unless set with
+    /// [`with_sign_powers`](Self::with_sign_powers).
+    #[must_use]
+    pub fn positive_power(&self) -> f32 {
+        self.sign_powers
+            .map_or(self.power, |(positive, _)| positive)
+    }
+
+    /// Returns the power for negative modifiers, `power` unless set with
+    /// [`with_sign_powers`](Self::with_sign_powers).
+    #[must_use]
+    pub fn negative_power(&self) -> f32 {
+        self.sign_powers
+            .map_or(self.power, |(_, negative)| negative)
+    }
+
+    /// Returns the power `modifier` applies at, picked by its sign.
+    #[must_use]
+    pub fn power_for(&self, modifier: ValueModifier) -> f32 {
+        let points = modifier.signed_points();
+        if points > 0.0 {
+            self.positive_power()
+        } else if points < 0.0 {
+            self.negative_power()
+        } else {
+            self.power
+        }
+    }
+
+    /// Returns true if a piecewise or custom curve replaces `power`.
+    pub(crate) fn uses_curve(&self) -> bool {
+        self.piecewise.is_some() || self.curve.is_some()
+    }
+
+    /// Applies `modifier` to `value` along the piecewise or custom curve, if
+    /// one is set.
+    pub(crate) fn apply_curve(&self, value: f32, modifier: ValueModifier) -> Option<f32> {
+        match (&self.piecewise, self.curve) {
+            (Some(curve), _) => Some(curve.apply(value, modifier)),
+            (None, Some(curve)) => Some(modifier.apply_with_curve(value, curve)),
+            (None, None) => None,
+        }
+    }
+
+    /// Applies one tracked entry to `value` at its recorded power, or along
+    /// the configured curve if it has none.
+    fn apply_tracked(&self, value: f32, effect: &AppliedEffect) -> f32 {
+        let modifier = effect.weighted_modifier();
+        match effect.power {
+            None => self
+                .apply_curve(value, modifier)
+                .unwrap_or_else(|| modifier.apply_scaled(value, self.power_for(modifier))),
+            Some(power) => modifier.apply_scaled(value, power),
+        }
+    }
+
+    /// Replays tracked entries from the base, yielding the value after each
+    /// one before and after clamping and rounding.
+    ///
+    /// Each entry is finished before the next one applies, as it was live.
+    /// Summed percents go through `sum`, reset first, as they did live.
+    pub(crate) fn replay_steps<'a>(
+        &'a self,
+        applied: &'a AppliedEffects<C>,
+        sum: &'a mut PercentSum<C>,
+    ) -> impl Iterator<Item = (f32, f32)> + 'a {
+        *sum = PercentSum::default();
+        let ctx = self.pipeline(self.power, 0.0);
+        applied
+            .effects
+            .iter()
+            .scan(applied.base, move |value, effect| {
+                let modifier = effect.weighted_modifier();
+                let raw = if self.sums_percent(modifier) {
+                    let power = effect.power.unwrap_or_else(|| self.power_for(modifier));
+                    sum.add(*value, modifier.percent_value(), power)
+                } else {
+                    self.apply_tracked(*value, effect)
+                };
+                *value = ctx.finish(raw);
+                Some((raw, *value))
+            })
+    }
+
+    /// Replays every tracked entry from the base, clamping and rounding after
+    /// each.
+    pub(crate) fn replay(&self, applied: &AppliedEffects<C>) -> f32 {
+        self.replay_into(applied, &mut PercentSum::default())
+    }
+
+    /// Replays like [`replay`](Self::replay), leaving the replayed base and
+    /// sum of summed percents in `sum`.
+    pub(crate) fn replay_into(&self, applied: &AppliedEffects<C>, sum: &mut PercentSum<C>) -> f32 {
+        self.replay_steps(applied, sum)
+            .last()
+            .map_or(applied.base, |(_, finished)| finished)
+    }
+
+    /// Returns the pipeline settings for one application.
+    #[must_use]
+    pub fn pipeline(&self, power: f32, resistance: f32) -> PipelineContext {
+        PipelineContext {
+            power,
+            resistance,
+            bounds: self.bounds,
+            round: self.round,
+        }
+    }
+
+    /// Returns true if applications are tracked.
+    #[must_use]
+    pub fn is_tracked(&self) -> bool {
+        self.tracked
+    }
+
+    /// Reads the component's stat value, if readable.
+    #[must_use]
+    pub fn stat_value(&self, component: &C) -> Option<f32> {
+        self.stat.map(|stat| (stat.get)(component))
+    }
+
+    /// Writes the component's stat value. Does nothing if not readable.
+    pub fn set_stat_value(&self, component: &mut C, value: f32) {
+        if let Some(stat) = self.stat {
+            (stat.set)(component, value);
+        }
+    }
+
+    /// Requires an explicit [`AutoInsert<C>`] resource for auto-insertion.
+    ///
+    /// Use this when `C::default()` isn't a meaningful starting value, so the
+    /// inserted value is always intentional. See [`AutoInsertMode::Resource`].
+    #[must_use]
+    pub fn explicit_auto_insert(mut self) -> Self {
+        self.auto_insert = AutoInsertMode::Resource;
+        self
+    }
+
+    /// Defers applications to the [`StatusEffectQueue`].
+    ///
+    /// Deferred effects are applied once per frame in [`PostUpdate`], after
+    /// every effect type they declare in
+    /// [`depends_on`](StatusEffectApplicator::depends_on).
+    #[must_use]
+    pub fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+
+    /// Defers applications and coalesces identical ones within a frame.
+    ///
+    /// Queued applications of the same effect type on the same entity are
+    /// merged before the drain, one per modifier kind: flats are summed and
+    /// percent multipliers multiplied. Ten `Val(1)` ticks apply as one
+    /// `Val(10)`, which differs from ten separate ticks under non-linear power.
+    /// Effects must implement
+    /// [`from_modifier`](StatusEffectApplicator::from_modifier) to be merged.
+    /// `Negate` applications are never merged.
+    #[must_use]
+    pub fn coalesced(mut self) -> Self {
+        self.deferred = true;
+        self.coalesce = true;
+        self
+    }
+
+    /// Defers applications and drains them in `schedule` instead of [`PostUpdate`].
+    ///
+    /// The drain runs in the [`StatusEffectDrain`] set. Triggers from systems
+    /// ordered before it in `schedule`, or from earlier schedules, apply the
+    /// same frame; later ones apply on the next frame's drain.
+    #[must_use]
+    pub fn applied_in(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.deferred = true;
+        self.schedule = Some(schedule.intern());
+        self
+    }
+
+    /// Applies each effect type at most once per entity per frame.
+    ///
+    /// Further applications of the same type in that frame are dropped, which
+    /// guards against accidental multi-triggering. Effects that legitimately
+    /// repeat can opt out with
+    /// [`repeats_per_frame`](StatusEffectApplicator::repeats_per_frame).
+    #[must_use]
+    pub fn once_per_frame(mut self) -> Self {
+        self.once_per_frame = true;
+        self
+    }
+
+    /// Accepts percent modifiers below -100% under the `strict` feature.
+    ///
+    /// Such reductions clamp to zero like -100% does, so strict mode treats
+    /// them as a likely sign error unless allowed here.
+    #[must_use]
+    pub fn allow_excess_reductions(mut self) -> Self {
+        self.excess_reductions = true;
+        self
+    }
+
+    /// Scales effects by the time since their type was last applied.
+    ///
+    /// Each application passes the seconds since the previous one of the same
+    /// type on that entity to
+    /// [`modifier_since_last`](StatusEffectApplicator::modifier_since_last),
+    /// recorded in a [`LastAppliedTime<E>`] component.
+    #[must_use]
+    pub fn since_last(mut self) -> Self {
+        self.since_last = true;
+        self
+    }
+
+    /// Stops writing [`StatusEffectEvent`] messages for this component.
+    ///
+    /// For effects applied so often, e.g. every frame, that they would flood
+    /// the message stream.
+    #[must_use]
+    pub fn without_events(mut self) -> Self {
+        self.events = false;
+        self
+    }
+
+    /// Triggers a [`StatChanged<C>`] at the end of every frame in which an
+    /// entity's stat moved, e.g. for UI bars that tween toward the new value.
+    ///
+    /// Several changes within one frame are reported as one, from the value at
+    /// the end of the previous frame. Changes from outside effects count too.
+    #[must_use]
+    pub fn reports_changes(mut self) -> Self
+    where
+        C: StatValue,
+    {
+        self.report_changes = true;
+        self.with_stat_value()
+    }
+
+    /// Handles [`LockStatusEffect<C>`] and lets timed [`StatLocked<C>`] run out.
+    ///
+    /// Inserted locks block effects either way; this only adds the observer
+    /// and the timer system.
+    #[must_use]
+    pub fn lockable(mut self) -> Self {
+        self.lockable = true;
+        self
+    }
+
+    /// Handles [`ScalingModifierEffect<C>`] and lets timed
+    /// [`ScalingMultiplier<C>`] run out.
+    ///
+    /// Inserted multipliers scale effects either way; this only adds the
+    /// observer and the timer system.
+    #[must_use]
+    pub fn amplifiable(mut self) -> Self {
+        self.amplifiable = true;
+        self
+    }
+
+    /// Creates a config with square root scaling (diminishing returns).
+    #[must_use]
+    pub fn sqrt() -> Self {
+        Self::with_power(scaling::SQRT)
+    }
+
+    /// Creates a config with cube root scaling (strong diminishing returns).
+    #[must_use]
+    pub fn cube_root() -> Self {
+        Self::with_power(scaling::CUBE_ROOT)
+    }
+
+    /// Creates a config with linear scaling (no diminishing returns).
+    #[must_use]
+    pub fn linear() -> Self {
+        Self::with_power(scaling::LINEAR)
+    }
+
+    /// Creates a config with square scaling (increasing returns).
+    #[must_use]
+    pub fn square() -> Self {
+        Self::with_power(scaling::SQUARE)
+    }
+}
+
+/// Source of the value inserted when an effect targets an entity missing the component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum AutoInsertMode {
+    /// Insert `C::default()`
+    #[default]
+    Default,
+    /// Insert the value held by the [`AutoInsert<C>`] resource.
+    ///
+    /// If the resource is missing, the effect is dropped with an error.
+    Resource,
+}
+
+/// Explicit value inserted for components using [`AutoInsertMode::Resource`].
+///
+/// # Example
 ///
 /// ```rust
 /// use bevy::prelude::*;
 /// use msg_status_effect::prelude::*;
 ///
-/// #[derive(Event, Clone, Copy)]
-/// struct SpeedModifier(ValueModifier);
+/// #[derive(Component, Clone, Default)]
+/// struct Speed(f32);
 ///
-/// // In a system, you would trigger it like this:
-/// fn apply_speed_boost(mut commands: Commands, entity: Entity) {
-///     commands.trigger(ApplyStatusEffect {
-///         effect: SpeedModifier(ValueModifier::Percent(50.0)),
-///         entity,
-///     });
-/// }
+/// let mut app = App::new();
+/// // Entities without Speed start at 100 instead of 0
+/// app.insert_resource(AutoInsert::new(Speed(100.0)));
 /// ```
-#[derive(EntityEvent, Clone, Copy)]
-pub struct ApplyStatusEffect<E: Event + Clone> {
-    /// The effect to apply
-    pub effect: E,
-    /// The target entity
-    pub entity: Entity,
+#[derive(Resource)]
+pub struct AutoInsert<C: MutableComponent> {
+    value: Box<dyn Fn() -> C + Send + Sync>,
 }
 
-/// Generic observer that handles any `ApplyStatusEffect<E>` for component C.
-///
-/// If the target entity doesn't have the component, it will be automatically
-/// inserted with its default value before applying the effect.
-fn apply_status_effect_observer<C, E>(
-    on: On<ApplyStatusEffect<E>>,
-    config: Res<StatusEffectApplication<C>>,
-    mut q: Query<&mut C>,
-    mut commands: Commands,
-) where
-    C: MutableComponent + Default,
-    E: Event + Clone + StatusEffectApplicator<C>,
-{
-    let entity = on.entity;
-    if let Ok(mut component) = q.get_mut(entity) {
-        on.effect.apply(&mut component, config.power);
-    } else if let Ok(mut entity_commands) = commands.get_spawned_entity(entity) {
-        // Entity is spawned but missing component - insert default and re-trigger
-        entity_commands.insert(C::default());
-        commands.trigger(ApplyStatusEffect {
-            effect: on.effect.clone(),
-            entity,
-        });
+impl<C: MutableComponent> AutoInsert<C> {
+    /// Creates the resource from the value to insert.
+    #[must_use]
+    pub fn new(value: C) -> Self
+    where
+        C: Clone,
+    {
+        Self {
+            value: Box::new(move || value.clone()),
+        }
+    }
+
+    /// Returns a fresh copy of the value to insert.
+    #[must_use]
+    pub fn value(&self) -> C {
+        (self.value)()
     }
-    // If entity doesn't exist or isn't spawned yet, silently ignore
 }
 
-/// Plugin for registering a status effect for a specific component.
+/// Trait linking effect types to their target components.
+///
+/// Implement this trait to define how an effect modifies a specific component.
 ///
 /// # Example
 ///
@@ -459,98 +1620,468 @@ fn apply_status_effect_observer<C, E>(
 /// use bevy::prelude::*;
 /// use msg_status_effect::prelude::*;
 ///
-/// // Define component and effect (component must implement Default)
-/// #[derive(Component, Default)]
+/// #[derive(Component)]
 /// struct Speed(f32);
 ///
 /// #[derive(Event, Clone, Copy)]
 /// struct SpeedModifier(ValueModifier);
 ///
 /// impl StatusEffectApplicator<Speed> for SpeedModifier {
-///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn modifier(&self) -> ValueModifier {
+///         self.0
+///     }
+///
 ///     fn apply(&self, component: &mut Speed, power: f32) {
 ///         component.0 = self.0.apply_scaled(component.0, power);
 ///     }
 /// }
 ///
-/// fn plugin(app: &mut App) {
-///     // Linear scaling (default)
-///     app.add_plugins(StatusEffectPlugin::<Speed, SpeedModifier>::default());
-///
-///     // Or with square root scaling for diminishing returns
-///     // app.add_plugins(StatusEffectPlugin::<Speed, SpeedModifier>::new(
-///     //     StatusEffectApplication::sqrt()
-///     // ));
-///
-///     // Or with custom power scaling
-///     // app.add_plugins(StatusEffectPlugin::<Speed, SpeedModifier>::new(
-///     //     StatusEffectApplication::with_power(0.7)
-///     // ));
-/// }
+/// // Test the implementation
+/// let effect = SpeedModifier(ValueModifier::Percent(50.0));
+/// let mut speed = Speed(100.0);
+/// effect.apply(&mut speed, 1.0);
+/// assert!((speed.0 - 150.0).abs() < 0.001);
 /// ```
-pub struct StatusEffectPlugin<C, E>
-where
-    C: MutableComponent + Default,
-    E: Event + Clone + StatusEffectApplicator<C>,
-{
-    config: StatusEffectApplication<C>,
-    _marker: PhantomData<E>,
-}
+pub trait StatusEffectApplicator<C: MutableComponent>: Event + Clone {
+    /// Get the value modifier from the effect.
+    fn modifier(&self) -> ValueModifier;
 
-impl<C, E> Default for StatusEffectPlugin<C, E>
-where
-    C: MutableComponent + Default,
-    E: Event + Clone + StatusEffectApplicator<C>,
-{
-    fn default() -> Self {
-        Self {
-            config: StatusEffectApplication::default(),
-            _marker: PhantomData,
-        }
-    }
-}
+    /// Apply the effect to the component with the given power scaling.
+    fn apply(&self, component: &mut C, power: f32);
 
-impl<C, E> StatusEffectPlugin<C, E>
-where
-    C: MutableComponent + Default,
-    E: Event + Clone + StatusEffectApplicator<C>,
-{
-    /// Creates a new plugin with the specified configuration.
-    #[must_use]
-    pub fn new(config: StatusEffectApplication<C>) -> Self {
-        Self {
-            config,
-            _marker: PhantomData,
-        }
+    /// Applies the effect on behalf of `source`, the entity it came from.
+    ///
+    /// Called instead of [`apply`](Self::apply) for every application, with
+    /// the source given through [`ApplyStatusEffectFrom`] or
+    /// [`apply_effect_from`](StatusEffectCommandsExt::apply_effect_from), and
+    /// `None` otherwise. Override it for attribution-based logic, e.g. double
+    /// damage from a nemesis. The default ignores the source. Like `apply`, not
+    /// called for components that apply through their [`StatValue`] impl, such
+    /// as [piecewise](StatusEffectApplication::piecewise) ones.
+    fn apply_from(&self, component: &mut C, power: f32, source: Option<Entity>) {
+        let _ = source;
+        self.apply(component, power);
     }
-}
 
-impl<C, E> Plugin for StatusEffectPlugin<C, E>
-where
-    C: MutableComponent + Default,
-    E: Event + Clone + StatusEffectApplicator<C>,
-{
-    fn build(&self, app: &mut App) {
-        app.insert_resource(StatusEffectApplication::<C> {
-            power: self.config.power,
-            _marker: PhantomData,
-        });
-        app.add_observer(apply_status_effect_observer::<C, E>);
+    /// Effect types that must be applied before this one within a frame.
+    ///
+    /// Only honored for [`deferred`](StatusEffectApplication::deferred)
+    /// components, where the queue is sorted so dependencies apply first.
+    /// Cyclic dependencies are reported and fall back to trigger order.
+    ///
+    /// ```rust
+    /// use std::any::TypeId;
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(f32);
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct ArmorShred(ValueModifier);
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Damage(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Health> for Damage {
+    ///     fn modifier(&self) -> ValueModifier { self.0 }
+    ///     fn apply(&self, component: &mut Health, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     fn depends_on(&self) -> &[TypeId] {
+    ///         const { &[TypeId::of::<ArmorShred>()] }
+    ///     }
+    /// }
+    ///
+    /// let damage = Damage(ValueModifier::Val(-10.0));
+    /// assert_eq!(damage.depends_on(), &[TypeId::of::<ArmorShred>()]);
+    /// ```
+    fn depends_on(&self) -> &[TypeId] {
+        &[]
+    }
+
+    /// Component types the target must have for this effect to apply.
+    ///
+    /// Checked when the effect is triggered. Applications to entities missing
+    /// any of them are rejected with [`RejectReason::MissingComponent`], and
+    /// aren't applied if the component is added later.
+    ///
+    /// ```rust
+    /// use std::any::TypeId;
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Speed(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Grounded;
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Quicksand(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Speed> for Quicksand {
+    ///     fn modifier(&self) -> ValueModifier { self.0 }
+    ///     fn apply(&self, component: &mut Speed, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     fn required_components(&self) -> &[TypeId] {
+    ///         const { &[TypeId::of::<Grounded>()] }
+    ///     }
+    /// }
+    /// ```
+    fn required_components(&self) -> &[TypeId] {
+        &[]
+    }
+
+    /// Powers for this effect's flat and percent modifiers, as
+    /// `(flat_power, percent_power)`. Multipliers use the percent power.
+    ///
+    /// Overrides the component's [power](StatusEffectApplication::power) when
+    /// applying this effect, e.g. to add flats linearly but diminish percents.
+    /// Returning the component's power for both is the same as `None`.
+    fn kind_powers(&self) -> Option<(f32, f32)> {
+        None
+    }
+
+    /// Maximum number of stacks of this effect type per entity.
+    ///
+    /// Only enforced for [tracked](StatusEffectApplication::tracked)
+    /// components, where further applications are dropped once the cap is
+    /// reached. `None` means unlimited.
+    fn max_stacks() -> Option<usize> {
+        None
+    }
+
+    /// Priority of this effect for [`EffectSlots`]. When the slots are full,
+    /// the lowest-priority effect is evicted to make room for one of at least
+    /// its priority.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Whether this effect may apply more than once per frame.
+    ///
+    /// Only consulted for [`once_per_frame`](StatusEffectApplication::once_per_frame)
+    /// components. Return true to bypass the guard.
+    fn repeats_per_frame(&self) -> bool {
+        false
+    }
+
+    /// Minimum magnitude a harmful effect keeps after [`Resistance`].
+    ///
+    /// E.g. `Some(5.0)` on a `Percent(-20)` slow leaves at least `Percent(-5)`
+    /// against any resistance. Never makes an effect stronger than authored,
+    /// and beneficial effects aren't resisted at all. Needs
+    /// [`from_modifier`](Self::from_modifier) to take effect.
+    fn min_magnitude_after_resistance(&self) -> Option<f32> {
+        None
+    }
+
+    /// Whether this effect inserts a missing component before applying.
+    ///
+    /// Return false for effects that shouldn't create the component, e.g.
+    /// debuffs on an entity that never had the stat. They're dropped instead.
+    fn should_auto_insert(&self) -> bool {
+        true
+    }
+
+    /// Checks this effect for nonsensical values before it is applied.
+    ///
+    /// Checked by the observer in debug builds only: failures are logged and
+    /// the effect is dropped. Release builds apply effects unchecked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Health(f32);
+    ///
+    /// #[derive(Event, Clone)]
+    /// struct Heal(f32);
+    ///
+    /// impl StatusEffectApplicator<Health> for Heal {
+    ///     fn modifier(&self) -> ValueModifier {
+    ///         ValueModifier::Val(self.0)
+    ///     }
+    ///     fn apply(&self, component: &mut Health, power: f32) {
+    ///         component.0 = self.modifier().apply_scaled(component.0, power);
+    ///     }
+    ///     fn validate(&self) -> Result<(), String> {
+    ///         if self.0 < 0.0 {
+    ///             return Err(format!("heal amount {} is negative", self.0));
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    /// ```
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Receives the part of this application cut off by the component's
+    /// [bounds](StatusEffectApplication::clamped).
+    ///
+    /// `excess` is positive when the value hit the maximum and negative when it
+    /// hit the minimum. Only called when clamping changed the value. The default
+    /// discards the excess.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Health(f32);
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Shield(f32);
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Heal(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Health> for Heal {
+    ///     fn modifier(&self) -> ValueModifier {
+    ///         self.0
+    ///     }
+    ///     fn apply(&self, component: &mut Health, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     // Overheal becomes shield
+    ///     fn overflow(&self, excess: f32, commands: &mut Commands, entity: Entity) {
+    ///         if excess > 0.0 {
+    ///             commands.entity(entity).insert(Shield(excess));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn overflow(&self, excess: f32, commands: &mut Commands, entity: Entity) {
+        let _ = (excess, commands, entity);
+    }
+
+    /// Called when this effect was rejected on `entity`, e.g. to play a
+    /// "resisted" animation.
+    ///
+    /// See [`RejectReason`] for when each reason applies. The default does
+    /// nothing.
+    fn on_rejected(&self, reason: RejectReason, commands: &mut Commands, entity: Entity) {
+        let _ = (reason, commands, entity);
+    }
+
+    /// Builds this effect from a bare modifier.
+    ///
+    /// Used by type-erased tooling such as [`replay`]. The default returns
+    /// `None`, which makes the effect unavailable to that tooling.
+    fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+        let _ = modifier;
+        None
+    }
+
+    /// Returns the modifier to apply, given the target's surroundings.
+    ///
+    /// Called by the observer right before application. When the result
+    /// differs from [`modifier`](Self::modifier), the effect is rebuilt with
+    /// [`from_modifier`](Self::from_modifier); effects that don't support it
+    /// apply unchanged. The default ignores the context.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Attack(f32);
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Rally(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Attack> for Rally {
+    ///     fn modifier(&self) -> ValueModifier { self.0 }
+    ///     fn apply(&self, component: &mut Attack, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+    ///         Some(Self(modifier))
+    ///     }
+    ///     // Stronger for every ally within 10 units
+    ///     fn modifier_with_context(&self, ctx: &EffectContext) -> ValueModifier {
+    ///         self.0.scaled_by(ctx.count_within(10.0).max(1) as f32)
+    ///     }
+    /// }
+    /// ```
+    fn modifier_with_context(&self, ctx: &EffectContext) -> ValueModifier {
+        let _ = ctx;
+        self.modifier()
+    }
+
+    /// Adjusts the modifier by the seconds since this effect type was last
+    /// applied to the entity.
+    ///
+    /// Only consulted for [`since_last`](StatusEffectApplication::since_last)
+    /// components. `elapsed` is `None` on the first application, so the
+    /// effect picks its own baseline. Needs
+    /// [`from_modifier`](Self::from_modifier) to take effect.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use msg_status_effect::prelude::*;
+    /// # #[derive(Component, Default)]
+    /// # struct Health(f32);
+    /// #[derive(Event, Clone, Copy)]
+    /// struct RampingHeal(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Health> for RampingHeal {
+    ///     fn modifier(&self) -> ValueModifier {
+    ///         self.0
+    ///     }
+    ///     fn apply(&self, component: &mut Health, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+    ///         Some(Self(modifier))
+    ///     }
+    ///     // Heals more the longer it's been, up to 5 seconds' worth
+    ///     fn modifier_since_last(&self, elapsed: Option<f32>) -> ValueModifier {
+    ///         self.0.scaled_by(elapsed.unwrap_or(5.0).min(5.0))
+    ///     }
+    /// }
+    /// ```
+    fn modifier_since_last(&self, elapsed: Option<f32>) -> ValueModifier {
+        let _ = elapsed;
+        self.modifier()
+    }
+
+    /// Adjusts the modifier by the target's [`Level`], 1 if it has none.
+    ///
+    /// Needs [`from_modifier`](Self::from_modifier) to take effect.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use msg_status_effect::prelude::*;
+    /// # #[derive(Component, Default)]
+    /// # struct Health(f32);
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Poison(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Health> for Poison {
+    ///     fn modifier(&self) -> ValueModifier {
+    ///         self.0
+    ///     }
+    ///     fn apply(&self, component: &mut Health, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+    ///         Some(Self(modifier))
+    ///     }
+    ///     // Scales linearly with level
+    ///     fn modifier_for_level(&self, level: u32) -> ValueModifier {
+    ///         self.0.scaled_by(level as f32)
+    ///     }
+    /// }
+    /// ```
+    fn modifier_for_level(&self, level: u32) -> ValueModifier {
+        let _ = level;
+        self.modifier()
+    }
+
+    /// Categories of this effect, matched against [`Dispel`] masks.
+    ///
+    /// Effects with no flags are never dispelled by category.
+    fn flags(&self) -> EffectFlags {
+        EffectFlags::empty()
+    }
+
+    /// Returns the modifier for an application caused by a source whose stat
+    /// is `source_stat`, e.g. damage scaling with the caster's attack.
+    ///
+    /// Used by [`apply_effect_from`](StatusEffectCommandsExt::apply_effect_from),
+    /// which rebuilds the effect with
+    /// [`from_modifier`](Self::from_modifier). The default ignores the source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Health(f32);
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Fireball(ValueModifier);
+    ///
+    /// impl StatusEffectApplicator<Health> for Fireball {
+    ///     fn modifier(&self) -> ValueModifier {
+    ///         self.0
+    ///     }
+    ///     fn apply(&self, component: &mut Health, power: f32) {
+    ///         component.0 = self.0.apply_scaled(component.0, power);
+    ///     }
+    ///     fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+    ///         Some(Self(modifier))
+    ///     }
+    ///     // Authored for 100 attack
+    ///     fn modifier_from_source(&self, source_stat: f32) -> ValueModifier {
+    ///         self.0.scaled_by(source_stat / 100.0)
+    ///     }
+    /// }
+    /// ```
+    fn modifier_from_source(&self, source_stat: f32) -> ValueModifier {
+        let _ = source_stat;
+        self.modifier()
+    }
+
+    /// Returns this effect with its modifier scaled by `factor`.
+    ///
+    /// Used to apply [`DifficultyScale`]. The default rebuilds the effect with
+    /// [`from_modifier`](Self::from_modifier); effects that don't support it
+    /// are returned unscaled.
+    #[must_use]
+    fn scaled_by(&self, factor: f32) -> Self {
+        Self::from_modifier(self.modifier().scaled_by(factor)).unwrap_or_else(|| self.clone())
     }
 }
 
-/// Marker component used to organize status effect observers in the entity hierarchy.
+/// Global multiplier applied to every effect's modifier before it is applied.
 ///
-/// When using [`status_effect_observer!`], observers are attached to entities
-/// with this marker, making them easier to inspect in debugging tools.
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct StatusEffectObserverMarker;
+/// Unlike per-component [power](StatusEffectApplication::power), which shapes
+/// how effects combine, this scales the effects themselves: a difficulty of
+/// `2.0` turns `Val(20)` into `Val(40)` and `Percent(10)` into `Percent(20)`.
+/// `Set` and `Negate` are left as they are. Without the resource, effects
+/// apply at their authored strength.
+///
+/// Effects are rescaled through
+/// [`scaled_by`](StatusEffectApplicator::scaled_by), which rebuilds them with
+/// [`from_modifier`](StatusEffectApplicator::from_modifier). Effects that
+/// don't implement it ignore the scale.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// // Enemy debuffs hit 50% harder on hard mode
+/// app.insert_resource(DifficultyScale(1.5));
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Debug, PartialEq)]
+pub struct DifficultyScale(pub f32);
 
-/// Macro for registering status effect observers with organized entity hierarchy.
+impl Default for DifficultyScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Generic event wrapper for applying status effects to entities.
 ///
-/// This macro creates observers that are attached to marker entities for easier
-/// inspection and debugging. Inspired by bevy_fsm's `fsm_observer!` macro.
+/// Similar to `Enter<T>`/`Exit<T>` from bevy_fsm, this wrapper provides
+/// type-safe effect application.
 ///
 /// # Usage
 ///
@@ -558,618 +2089,2794 @@ pub struct StatusEffectObserverMarker;
 /// use bevy::prelude::*;
 /// use msg_status_effect::prelude::*;
 ///
-/// // Define a component and effect type
-/// #[derive(Component)]
-/// struct Speed(f32);
-///
 /// #[derive(Event, Clone, Copy)]
 /// struct SpeedModifier(ValueModifier);
 ///
-/// impl StatusEffectApplicator<Speed> for SpeedModifier {
-///     fn modifier(&self) -> ValueModifier { self.0 }
-///     fn apply(&self, component: &mut Speed, power: f32) {
-///         component.0 = self.0.apply_scaled(component.0, power);
-///     }
+/// // In a system, you would trigger it like this:
+/// fn apply_speed_boost(mut commands: Commands, entity: Entity) {
+///     commands.trigger(ApplyStatusEffect {
+///         effect: SpeedModifier(ValueModifier::Percent(50.0)),
+///         entity,
+///     });
+///     // Or, without naming the fields
+///     commands.trigger(ApplyStatusEffect::new(
+///         entity,
+///         SpeedModifier(ValueModifier::Percent(50.0)),
+///     ));
 /// }
+/// ```
+#[derive(EntityEvent, Clone, Copy)]
+pub struct ApplyStatusEffect<E: Event + Clone> {
+    /// The effect to apply
+    pub effect: E,
+    /// The target entity
+    pub entity: Entity,
+}
+
+impl<E: Event + Clone> ApplyStatusEffect<E> {
+    /// Applies `effect` to `entity`, in the same order as
+    /// [`apply_effect`](StatusEffectCommandsExt::apply_effect).
+    #[must_use]
+    pub fn new(entity: Entity, effect: E) -> Self {
+        Self { effect, entity }
+    }
+
+    /// Triggers `effect` on every entity in `entities`.
+    ///
+    /// Shorthand for the per-entity loop: each target still gets its own
+    /// trigger and observer run, in iteration order, so it costs the same.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use msg_status_effect::prelude::*;
+    ///
+    /// #[derive(Event, Clone, Copy)]
+    /// struct Chill(ValueModifier);
+    ///
+    /// fn frost_nova(mut commands: Commands, enemies: Query<Entity, With<Name>>) {
+    ///     ApplyStatusEffect::trigger_batch(
+    ///         &mut commands,
+    ///         Chill(ValueModifier::Percent(-30.0)),
+    ///         &enemies,
+    ///     );
+    /// }
+    /// ```
+    pub fn trigger_batch(
+        commands: &mut Commands,
+        effect: E,
+        entities: impl IntoIterator<Item = Entity>,
+    ) {
+        let entities: Vec<Entity> = entities.into_iter().collect();
+        commands.queue(move |world: &mut World| {
+            for entity in entities {
+                world.trigger(ApplyStatusEffect {
+                    effect: effect.clone(),
+                    entity,
+                });
+            }
+        });
+    }
+}
+
+/// Why an application was rejected, passed to
+/// [`on_rejected`](StatusEffectApplicator::on_rejected).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RejectReason {
+    /// The component is [locked](StatLocked) on the entity, or the entity
+    /// has an [`Immunity`] to one of the effect's flags
+    Immune,
+    /// The effect already applied this frame under
+    /// [`once_per_frame`](StatusEffectApplication::once_per_frame)
+    Cooldown,
+    /// The entity already has [`max_stacks`](StatusEffectApplicator::max_stacks)
+    /// tracked stacks of the effect
+    StackCap,
+    /// Every [slot](EffectSlots) holds a higher-priority effect
+    SlotsFull,
+    /// The entity lacks one of the effect's
+    /// [required components](StatusEffectApplicator::required_components)
+    MissingComponent,
+    /// Clamping cut the whole change, leaving the value where it was
+    ///
+    /// Unlike the other reasons, the application still counts as applied.
+    NoChange,
+    /// A [`TimedStatusEffect`] targeted a [deferred](StatusEffectApplication::deferred)
+    /// component that isn't [tracked](StatusEffectApplication::tracked), so it
+    /// could never wear off
+    Untimed,
+}
+
+/// Event triggered after an effect has been applied to an entity.
 ///
-/// // Observer function for the effect
-/// fn on_apply_speed_modifier(
-///     on: On<ApplyStatusEffect<SpeedModifier>>,
-///     mut q_speed: Query<&mut Speed>,
-/// ) {
-///     let entity = on.entity;
-///     if let Ok(mut speed) = q_speed.get_mut(entity) {
-///         on.effect.apply(&mut speed, 1.0);
-///     }
-/// }
+/// Carries the effect as applied, after [`DifficultyScale`]. Not triggered for
+/// effects ignored because of a [lock](StatLocked) or rejected before
+/// applying, nor for entities that don't exist. An application that
+/// auto-inserts its component triggers it once, after the insert.
 ///
-/// // Register in your plugin
-/// fn plugin(app: &mut App) {
-///     status_effect_observer!(app, SpeedModifier, on_apply_speed_modifier);
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct SpeedModifier(ValueModifier);
+///
+/// fn log_losses(on: On<StatusEffectApplied<SpeedModifier>>) {
+///     info!("{} lost {} to scaling", on.entity, on.scaling_loss);
 /// }
 /// ```
+#[derive(EntityEvent, Clone)]
+pub struct StatusEffectApplied<E: Event + Clone> {
+    /// The applied effect
+    pub effect: E,
+    /// The entity the effect was applied to
+    pub entity: Entity,
+    /// Linear result minus the scaled result, for balance telemetry
+    ///
+    /// Positive when scaling weakened a buff, negative when it weakened a
+    /// debuff, zero under linear scaling. Resistance, clamping and rounding
+    /// don't count towards it. Only computed for components
+    /// configured [`with_stat_value`](StatusEffectApplication::with_stat_value)
+    /// or [`tracked`](StatusEffectApplication::tracked); zero otherwise.
+    pub scaling_loss: f32,
+    /// Why the effect was applied, empty unless given through
+    /// [`ApplyStatusEffectReason`]
+    pub reason: Cow<'static, str>,
+    /// The entity the effect came from, `None` unless given through
+    /// [`ApplyStatusEffectFrom`] or
+    /// [`apply_effect_from`](StatusEffectCommandsExt::apply_effect_from)
+    pub source: Option<Entity>,
+    /// Stat value before the application, `None` unless the component is
+    /// configured [`with_stat_value`](StatusEffectApplication::with_stat_value)
+    /// or [`tracked`](StatusEffectApplication::tracked)
+    pub old_value: Option<f32>,
+    /// Stat value after the application, `None` under the same conditions as
+    /// [`old_value`](Self::old_value)
+    pub new_value: Option<f32>,
+}
+
+/// Picks the power for `modifier` from an effect's
+/// [`kind_powers`](StatusEffectApplicator::kind_powers).
+pub(crate) fn kind_power(kind_powers: Option<(f32, f32)>, modifier: ValueModifier) -> Option<f32> {
+    kind_powers.map(|(flat, percent)| match modifier {
+        ValueModifier::Val(_) | ValueModifier::Set(_) | ValueModifier::Negate => flat,
+        ValueModifier::Percent(_) | ValueModifier::Multiply(_) => percent,
+    })
+}
+
+/// Power for the application currently being triggered, set by
+/// [`apply_effect_scaled`](StatusEffectCommandsExt::apply_effect_scaled) and
+/// [`with_scaling`].
 ///
-/// # Organization
+/// Only present for the duration of one trigger or scope, so the stored
+/// config is never touched. Recorded on tracked entries, so rebuilds replay
+/// them at the same power.
+#[derive(Resource)]
+pub(crate) struct PowerOverride {
+    power: f32,
+    /// Component the power is limited to, or `None` for every component
+    component: Option<TypeId>,
+}
+
+impl PowerOverride {
+    /// Overrides the power of every component.
+    pub(crate) fn new(power: f32) -> Self {
+        Self {
+            power,
+            component: None,
+        }
+    }
+
+    /// Overrides the power of `C` only.
+    pub(crate) fn of<C: Component>(power: f32) -> Self {
+        Self {
+            power,
+            component: Some(TypeId::of::<C>()),
+        }
+    }
+
+    /// Returns the power to apply effects on `C` at, if overridden.
+    fn power_for<C: Component>(&self) -> Option<f32> {
+        self.component
+            .is_none_or(|component| component == TypeId::of::<C>())
+            .then_some(self.power)
+    }
+}
+
+/// Limits the application currently being triggered to component `C`'s
+/// observers, for effect types that target several components.
 ///
-/// This macro spawns a marker entity named after the observer function
-/// (e.g., "on_apply_walk_speed") for visibility in entity inspectors,
-/// and registers a global observer that responds to the effect on any entity.
-/// Uses pure snake_case naming consistent with fsm_observer!.
-#[macro_export]
-macro_rules! status_effect_observer {
-    ($app:expr, $effect_type:ty, $observer_fn:ident) => {{
-        // Create marker entity for this observer group
-        let marker_name = concat!(stringify!($effect_type), "_observer");
+/// Set when one component's application is re-triggered or copied, so the
+/// others don't apply it a second time.
+#[derive(Resource, Clone, Copy)]
+pub(crate) struct ComponentScope(TypeId);
+
+impl ComponentScope {
+    /// Limits applications to `C`.
+    pub(crate) fn of<C: Component>() -> Self {
+        Self(TypeId::of::<C>())
+    }
+
+    /// Returns true if `C`'s observers may apply.
+    fn admits<C: Component>(&self) -> bool {
+        self.0 == TypeId::of::<C>()
+    }
+}
+
+/// Triggers `event` with `power` in place of the component's configured power.
+pub(crate) fn trigger_with_power<E>(world: &mut World, event: ApplyStatusEffect<E>, power: f32)
+where
+    E: Event + Clone,
+{
+    with_override(world, PowerOverride::new(power), |world| {
+        world.trigger(event)
+    });
+}
+
+/// Re-triggers `event` later for component `C` only, with the power, reason,
+/// item source, source entity and expiry it was triggered with.
+fn retrigger<C, E>(
+    event: ApplyStatusEffect<E>,
+    power: Option<f32>,
+    reason: Option<Cow<'static, str>>,
+    source: Option<Cow<'static, str>>,
+    source_entity: Option<Entity>,
+    expiry: Option<u64>,
+) -> impl FnOnce(&mut World) + Send + Sync + 'static
+where
+    C: MutableComponent,
+    E: Event + Clone,
+{
+    move |world: &mut World| {
+        let trigger = move |world: &mut World| match source_entity {
+            Some(source) => attribution::trigger_from(world, event, source),
+            None => world.trigger(event),
+        };
+        let trigger = move |world: &mut World| match expiry {
+            Some(expiry) => with_override(world, ExpiryTag(expiry), trigger),
+            None => trigger(world),
+        };
+        let trigger = move |world: &mut World| match power {
+            Some(power) => with_override(world, PowerOverride::new(power), trigger),
+            None => trigger(world),
+        };
+        let trigger = move |world: &mut World| match reason {
+            Some(reason) => with_override(world, ApplyReason(reason), trigger),
+            None => trigger(world),
+        };
+        let trigger = move |world: &mut World| match source {
+            Some(source) => with_override(world, ApplySource(source), trigger),
+            None => trigger(world),
+        };
+        with_override(world, ComponentScope::of::<C>(), trigger);
+    }
+}
+
+/// Restores the previous override of `R` when dropped.
+struct OverrideGuard<'w, R: Resource> {
+    world: &'w mut World,
+    previous: Option<R>,
+}
+
+impl<R: Resource> Drop for OverrideGuard<'_, R> {
+    fn drop(&mut self) {
+        match self.previous.take() {
+            Some(previous) => self.world.insert_resource(previous),
+            None => {
+                self.world.remove_resource::<R>();
+            }
+        }
+    }
+}
+
+/// Runs `f` with `resource` inserted, then restores the previous one, if any,
+/// even if `f` panics.
+pub(crate) fn with_override<R: Resource, T>(
+    world: &mut World,
+    resource: R,
+    f: impl FnOnce(&mut World) -> T,
+) -> T {
+    let previous = world.remove_resource::<R>();
+    world.insert_resource(resource);
+    let guard = OverrideGuard { world, previous };
+    f(guard.world)
+}
+
+/// Generic observer that handles any `ApplyStatusEffect<E>` for component C.
+///
+/// If the target entity doesn't have the component, it will be automatically
+/// inserted with its default value before applying the effect. If the entity
+/// is despawned before the insert runs, the effect is dropped.
+///
+/// In debug builds, effects failing
+/// [`validate`](StatusEffectApplicator::validate) are logged and dropped.
+/// For deferred components the application is queued instead, unless the
+/// queue is currently draining. Effects on a [locked](StatLocked) component
+/// or of a category the entity has an [`Immunity`] to
+/// are ignored, as are stacks beyond
+/// [`max_stacks`](StatusEffectApplicator::max_stacks) or the plugin's
+/// [`StackPolicy`], and repeats within a
+/// frame for [`once_per_frame`](StatusEffectApplication::once_per_frame)
+/// components.
+/// The rest are adjusted by
+/// [`modifier_with_context`](StatusEffectApplicator::modifier_with_context)
+/// and [`modifier_for_level`](StatusEffectApplicator::modifier_for_level),
+/// scaled by [`DifficultyScale`] and any [`ScalingMultiplier`], and applied through the
+/// [pipeline](apply_pipeline). Whatever clamping cuts off is passed to
+/// [`overflow`](StatusEffectApplicator::overflow).
+fn apply_status_effect_observer<C, E>(
+    on: On<ApplyStatusEffect<E>>,
+    config: Res<StatusEffectApplication<C>>,
+    mut q: Query<(
+        &mut C,
+        Option<&mut AppliedEffects<C>>,
+        Option<&EffectSlots<C>>,
+        Option<&mut ScalingMultiplier<C>>,
+        Option<&Level>,
+    )>,
+    mut commands: Commands,
+    (queue, throttle): (
+        Option<ResMut<StatusEffectQueue>>,
+        Option<ResMut<StatusEffectThrottle>>,
+    ),
+    (recorder, events): (
+        Option<ResMut<StatusEffectRecorder>>,
+        Option<ResMut<Messages<StatusEffectEvent>>>,
+    ),
+    (frame, time): (Option<Res<FrameCount>>, Option<Res<Time>>),
+    auto_insert: Option<Res<AutoInsert<C>>>,
+    (locked, immunities, presence): (
+        Query<(), With<StatLocked<C>>>,
+        Query<&Immunity>,
+        ComponentPresence,
+    ),
+    difficulty: Option<Res<DifficultyScale>>,
+    mut last_applied: Query<(
+        Option<&mut LastAppliedFrame<E>>,
+        Option<&mut LastAppliedTime<E>>,
+    )>,
+    positions: Query<(Entity, &'static GlobalTransform)>,
+    resistances: Query<&Resistance<C>>,
+    mut percent_sums: Query<&mut PercentSum<C>>,
+    (power_override, reason, source, source_entity, expiry, scope): (
+        Option<Res<PowerOverride>>,
+        Option<Res<ApplyReason>>,
+        Option<Res<ApplySource>>,
+        Option<Res<SourceEntity>>,
+        Option<Res<ExpiryTag>>,
+        Option<Res<ComponentScope>>,
+    ),
+    (stack_policy, mut stacks): (
+        Option<Res<stacks::EffectStackPolicy<E>>>,
+        Query<&mut ActiveStacks<E>>,
+    ),
+) where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    let entity = on.entity;
+    if scope.is_some_and(|scope| !scope.admits::<C>()) {
+        return;
+    }
+    let mut events = events.filter(|_| config.events);
+    let power_override = power_override.and_then(|power| power.power_for::<C>());
+    let source_entity = source_entity.map(|source| source.0);
+    let expiry = expiry.map(|expiry| expiry.0);
+    let draining = queue.as_ref().is_some_and(|queue| queue.is_draining());
+    if let Some(mut throttle) = throttle
+        && !draining
+        && !throttle.admit()
+    {
+        let event = ApplyStatusEffect {
+            effect: on.effect.clone(),
+            entity,
+        };
+        if !q.contains(entity) {
+            cleanup::watch(&mut commands, entity);
+        }
+        throttle.defer(
+            entity,
+            retrigger::<C, _>(
+                event,
+                power_override,
+                reason.map(|reason| reason.0.clone()),
+                source.map(|source| source.0.clone()),
+                source_entity,
+                expiry,
+            ),
+        );
+        return;
+    }
+    #[cfg(debug_assertions)]
+    if let Err(err) = on.effect.validate() {
+        log_warn!(
+            "Rejected invalid {} on {entity}: {err}",
+            std::any::type_name::<E>()
+        );
+        return;
+    }
+    strict_assert!(
+        config.excess_reductions
+            || !matches!(on.effect.modifier(), ValueModifier::Percent(p) if p < -100.0)
+                && !matches!(on.effect.modifier(), ValueModifier::Multiply(f) if f < 0.0),
+        "{} on {entity} reduces {} by more than 100%",
+        std::any::type_name::<E>(),
+        std::any::type_name::<C>()
+    );
+    if locked.contains(entity)
+        || immunities
+            .get(entity)
+            .is_ok_and(|immunity| immunity.blocks(on.effect.flags()))
+    {
+        activity::reject::<C, _>(
+            &on.effect,
+            RejectReason::Immune,
+            &mut commands,
+            entity,
+            events.as_deref_mut(),
+        );
+        return;
+    }
+    if !presence.has_all(entity, on.effect.required_components()) {
+        activity::reject::<C, _>(
+            &on.effect,
+            RejectReason::MissingComponent,
+            &mut commands,
+            entity,
+            events.as_deref_mut(),
+        );
+        return;
+    }
+    if config.deferred
+        && let Some(mut queue) = queue
+        && !draining
+    {
+        let event = ApplyStatusEffect {
+            effect: on.effect.clone(),
+            entity,
+        };
+        let reason = reason.map(|reason| reason.0.clone());
+        let source = source.map(|source| source.0.clone());
+        let coalesce = (config.coalesce
+            && power_override.is_none()
+            && reason.is_none()
+            && source.is_none()
+            && source_entity.is_none()
+            && expiry.is_none()
+            && !on.effect.modifier().is_negate()
+            && !on.effect.modifier().is_set()
+            && E::from_modifier(on.effect.modifier()).is_some())
+        .then(|| queue::Coalesce::of::<C, E>(entity, on.effect.modifier()));
+        if !q.contains(entity) {
+            cleanup::watch(&mut commands, entity);
+        }
+        queue.push::<E>(
+            config.schedule.unwrap_or_else(|| PostUpdate.intern()),
+            entity,
+            on.effect.modifier(),
+            on.effect.depends_on(),
+            coalesce,
+            retrigger::<C, _>(event, power_override, reason, source, source_entity, expiry),
+        );
+        return;
+    }
+
+    if let Ok((mut component, mut applied, slots, multiplier, level)) = q.get_mut(entity) {
+        if config.tracked
+            && let Some(max) = E::max_stacks()
+            && applied
+                .as_ref()
+                .is_some_and(|applied| applied.of_type::<E>().count() >= max)
+        {
+            activity::reject::<C, _>(
+                &on.effect,
+                RejectReason::StackCap,
+                &mut commands,
+                entity,
+                events.as_deref_mut(),
+            );
+            return;
+        }
+        let policy = stack_policy.map_or(StackPolicy::Unlimited, |policy| policy.policy);
+        let active = match (&applied, stacks.get(entity)) {
+            (Some(applied), _) if config.tracked => applied.of_type::<E>().count(),
+            (_, Ok(stacks)) => stacks.count() as usize,
+            _ => 0,
+        };
+        if let Some(max) = policy.max()
+            && active >= max as usize
+        {
+            if policy == StackPolicy::RefreshOnly {
+                commands
+                    .entity(entity)
+                    .queue_silenced(stacks::refresh_countdowns::<C, E>());
+            } else {
+                activity::reject::<C, _>(
+                    &on.effect,
+                    RejectReason::StackCap,
+                    &mut commands,
+                    entity,
+                    events.as_deref_mut(),
+                );
+            }
+            return;
+        }
+        if config.once_per_frame
+            && !on.effect.repeats_per_frame()
+            && let Some(frame) = &frame
+            && let Ok((Some(mut last), _)) = last_applied.get_mut(entity)
+            && !last.mark(frame.0)
+        {
+            activity::reject::<C, _>(
+                &on.effect,
+                RejectReason::Cooldown,
+                &mut commands,
+                entity,
+                events.as_deref_mut(),
+            );
+            return;
+        }
+        if config.tracked
+            && let Some(slots) = slots
+        {
+            match slots.check(applied.as_deref(), on.effect.priority()) {
+                SlotCheck::Free => {}
+                SlotCheck::Evict(index) => {
+                    if let Some(applied) = applied.as_mut() {
+                        applied.effects.remove(index);
+                        let mut sum = percent_sums.get_mut(entity).ok();
+                        if tracking::rebuild(
+                            &config,
+                            component.bypass_change_detection(),
+                            applied,
+                            sum.as_deref_mut(),
+                        ) {
+                            component.set_changed();
+                        }
+                    }
+                }
+                SlotCheck::Full => {
+                    activity::reject::<C, _>(
+                        &on.effect,
+                        RejectReason::SlotsFull,
+                        &mut commands,
+                        entity,
+                        events.as_deref_mut(),
+                    );
+                    return;
+                }
+            }
+        }
+        let modifier = on
+            .effect
+            .modifier_with_context(&EffectContext::new(entity, &positions));
+        let effect = if modifier == on.effect.modifier() {
+            on.effect.clone()
+        } else {
+            E::from_modifier(modifier).unwrap_or_else(|| on.effect.clone())
+        };
+        let effect = match last_applied.get_mut(entity) {
+            Ok((_, Some(mut last))) if config.since_last => {
+                let now = time.as_ref().map_or(0.0, |time| time.elapsed_secs_f64());
+                let modifier = effect.modifier_since_last(last.mark(now));
+                if modifier == effect.modifier() {
+                    effect
+                } else {
+                    E::from_modifier(modifier).unwrap_or(effect)
+                }
+            }
+            _ => effect,
+        };
+        let modifier = effect.modifier_for_level(level.map_or(1, |level| level.0));
+        let effect = if modifier == effect.modifier() {
+            effect
+        } else {
+            E::from_modifier(modifier).unwrap_or(effect)
+        };
+        let factor = difficulty.map_or(1.0, |difficulty| difficulty.0);
+        let scaled = if factor == 1.0 {
+            effect
+        } else {
+            effect.scaled_by(factor)
+        };
+        // Only effects the multiplier actually rebuilt spend a charge
+        let amplified = multiplier
+            .as_deref()
+            .and_then(ScalingMultiplier::factor)
+            .filter(|&factor| factor != 1.0)
+            .map(|factor| scaled.scaled_by(factor))
+            .filter(|amplified| amplified.modifier() != scaled.modifier());
+        let amplifies = amplified.is_some();
+        let scaled = amplified.unwrap_or(scaled);
+        let power = power_override.or_else(|| kind_power(scaled.kind_powers(), scaled.modifier()));
+        let applied_power = power.unwrap_or_else(|| config.power_for(scaled.modifier()));
+        strict_assert!(
+            applied_power.is_finite(),
+            "{} on {entity} applied with non-finite power {applied_power}",
+            std::any::type_name::<E>()
+        );
+        let resistance = resistances.get(entity).map_or(0.0, |r| r.fraction);
+        let ctx = config.pipeline(applied_power, resistance);
+        let before = config.stat_value(&component);
+        let (scaled, overflow) = match percent_sums.get_mut(entity) {
+            Ok(mut sum) if config.sums_percent(scaled.modifier()) => percent_sum::apply_effect(
+                &scaled,
+                component.bypass_change_detection(),
+                &mut sum,
+                &config,
+                &ctx,
+            ),
+            _ if power.is_none() && config.uses_curve() => {
+                piecewise::apply_effect(&scaled, component.bypass_change_detection(), &config, &ctx)
+            }
+            _ => pipeline::apply_effect(
+                &scaled,
+                component.bypass_change_detection(),
+                &config,
+                &ctx,
+                source_entity,
+            ),
+        };
+        let after = config.stat_value(&component);
+        // Readable stats are only marked changed when their value moved
+        if before.is_none() || after != before {
+            component.set_changed();
+        }
+        let no_change = overflow != 0.0 && after.is_some() && after == before;
+        if overflow != 0.0 {
+            scaled.overflow(overflow, &mut commands, entity);
+            if no_change {
+                activity::reject::<C, _>(
+                    &scaled,
+                    RejectReason::NoChange,
+                    &mut commands,
+                    entity,
+                    events.as_deref_mut(),
+                );
+            }
+        }
+        if amplifies
+            && !no_change
+            && let Some(mut multiplier) = multiplier
+        {
+            multiplier.consume();
+            if multiplier.is_spent() {
+                commands.entity(entity).remove::<ScalingMultiplier<C>>();
+            }
+        }
+        if let Ok(mut stacks) = stacks.get_mut(entity) {
+            stacks.set(u32::try_from(active + 1).unwrap_or(u32::MAX));
+        }
+        let reason = reason.map_or(Cow::Borrowed(""), |reason| reason.0.clone());
+        // Compared before resistance, clamping and rounding, which aren't scaling
+        let scaling_loss = before.map_or(0.0, |before| {
+            let modifier = scaled.modifier();
+            let curved = config
+                .apply_curve(before, modifier)
+                .filter(|_| power.is_none());
+            modifier.apply(before)
+                - curved.unwrap_or_else(|| modifier.apply_scaled(before, applied_power))
+        });
+        if config.tracked
+            && let Some(base) = before
+        {
+            let effect = AppliedEffect {
+                power,
+                flags: scaled.flags(),
+                reason: reason.clone(),
+                source: source.map_or(Cow::Borrowed(""), |source| source.0.clone()),
+                priority: scaled.priority(),
+                expiry,
+                ..AppliedEffect::of::<E>(scaled.modifier())
+            };
+            match applied {
+                Some(mut applied) => applied.effects.push(effect),
+                None => {
+                    commands
+                        .entity(entity)
+                        .queue_silenced(tracking::track_first_effect::<C>(base, effect));
+                }
+            }
+        }
+        if let Some(events) = events.as_mut() {
+            events.write(StatusEffectEvent::Applied(EffectActivity::of::<C, E>(
+                entity,
+                scaled.modifier(),
+            )));
+        }
+        if let Some(mut recorder) = recorder {
+            let frame = frame.map_or(0, |frame| frame.0);
+            recorder.record::<E>(frame, entity, on.effect.modifier());
+        }
+        commands.trigger(StatusEffectApplied {
+            effect: scaled,
+            entity,
+            scaling_loss,
+            reason,
+            source: source_entity,
+            old_value: before,
+            new_value: after,
+        });
+    } else if let Ok(mut entity_commands) = commands.get_spawned_entity(entity) {
+        // Entity is spawned but missing component - insert and re-trigger
+        if !on.effect.should_auto_insert() {
+            return;
+        }
+        let value = match config.auto_insert {
+            AutoInsertMode::Default => C::default(),
+            AutoInsertMode::Resource => {
+                let Some(auto_insert) = auto_insert else {
+                    log_error!(
+                        "Dropping {} on {}: {} requires an AutoInsert resource",
+                        std::any::type_name::<E>(),
+                        entity,
+                        std::any::type_name::<C>()
+                    );
+                    return;
+                };
+                auto_insert.value()
+            }
+        };
+        // Insert and re-trigger in one command, dropped if the entity is
+        // despawned before it runs
+        let event = ApplyStatusEffect {
+            effect: on.effect.clone(),
+            entity,
+        };
+        // Keep the power, reason, item source, source entity and expiry the
+        // effect was triggered with
+        let retrigger = retrigger::<C, _>(
+            event,
+            power_override,
+            reason.map(|reason| reason.0.clone()),
+            source.map(|source| source.0.clone()),
+            source_entity,
+            expiry,
+        );
+        entity_commands.queue_silenced(move |mut entity: EntityWorldMut| {
+            entity.insert(value);
+            // Already counted by the throttle
+            throttle::pass(entity.into_world_mut(), retrigger);
+        });
+    }
+    // If entity doesn't exist or isn't spawned yet, silently ignore
+}
+
+/// Plugin for registering a status effect for a specific component.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// // Define component and effect (component must implement Default)
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct SpeedModifier(ValueModifier);
+///
+/// impl StatusEffectApplicator<Speed> for SpeedModifier {
+///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn apply(&self, component: &mut Speed, power: f32) {
+///         component.0 = self.0.apply_scaled(component.0, power);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     // Linear scaling (default)
+///     app.add_plugins(StatusEffectPlugin::<Speed, SpeedModifier>::default());
+///
+///     // Or with square root scaling for diminishing returns
+///     // app.add_plugins(StatusEffectPlugin::<Speed, SpeedModifier>::new(
+///     //     StatusEffectApplication::sqrt()
+///     // ));
+///
+///     // Or with custom power scaling
+///     // app.add_plugins(StatusEffectPlugin::<Speed, SpeedModifier>::new(
+///     //     StatusEffectApplication::with_power(0.7)
+///     // ));
+/// }
+/// ```
+pub struct StatusEffectPlugin<C, E>
+where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    config: StatusEffectApplication<C>,
+    stack_policy: StackPolicy,
+    register_config: Option<fn(&mut App)>,
+    periodic: bool,
+    reasons: bool,
+    attribution: bool,
+    _marker: PhantomData<E>,
+}
+
+impl<C, E> Default for StatusEffectPlugin<C, E>
+where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    fn default() -> Self {
+        Self {
+            config: StatusEffectApplication::default(),
+            stack_policy: StackPolicy::Unlimited,
+            register_config: None,
+            periodic: false,
+            reasons: false,
+            attribution: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, E> StatusEffectPlugin<C, E>
+where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    /// Creates a new plugin with the specified configuration.
+    #[must_use]
+    pub fn new(config: StatusEffectApplication<C>) -> Self {
+        Self {
+            config,
+            stack_policy: StackPolicy::Unlimited,
+            register_config: None,
+            periodic: false,
+            reasons: false,
+            attribution: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Limits how many applications of `E` can be active on an entity.
+    ///
+    /// The count is kept in an [`ActiveStacks<E>`] on every entity with `C`.
+    /// Applies on top of [`max_stacks`](StatusEffectApplicator::max_stacks).
+    #[must_use]
+    pub fn with_stack_policy(mut self, policy: StackPolicy) -> Self {
+        self.stack_policy = policy;
+        self
+    }
+
+    /// Registers [`StatusEffectApplication<C>`] for reflection, so the config
+    /// shows up and can be edited in inspectors.
+    ///
+    /// Opt-in since it needs `C: TypePath`. Schedules, custom curves and stat
+    /// accessors aren't reflected: edits through reflection keep them, but
+    /// configs built from reflected data start without them.
+    #[must_use]
+    pub fn reflect_config(mut self) -> Self
+    where
+        C: TypePath,
+    {
+        self.register_config = Some(|app| {
+            app.register_type::<StatusEffectApplication<C>>();
+        });
+        self
+    }
+
+    /// Registers [`ApplyPeriodicEffect<E>`] and the system ticking it.
+    ///
+    /// Ticks are split with [`scaled_by`](StatusEffectApplicator::scaled_by),
+    /// so `E` must implement
+    /// [`from_modifier`](StatusEffectApplicator::from_modifier).
+    #[must_use]
+    pub fn periodic(mut self) -> Self {
+        self.periodic = true;
+        self
+    }
+
+    /// Registers [`ApplyStatusEffectReason<E>`] for applications with a
+    /// logged reason.
+    #[must_use]
+    pub fn with_reasons(mut self) -> Self {
+        self.reasons = true;
+        self
+    }
+
+    /// Registers [`ApplyStatusEffectFrom<E>`] for applications attributed to
+    /// a source entity.
+    ///
+    /// [`apply_effect_from`](StatusEffectCommandsExt::apply_effect_from)
+    /// works without it.
+    #[must_use]
+    pub fn with_attribution(mut self) -> Self {
+        self.attribution = true;
+        self
+    }
+}
+
+impl<C, E> Plugin for StatusEffectPlugin<C, E>
+where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    fn build(&self, app: &mut App) {
+        if self.config.deferred && !app.is_plugin_added::<queue::StatusEffectQueuePlugin>() {
+            app.add_plugins(queue::StatusEffectQueuePlugin);
+        }
+        if let Some(schedule) = self.config.schedule {
+            queue::add_drain_schedule(app, schedule);
+        }
+        if self.periodic && !app.is_plugin_added::<periodic::PeriodicPlugin<E>>() {
+            app.add_plugins(periodic::PeriodicPlugin::<E>::new::<C>());
+        }
+        if !app.is_plugin_added::<temporary::TemporaryPlugin<E>>() {
+            app.add_plugins(temporary::TemporaryPlugin::<E>::default());
+        }
+        if self.reasons && !app.is_plugin_added::<reason::ReasonPlugin<E>>() {
+            app.add_plugins(reason::ReasonPlugin::<E>::default());
+        }
+        if self.attribution && !app.is_plugin_added::<attribution::AttributionPlugin<E>>() {
+            app.add_plugins(attribution::AttributionPlugin::<E>::default());
+        }
+        if self.config.tracked && !app.is_plugin_added::<temporary::FadePlugin<C, E>>() {
+            app.add_plugins(temporary::FadePlugin::<C, E>::default());
+        }
+        if self.config.tracked && !app.is_plugin_added::<tracking::TrackingGcPlugin<C>>() {
+            app.add_plugins(tracking::TrackingGcPlugin::<C>::default());
+        }
+        if self.config.report_changes && !app.is_plugin_added::<stat_change::StatChangePlugin<C>>()
+        {
+            app.add_plugins(stat_change::StatChangePlugin::<C>::default());
+        }
+        if self.config.tracked && !app.is_plugin_added::<flags::DispelPlugin<C>>() {
+            app.add_plugins(flags::DispelPlugin::<C>::default());
+        }
+        if self.config.tracked && !app.is_plugin_added::<item::ItemStatsPlugin<C>>() {
+            app.add_plugins(item::ItemStatsPlugin::<C>::default());
+        }
+        if !app.is_plugin_added::<cleanup::StatusEffectCleanupPlugin>() {
+            app.add_plugins(cleanup::StatusEffectCleanupPlugin);
+        }
+        cleanup::require_cleanup::<C>(app);
+        if self.config.lockable && !app.is_plugin_added::<lock::StatLockPlugin<C>>() {
+            app.add_plugins(lock::StatLockPlugin::<C>::default());
+        }
+        if self.config.amplifiable
+            && !app.is_plugin_added::<scaling_modifier::ScalingModifierPlugin<C>>()
+        {
+            app.add_plugins(scaling_modifier::ScalingModifierPlugin::<C>::default());
+        }
+        if self.config.uses_percent_sum() {
+            // Fails only when another effect on `C` already registered it
+            let _ = app.try_register_required_components::<C, PercentSum<C>>();
+        }
+        if self.config.once_per_frame {
+            app.register_required_components::<C, LastAppliedFrame<E>>();
+        }
+        if self.config.since_last {
+            app.register_required_components::<C, LastAppliedTime<E>>();
+        }
+        if self.stack_policy != StackPolicy::Unlimited {
+            app.insert_resource(stacks::EffectStackPolicy::<E>::new(
+                self.stack_policy,
+                !self.config.deferred,
+            ));
+            app.register_required_components::<C, ActiveStacks<E>>();
+            if self.config.tracked && !app.is_plugin_added::<stacks::StackSyncPlugin<C, E>>() {
+                app.add_plugins(stacks::StackSyncPlugin::<C, E>::default());
+            }
+        }
+        // Registered so enum-aware inspectors can edit modifiers by variant
+        app.register_type::<ValueModifier>();
+        app.register_type::<DifficultyScale>();
+        app.register_type::<AutoInsertMode>();
+        app.register_type::<PercentCombineMode>();
+        app.register_type::<PiecewiseScaling>();
+        if let Some(register_config) = self.register_config {
+            register_config(app);
+        }
+        app.add_message::<StatusEffectEvent>();
+        app.register_type::<StatusEffectObserverMarker>();
+        app.insert_resource(self.config.clone());
+        app.init_resource::<StatusEffectRegistry>();
+        let mut registry = app.world_mut().resource_mut::<StatusEffectRegistry>();
+        registry.register::<C, E>();
+        registry.register_save::<C>();
+        if self.config.stat.is_some() {
+            registry.register_stat::<C>();
+        }
+        if self.config.tracked {
+            registry.register_tracked::<C>();
+        }
+        app.add_observer(apply_status_effect_observer::<C, E>);
+        app.add_observer(tracking::remove_effect_everywhere_observer::<C, E>);
+        app.add_observer(tracking::remove_status_effect_observer::<C, E>);
+        app.add_observer(timed::timed_status_effect_observer::<C, E>);
+        app.add_systems(Update, timed::tick_timed_effects::<C, E>);
+    }
+}
+
+/// Marker component used to organize status effect observers in the entity hierarchy.
+///
+/// When using [`status_effect_observer!`], observers are attached to entities
+/// with this marker, making them easier to inspect in debugging tools.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct StatusEffectObserverMarker;
+
+/// A single [`status_effect_observer!`] registration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEffectObserverEntry {
+    /// Effect type as written at the macro call site
+    pub effect_type: &'static str,
+    /// Name of the observer function
+    pub observer: &'static str,
+    /// Marker entity spawned for the observer
+    pub marker: Entity,
+}
+
+/// Observers registered through [`status_effect_observer!`], in registration order.
+///
+/// Each macro call records its own entry, even for an effect type that
+/// already has one.
+#[derive(Resource, Default, Debug)]
+pub struct StatusEffectObservers {
+    entries: Vec<StatusEffectObserverEntry>,
+}
+
+impl StatusEffectObservers {
+    /// Records a registration. Called by [`status_effect_observer!`].
+    #[doc(hidden)]
+    pub fn record(&mut self, effect_type: &'static str, observer: &'static str, marker: Entity) {
+        self.entries.push(StatusEffectObserverEntry {
+            effect_type,
+            observer,
+            marker,
+        });
+    }
+
+    /// Returns every registration.
+    #[must_use]
+    pub fn entries(&self) -> &[StatusEffectObserverEntry] {
+        &self.entries
+    }
+
+    /// Iterates the registrations for one effect type.
+    pub fn for_effect<'a>(
+        &'a self,
+        effect_type: &'a str,
+    ) -> impl Iterator<Item = &'a StatusEffectObserverEntry> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.effect_type == effect_type)
+    }
+}
+
+/// Macro for registering status effect observers with organized entity hierarchy.
+///
+/// This macro creates observers that are attached to marker entities for easier
+/// inspection and debugging. Inspired by bevy_fsm's `fsm_observer!` macro.
+///
+/// # Usage
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// // Define a component and effect type
+/// #[derive(Component)]
+/// struct Speed(f32);
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct SpeedModifier(ValueModifier);
+///
+/// impl StatusEffectApplicator<Speed> for SpeedModifier {
+///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn apply(&self, component: &mut Speed, power: f32) {
+///         component.0 = self.0.apply_scaled(component.0, power);
+///     }
+/// }
+///
+/// // Observer function for the effect
+/// fn on_apply_speed_modifier(
+///     on: On<ApplyStatusEffect<SpeedModifier>>,
+///     mut q_speed: Query<&mut Speed>,
+/// ) {
+///     let entity = on.entity;
+///     if let Ok(mut speed) = q_speed.get_mut(entity) {
+///         on.effect.apply(&mut speed, 1.0);
+///     }
+/// }
+///
+/// // Register in your plugin
+/// fn plugin(app: &mut App) {
+///     status_effect_observer!(app, SpeedModifier, on_apply_speed_modifier);
+/// }
+/// ```
+///
+/// # Organization
+///
+/// This macro spawns a marker entity named after the observer function
+/// (e.g., "on_apply_walk_speed") for visibility in entity inspectors,
+/// and registers a global observer that responds to the effect on any entity.
+/// Uses pure snake_case naming consistent with fsm_observer!.
+///
+/// Each registration is recorded in the [`StatusEffectObservers`] resource.
+#[macro_export]
+macro_rules! status_effect_observer {
+    ($app:expr, $effect_type:ty, $observer_fn:ident) => {{
+        // Create marker entity for this observer group
+        let marker_name = concat!(stringify!($effect_type), "_observer");
+
+        // Spawn marker entity for visibility in entity inspectors
+        let marker = $app
+            .world_mut()
+            .spawn((Name::new(marker_name), $crate::StatusEffectObserverMarker))
+            .id();
+
+        // Record the registration for introspection
+        $app.world_mut()
+            .get_resource_or_init::<$crate::StatusEffectObservers>()
+            .record(stringify!($effect_type), stringify!($observer_fn), marker);
+
+        // Register a global observer that responds to the effect on any entity
+        $app.add_observer($observer_fn);
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // ValueModifier Unit Tests
+    // ============================================================================
+
+    #[test]
+    fn value_modifier_apply_linear() {
+        // Linear scaling (power = 1.0): standard addition
+        assert!((ValueModifier::Val(10.0).apply_scaled(100.0, 1.0) - 110.0).abs() < 0.001);
+
+        // Linear percentage: +50% = 1.5x
+        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, 1.0);
+        assert!((result - 150.0).abs() < 0.001);
+
+        // Linear percentage: -10% = 0.9x
+        let result = ValueModifier::Percent(-10.0).apply_scaled(100.0, 1.0);
+        assert!((result - 90.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_apply_scaled_sqrt() {
+        // Square root scaling (power = 0.5): quadratic addition
+        // Formula: (current^2 + val^2)^0.5
+        let result = ValueModifier::Val(30.0).apply_scaled(40.0, 0.5);
+        // (40^2 + 30^2)^0.5 = sqrt(2500) = 50
+        assert!((result - 50.0).abs() < 0.001);
+
+        // Negative val: subtraction with scaling
+        let result = ValueModifier::Val(-30.0).apply_scaled(40.0, 0.5);
+        // (40^2 - 30^2)^0.5 = sqrt(700) = ~26.46
+        assert!((result - 26.46).abs() < 0.01);
+
+        // Subtraction clamped to 0
+        let result = ValueModifier::Val(-50.0).apply_scaled(30.0, 0.5);
+        // (30^2 - 50^2)^0.5 = sqrt(-1600) -> clamped to 0
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn value_modifier_apply_scaled_percent() {
+        // Sqrt scaling: +50% -> 1.5^0.5 = ~1.2247x
+        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, 0.5);
+        // 100 * 1.5^0.5 = ~122.47
+        assert!((result - 122.47).abs() < 0.1);
+
+        // Sqrt scaling: -10% -> 0.9^0.5 = ~0.9487x
+        let result = ValueModifier::Percent(-10.0).apply_scaled(100.0, 0.5);
+        // 100 * 0.9^0.5 = ~94.87
+        assert!((result - 94.87).abs() < 0.1);
+
+        // Edge case: -100% = 0x, clamped
+        let result = ValueModifier::Percent(-100.0).apply_scaled(100.0, 0.5);
+        assert_eq!(result, 0.0);
+
+        // Edge case: -150% would be negative multiplier, clamped to 0
+        let result = ValueModifier::Percent(-150.0).apply_scaled(100.0, 0.5);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn value_modifier_clamp_boundary_uses_epsilon() {
+        // Exact cancellation clamps to zero
+        assert_eq!(ValueModifier::Val(-30.0).apply_scaled(30.0, 0.5), 0.0);
+
+        // Float residue within epsilon of zero is zero, not a tiny positive
+        let residue = ValueModifier::Val(-0.3).apply_scaled(0.1 + 0.2, 1.0);
+        assert_eq!(residue, 0.0);
+
+        // Just beyond epsilon survives the clamp
+        let small = ValueModifier::Val(-29.9).apply_scaled(30.0, 1.0);
+        assert!((small - 0.1).abs() < 0.001);
+
+        // Multipliers within epsilon of zero clamp
+        let result = ValueModifier::Percent(-99.999_99).apply_scaled(100.0, 0.5);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn integration_kind_powers_override_component_power() {
+        #[derive(Component, Default)]
+        struct TestSpeed(f32);
+
+        impl StatValue for TestSpeed {
+            fn stat_value(&self) -> f32 {
+                self.0
+            }
+            fn set_stat_value(&mut self, value: f32) {
+                self.0 = value;
+            }
+        }
+
+        /// Flats add linearly, percents diminish
+        #[derive(Event, Clone, Copy)]
+        struct TestSpeedEffect(ValueModifier);
+
+        impl StatusEffectApplicator<TestSpeed> for TestSpeedEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestSpeed, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
+            fn kind_powers(&self) -> Option<(f32, f32)> {
+                Some((scaling::LINEAR, scaling::SQRT))
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            StatusEffectApplication::square().tracked(),
+        ));
+        let entity = app.world_mut().spawn(TestSpeed(40.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, TestSpeedEffect(ValueModifier::Val(30.0)));
+        app.update();
+        assert!((app.world().get::<TestSpeed>(entity).unwrap().0 - 70.0).abs() < 0.001);
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, TestSpeedEffect(ValueModifier::Percent(50.0)));
+        app.update();
+        let speed = app.world().get::<TestSpeed>(entity).unwrap().0;
+        assert!((speed - 70.0 * 1.5f32.sqrt()).abs() < 0.001);
+
+        // Tracking replays each entry with the power it applied with
+        let applied = app
+            .world()
+            .get::<AppliedEffects<TestSpeed>>(entity)
+            .unwrap();
+        assert!((applied.effective_value(scaling::SQUARE) - speed).abs() < 0.001);
+    }
+
+    #[test]
+    fn plugin_registers_reflected_types() {
+        #[derive(Component, Default, Reflect)]
+        struct Armor(f32);
+
+        #[derive(Event, Clone, Copy)]
+        struct ArmorEffect(ValueModifier);
+
+        impl StatusEffectApplicator<Armor> for ArmorEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+
+            fn apply(&self, component: &mut Armor, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        app.add_plugins(
+            StatusEffectPlugin::<Armor, ArmorEffect>::new(StatusEffectApplication::sqrt())
+                .reflect_config(),
+        );
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        for type_id in [
+            TypeId::of::<ValueModifier>(),
+            TypeId::of::<StatusEffectObserverMarker>(),
+            TypeId::of::<AutoInsertMode>(),
+            TypeId::of::<PercentCombineMode>(),
+            TypeId::of::<StatusEffectApplication<Armor>>(),
+        ] {
+            assert!(registry.contains(type_id));
+        }
+        // Not opted in
+        assert!(!registry.contains(TypeId::of::<StatusEffectApplication<TestSpeed>>()));
+
+        // The config is editable in place, like an inspector would
+        let reflect = registry
+            .get_type_data::<ReflectResource>(TypeId::of::<StatusEffectApplication<Armor>>())
+            .unwrap()
+            .clone();
+        drop(registry);
+        let mut config = reflect.reflect_mut(app.world_mut()).unwrap();
+        let Ok(power) = config
+            .reflect_mut()
+            .as_struct()
+            .map(|config| config.field_mut("power").unwrap())
+        else {
+            panic!("StatusEffectApplication should reflect as a struct");
+        };
+        power.apply(&0.25f32);
+        let config = app.world().resource::<StatusEffectApplication<Armor>>();
+        assert_eq!(config.power, 0.25);
+    }
+
+    #[test]
+    fn value_modifier_is_editable_through_reflection() {
+        use bevy::reflect::{DynamicEnum, DynamicTuple, ReflectMut, TypeInfo, Typed, VariantInfo};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let registration = registry.get(TypeId::of::<ValueModifier>()).unwrap();
+        let TypeInfo::Enum(info) = registration.type_info() else {
+            panic!("ValueModifier should reflect as an enum");
+        };
+        assert_eq!(
+            info.variant_names(),
+            ["Val", "Percent", "Multiply", "Set", "Negate"]
+        );
+        assert!(matches!(
+            info.variant("Percent"),
+            Some(VariantInfo::Tuple(_))
+        ));
+        assert!(matches!(ValueModifier::type_info(), TypeInfo::Enum(_)));
+
+        // Edit the inner value in place
+        let mut modifier = ValueModifier::Val(10.0);
+        let ReflectMut::Enum(variant) = modifier.reflect_mut() else {
+            panic!("ValueModifier should reflect as an enum");
+        };
+        variant.field_at_mut(0).unwrap().apply(&25.0f32);
+        assert_eq!(modifier, ValueModifier::Val(25.0));
+
+        // Switch variants, as a dropdown would
+        let mut value = DynamicTuple::default();
+        value.insert(15.0f32);
+        PartialReflect::apply(&mut modifier, &DynamicEnum::new("Percent", value));
+        assert_eq!(modifier, ValueModifier::Percent(15.0));
+    }
+
+    #[test]
+    fn blended_power_lies_between_curves() {
+        let blended = StatusEffectApplication::<TestSpeed>::with_blended_power(
+            scaling::SQRT,
+            scaling::LINEAR,
+            0.5,
+        );
+        assert!((blended.power - 0.75).abs() < 0.0001);
+
+        let sqrt = ValueModifier::Val(30.0).apply_scaled(40.0, scaling::SQRT);
+        let linear = ValueModifier::Val(30.0).apply_scaled(40.0, scaling::LINEAR);
+        let value = ValueModifier::Val(30.0).apply_scaled(40.0, blended.power);
+        assert!(sqrt < value && value < linear);
+
+        // t outside [0, 1] clamps to the endpoints
+        let below =
+            StatusEffectApplication::<TestSpeed>::with_blended_power(scaling::SQRT, 1.0, -1.0);
+        let above =
+            StatusEffectApplication::<TestSpeed>::with_blended_power(scaling::SQRT, 1.0, 2.0);
+        assert_eq!(below.power, scaling::SQRT);
+        assert_eq!(above.power, scaling::LINEAR);
+    }
+
+    #[cfg(feature = "no-log")]
+    #[test]
+    fn negative_current_without_logging() {
+        assert_eq!(
+            ValueModifier::Percent(50.0).apply_scaled(-100.0, 1.0),
+            -150.0
+        );
+        assert_eq!(ValueModifier::Val(10.0).apply_scaled(-100.0, 1.0), -110.0);
+        // Same magnitude as on the positive stat, sign restored
+        let modifier = ValueModifier::Val(-30.0);
+        assert_eq!(
+            modifier.apply_scaled(-100.0, scaling::SQRT),
+            -modifier.apply_scaled(100.0, scaling::SQRT)
+        );
+    }
+
+    #[test]
+    fn linear_equivalent_round_trips() {
+        for power in [scaling::SQRT, scaling::LINEAR, scaling::SQUARE] {
+            for points in [30.0, -30.0, 0.0] {
+                let scaled = ValueModifier::Val(points).apply_scaled(100.0, power);
+                let back = ValueModifier::to_linear_equivalent(scaled, 100.0, power);
+                assert!((back - points).abs() < 1e-3, "{points} at {power}: {back}");
+                let again = ValueModifier::Val(back).apply_scaled(100.0, power);
+                assert!((again - scaled).abs() < 1e-3);
+            }
+        }
+
+        // sqrt-scaled speed: 44 points on 100 only reach 109.25
+        let speed = ValueModifier::Val(44.0).apply_scaled(100.0, scaling::SQRT);
+        assert!(
+            (ValueModifier::to_linear_equivalent(speed, 100.0, scaling::SQRT) - 44.0).abs() < 1e-3
+        );
+
+        // Zero base: points equal the value
+        assert_eq!(
+            ValueModifier::to_linear_equivalent(25.0, 0.0, scaling::SQRT),
+            25.0
+        );
+    }
+
+    #[test]
+    fn value_modifier_near_zero_current_is_zero() {
+        // Tiny negative from float error is treated as zero, not as a negative stat
+        let result = ValueModifier::Percent(50.0).apply_scaled(-EPSILON / 2.0, 1.0);
+        assert_eq!(result, 0.0);
+
+        let result = ValueModifier::Val(10.0).apply_scaled(-EPSILON / 2.0, 0.5);
+        assert!((result - 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_constructors() {
+        let flat = ValueModifier::flat(10.0);
+        assert!(flat.is_flat());
+        assert!(!flat.is_percent());
+        assert_eq!(flat.flat_value(), 10.0);
+        assert_eq!(flat.percent_value(), 0.0);
+
+        let percent = ValueModifier::percent(25.0);
+        assert!(!percent.is_flat());
+        assert!(percent.is_percent());
+        assert_eq!(percent.flat_value(), 0.0);
+        assert_eq!(percent.percent_value(), 25.0);
+    }
+
+    #[test]
+    fn value_modifier_scaled_by() {
+        let flat = ValueModifier::flat(10.0);
+        assert_eq!(flat.scaled_by(2.0), ValueModifier::Val(20.0));
+
+        let percent = ValueModifier::percent(50.0);
+        assert_eq!(percent.scaled_by(0.5), ValueModifier::Percent(25.0));
+    }
+
+    #[test]
+    fn value_modifier_multiply() {
+        let double = ValueModifier::Multiply(2.0);
+        assert_eq!(double.apply(100.0), 200.0);
+        assert_eq!(double.apply_scaled(100.0, 1.0), 200.0);
+        assert!((double.apply_scaled(100.0, 0.5) - 100.0 * 2f32.sqrt()).abs() < 0.001);
+
+        assert!(double.is_multiply());
+        assert!(!double.is_flat() && !double.is_percent());
+        assert_eq!(double.multiply_value(), 2.0);
+        assert_eq!(double.flat_value(), 0.0);
+        assert_eq!(double.percent_value(), 0.0);
+        assert_eq!(ValueModifier::Val(10.0).multiply_value(), 1.0);
+
+        // Scales its distance from 1, like the equivalent percent
+        assert_eq!(
+            ValueModifier::Multiply(1.5).scaled_by(0.5),
+            ValueModifier::Multiply(1.25)
+        );
+        assert_eq!(
+            double.as_percent_of(50.0),
+            Some(ValueModifier::Percent(100.0))
+        );
+        assert!(double.same_effect(&ValueModifier::Multiply(2.0)));
+        assert!(!double.same_effect(&ValueModifier::Percent(2.0)));
+
+        // Negative factors clamp to zero
+        assert_eq!(ValueModifier::Multiply(-1.0).apply_scaled(100.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn value_modifier_set_ignores_current_and_power() {
+        let freeze = ValueModifier::Set(0.0);
+        for power in [
+            scaling::LINEAR,
+            scaling::SQRT,
+            scaling::CUBE_ROOT,
+            scaling::SQUARE,
+        ] {
+            assert_eq!(freeze.apply_scaled(999.0, power), 0.0);
+            assert_eq!(ValueModifier::Set(50.0).apply_scaled(-20.0, power), 50.0);
+        }
+        assert_eq!(ValueModifier::Set(50.0).apply(10.0), 50.0);
+
+        assert!(freeze.is_set());
+        assert_eq!(ValueModifier::Set(50.0).set_value(), Some(50.0));
+        assert_eq!(ValueModifier::Val(50.0).set_value(), None);
+        assert_eq!(freeze.flat_value(), 0.0);
+        assert_eq!(freeze.percent_value(), 0.0);
+        assert_eq!(freeze.multiply_value(), 1.0);
+
+        assert_eq!(
+            ValueModifier::Set(50.0).scaled_by(0.5),
+            ValueModifier::Set(50.0)
+        );
+        assert_eq!(
+            ValueModifier::Set(50.0).scaled_by_dt(0.25),
+            ValueModifier::Set(50.0)
+        );
+        assert!(!freeze.same_effect(&ValueModifier::Val(0.0)));
+    }
+
+    #[test]
+    fn value_modifier_as_percent_of() {
+        assert_eq!(
+            ValueModifier::Val(10.0).as_percent_of(100.0),
+            Some(ValueModifier::Percent(10.0))
+        );
+        assert_eq!(
+            ValueModifier::Val(-30.0).as_percent_of(60.0),
+            Some(ValueModifier::Percent(-50.0))
+        );
+        assert_eq!(
+            ValueModifier::Percent(25.0).as_percent_of(0.0),
+            Some(ValueModifier::Percent(25.0))
+        );
+
+        // No percent of zero is ever non-zero
+        assert_eq!(ValueModifier::Val(10.0).as_percent_of(0.0), None);
+        assert_eq!(ValueModifier::Val(10.0).as_percent_of(1e-6), None);
+        assert_eq!(ValueModifier::Negate.as_percent_of(100.0), None);
+    }
+
+    #[test]
+    fn value_modifier_scaled_by_dt_flat() {
+        // 10 per second at 0.25s per frame
+        assert_eq!(
+            ValueModifier::Val(10.0).scaled_by_dt(0.25),
+            ValueModifier::Val(2.5)
+        );
+
+        // dt of one second is the identity
+        assert_eq!(
+            ValueModifier::Val(-10.0).scaled_by_dt(1.0),
+            ValueModifier::Val(-10.0)
+        );
+    }
+
+    #[test]
+    fn value_modifier_scaled_by_dt_frame_rate_independent() {
+        // Flat: two half-second frames equal one full-second frame
+        let one_frame = ValueModifier::Val(-10.0).scaled_by_dt(1.0).apply(100.0);
+        let half = ValueModifier::Val(-10.0).scaled_by_dt(0.5);
+        let two_frames = half.apply(half.apply(100.0));
+        assert!((one_frame - two_frames).abs() < 0.001);
+        assert!((one_frame - 90.0).abs() < 0.001);
+
+        // Percent: compounding halves must reach the same per-second result
+        let one_frame = ValueModifier::Percent(-20.0).scaled_by_dt(1.0).apply(100.0);
+        let half = ValueModifier::Percent(-20.0).scaled_by_dt(0.5);
+        let two_frames = half.apply(half.apply(100.0));
+        assert!((one_frame - two_frames).abs() < 0.001);
+        assert!((one_frame - 80.0).abs() < 0.001);
+
+        // Linear scaling of the percent would overshoot: 100 * 0.9 * 0.9 = 81
+        let naive = ValueModifier::Percent(-20.0).scaled_by(0.5);
+        assert!((naive.apply(naive.apply(100.0)) - 81.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_scaled_by_dt_many_frames() {
+        // Sixty frames of 1/60s add up to one second of +50%
+        let step = ValueModifier::Percent(50.0).scaled_by_dt(1.0 / 60.0);
+        let result = (0..60).fold(100.0, |value, _| step.apply(value));
+        assert!((result - 150.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn effective_value_matches_iterative_application() {
+        let flats = [30.0, -10.0, 25.0];
+        let percents = [50.0, -20.0, 10.0];
+
+        for power in [
+            scaling::LINEAR,
+            scaling::SQRT,
+            scaling::CUBE_ROOT,
+            scaling::SQUARE,
+        ] {
+            let iterative = flats
+                .iter()
+                .map(|&v| ValueModifier::Val(v))
+                .chain(percents.iter().map(|&p| ValueModifier::Percent(p)))
+                .fold(100.0, |value, modifier| modifier.apply_scaled(value, power));
+            let closed = scaling::effective_value(100.0, &flats, &percents, power);
+            assert!(
+                (iterative - closed).abs() / iterative < 1e-4,
+                "power {power}: iterative {iterative}, closed {closed}"
+            );
+        }
+    }
+
+    #[test]
+    fn effective_value_empty_slices_return_base() {
+        assert_eq!(
+            scaling::effective_value(42.0, &[], &[], scaling::SQRT),
+            42.0
+        );
+        assert_eq!(
+            scaling::effective_value(0.0, &[], &[], scaling::CUBE_ROOT),
+            0.0
+        );
+    }
+
+    #[test]
+    fn effective_value_clamps_to_zero() {
+        assert_eq!(
+            scaling::effective_value(30.0, &[-50.0], &[], scaling::SQRT),
+            0.0
+        );
+        assert_eq!(
+            scaling::effective_value(30.0, &[], &[10.0, -100.0], scaling::SQRT),
+            0.0
+        );
+    }
+
+    #[test]
+    fn effective_value_is_stable_for_many_small_percents() {
+        let percents = vec![0.01; 10_000];
+
+        // Exact reference computed in f64
+        let reference = 100.0 * (1.0 + f64::from(0.01f32) / 100.0).powf(0.5 * 10_000.0);
+
+        let iterative = percents.iter().fold(100.0, |value, &p| {
+            ValueModifier::Percent(p).apply_scaled(value, scaling::SQRT)
+        });
+        let closed = scaling::effective_value(100.0, &[], &percents, scaling::SQRT);
+
+        let iterative_error = (f64::from(iterative) - reference).abs();
+        let closed_error = (f64::from(closed) - reference).abs();
+        assert!(closed_error < 1e-4, "closed-form error {closed_error}");
+        assert!(
+            closed_error < iterative_error,
+            "closed {closed_error} vs iterative {iterative_error}"
+        );
+    }
+
+    #[test]
+    fn scaling_fn_power_matches_apply_scaled() {
+        let curve = scaling::ScalingFn::from(scaling::SQRT);
+        for modifier in [
+            ValueModifier::Val(30.0),
+            ValueModifier::Val(-30.0),
+            ValueModifier::Percent(50.0),
+            ValueModifier::Multiply(2.0),
+        ] {
+            assert_eq!(
+                modifier.apply_with_curve(40.0, curve),
+                modifier.apply_scaled(40.0, scaling::SQRT)
+            );
+        }
+
+        // A custom identity curve is linear, up to bisection
+        let identity = scaling::ScalingFn::Custom(|raw| raw);
+        let value = ValueModifier::Val(30.0).apply_with_curve(40.0, identity);
+        assert!((value - 70.0).abs() < 0.001);
+        let value = ValueModifier::Percent(-50.0).apply_with_curve(40.0, identity);
+        assert!((value - 20.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn integration_custom_curve_caps_stacks() {
+        #[derive(Component, Default)]
+        struct Stacks(f32);
+
+        impl StatValue for Stacks {
+            fn stat_value(&self) -> f32 {
+                self.0
+            }
+
+            fn set_stat_value(&mut self, value: f32) {
+                self.0 = value;
+            }
+        }
+
+        #[derive(Event, Clone, Copy)]
+        struct AddStack(ValueModifier);
+
+        impl StatusEffectApplicator<Stacks> for AddStack {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+
+            fn apply(&self, component: &mut Stacks, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
+        }
+
+        let config = StatusEffectApplication::linear()
+            .with_curve(scaling::ScalingFn::Custom(|stacks| stacks.min(5.0)));
+        assert!(matches!(config.scaling(), scaling::ScalingFn::Custom(_)));
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Stacks, AddStack>::new(config));
+        let entity = app.world_mut().spawn(Stacks(0.0)).id();
+        app.update();
+
+        for _ in 0..8 {
+            app.world_mut().trigger(ApplyStatusEffect {
+                effect: AddStack(ValueModifier::Val(1.0)),
+                entity,
+            });
+        }
+        app.update();
+        assert_eq!(app.world().get::<Stacks>(entity).unwrap().0, 5.0);
+
+        // Stacks over the ceiling aren't banked: one removal drops below it
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: AddStack(ValueModifier::Val(-1.0)),
+            entity,
+        });
+        app.update();
+        assert_eq!(app.world().get::<Stacks>(entity).unwrap().0, 4.0);
+    }
+
+    #[test]
+    fn integration_sign_powers_split_buffs_and_debuffs() {
+        let config =
+            StatusEffectApplication::linear().with_sign_powers(scaling::SQRT, scaling::LINEAR);
+        assert_eq!(
+            config.power_for(ValueModifier::Percent(50.0)),
+            scaling::SQRT
+        );
+        assert_eq!(
+            config.power_for(ValueModifier::Multiply(0.5)),
+            scaling::LINEAR
+        );
+        // No direction: the neutral power
+        assert_eq!(config.power_for(ValueModifier::Set(10.0)), scaling::LINEAR);
+        assert_eq!(config.power_for(ValueModifier::Val(0.0)), scaling::LINEAR);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            config,
+        ));
+        let buffed = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        let debuffed = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        app.update();
+
+        for (entity, percent) in [(buffed, 50.0), (debuffed, -50.0)] {
+            app.world_mut().trigger(ApplyStatusEffect {
+                effect: TestSpeedEffect(ValueModifier::Percent(percent)),
+                entity,
+            });
+        }
+        app.update();
+        // The buff diminishes, the debuff is linear
+        let buffed = app.world().get::<TestSpeed>(buffed).unwrap().value;
+        assert!((buffed - 100.0 * 1.5f32.sqrt()).abs() < 0.001);
+        assert_eq!(app.world().get::<TestSpeed>(debuffed).unwrap().value, 50.0);
+    }
+
+    #[test]
+    fn status_effect_application_presets() {
+        #[derive(Component)]
+        struct TestComponent;
+
+        let linear = StatusEffectApplication::<TestComponent>::linear();
+        assert!((linear.power - 1.0).abs() < 0.001);
+
+        let sqrt = StatusEffectApplication::<TestComponent>::sqrt();
+        assert!((sqrt.power - 0.5).abs() < 0.001);
+
+        let cube_root = StatusEffectApplication::<TestComponent>::cube_root();
+        assert!((cube_root.power - (1.0 / 3.0)).abs() < 0.001);
+
+        let square = StatusEffectApplication::<TestComponent>::square();
+        assert!((square.power - 2.0).abs() < 0.001);
+
+        let custom = StatusEffectApplication::<TestComponent>::with_power(0.7);
+        assert!((custom.power - 0.7).abs() < 0.001);
+    }
+
+    // ============================================================================
+    // ValueModifier Edge Case Tests
+    // ============================================================================
+
+    #[test]
+    fn value_modifier_same_effect_detects_duplicates() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(modifier: ValueModifier) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            modifier.hash_bits(&mut hasher);
+            hasher.finish()
+        }
+
+        let stack = [
+            ValueModifier::Val(10.0),
+            ValueModifier::Percent(10.0),
+            ValueModifier::Val(10.0),
+            ValueModifier::Val(0.0),
+            ValueModifier::Val(-0.0),
+            ValueModifier::Negate,
+            ValueModifier::Negate,
+        ];
+        let mut unique: Vec<ValueModifier> = Vec::new();
+        for modifier in stack {
+            if !unique.iter().any(|seen| seen.same_effect(&modifier)) {
+                unique.push(modifier);
+            }
+        }
+        assert_eq!(
+            unique,
+            [
+                ValueModifier::Val(10.0),
+                ValueModifier::Percent(10.0),
+                ValueModifier::Val(0.0),
+                ValueModifier::Negate,
+            ]
+        );
+
+        // Same effects hash the same; kinds don't collide
+        assert_eq!(
+            hash(ValueModifier::Val(0.0)),
+            hash(ValueModifier::Val(-0.0))
+        );
+        assert_ne!(
+            hash(ValueModifier::Val(10.0)),
+            hash(ValueModifier::Percent(10.0))
+        );
+
+        // NaN is never a duplicate, not even of itself
+        let nan = ValueModifier::Percent(f32::NAN);
+        assert!(!nan.same_effect(&nan));
+    }
+
+    #[test]
+    fn value_modifier_negate_flips_sign_ignoring_power() {
+        for power in [scaling::LINEAR, scaling::SQRT, scaling::CUBE_ROOT] {
+            assert_eq!(ValueModifier::Negate.apply_scaled(100.0, power), -100.0);
+            assert_eq!(ValueModifier::Negate.apply_scaled(-100.0, power), 100.0);
+        }
+        // Zero stays a positive zero
+        let zero = ValueModifier::Negate.apply_scaled(0.0, scaling::SQRT);
+        assert!(zero == 0.0 && zero.is_sign_positive());
+        assert_eq!(ValueModifier::Negate.scaled_by(3.0), ValueModifier::Negate);
+    }
+
+    #[test]
+    fn value_modifier_zero_current_value() {
+        // Adding to zero
+        let result = ValueModifier::Val(50.0).apply_scaled(0.0, 1.0);
+        assert!((result - 50.0).abs() < 0.001);
+
+        // Percentage on zero should stay zero
+        let result = ValueModifier::Percent(100.0).apply_scaled(0.0, 1.0);
+        assert_eq!(result, 0.0);
+
+        // Zero with sqrt scaling
+        let result = ValueModifier::Val(50.0).apply_scaled(0.0, 0.5);
+        assert!((result - 50.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_zero_modifier_value() {
+        // Zero flat value should not change current
+        let result = ValueModifier::Val(0.0).apply_scaled(100.0, 1.0);
+        assert!((result - 100.0).abs() < 0.001);
+
+        // Zero percent should not change current
+        let result = ValueModifier::Percent(0.0).apply_scaled(100.0, 1.0);
+        assert!((result - 100.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_very_large_values() {
+        // Large flat value
+        let result = ValueModifier::Val(1000000.0).apply_scaled(100.0, 1.0);
+        assert!((result - 1000100.0).abs() < 1.0);
+
+        // Large percentage (10x multiplier)
+        let result = ValueModifier::Percent(900.0).apply_scaled(100.0, 1.0);
+        assert!((result - 1000.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_small_values() {
+        // Very small flat value
+        let result = ValueModifier::Val(0.001).apply_scaled(100.0, 1.0);
+        assert!((result - 100.001).abs() < 0.0001);
+
+        // Very small percentage
+        let result = ValueModifier::Percent(0.1).apply_scaled(100.0, 1.0);
+        assert!((result - 100.1).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_cube_root_scaling() {
+        // Cube root scaling (power = 1/3): strong diminishing returns
+        // Formula: (current^3 + val^3)^(1/3)
+        let result = ValueModifier::Val(30.0).apply_scaled(40.0, scaling::CUBE_ROOT);
+        // (40^3 + 30^3)^(1/3) = (64000 + 27000)^(1/3) = 91000^(1/3) = ~45.0
+        assert!((result - 45.0).abs() < 0.5);
+
+        // Cube root percentage
+        let result = ValueModifier::Percent(100.0).apply_scaled(100.0, scaling::CUBE_ROOT);
+        // 100 * 2^(1/3) = ~126
+        assert!((result - 126.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn value_modifier_square_scaling() {
+        // Square scaling (power = 2): increasing returns
+        // Formula: (sqrt(current) + sqrt(val))^2
+        let result = ValueModifier::Val(21.0).apply_scaled(100.0, scaling::SQUARE);
+        // (sqrt(100) + sqrt(21))^2 = (10 + 4.58)^2 = ~212.2
+        assert!((result - 212.2).abs() < 1.0);
+
+        // Square percentage
+        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, scaling::SQUARE);
+        // 100 * 1.5^2 = 225
+        assert!((result - 225.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_cube_scaling() {
+        // Cube scaling (power = 3): strong increasing returns
+        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, scaling::CUBE);
+        // 100 * 1.5^3 = 337.5
+        assert!((result - 337.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_apply_no_scaling() {
+        // Test the simple apply() method (linear, no scaling)
+        assert!((ValueModifier::Val(10.0).apply(100.0) - 110.0).abs() < 0.001);
+        assert!((ValueModifier::Percent(50.0).apply(100.0) - 150.0).abs() < 0.001);
+        assert!((ValueModifier::Percent(-25.0).apply(100.0) - 75.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_default() {
+        let default = ValueModifier::default();
+        assert_eq!(default, ValueModifier::Val(0.0));
+        // Default should not change value
+        assert!((default.apply(100.0) - 100.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn value_modifier_scaled_by_zero() {
+        let flat = ValueModifier::flat(100.0);
+        assert_eq!(flat.scaled_by(0.0), ValueModifier::Val(0.0));
+
+        let percent = ValueModifier::percent(50.0);
+        assert_eq!(percent.scaled_by(0.0), ValueModifier::Percent(0.0));
+    }
+
+    #[test]
+    fn value_modifier_scaled_by_negative() {
+        let flat = ValueModifier::flat(10.0);
+        assert_eq!(flat.scaled_by(-1.0), ValueModifier::Val(-10.0));
+
+        let percent = ValueModifier::percent(50.0);
+        assert_eq!(percent.scaled_by(-1.0), ValueModifier::Percent(-50.0));
+    }
+
+    #[test]
+    fn value_modifier_subtraction_with_various_scaling() {
+        // Linear subtraction
+        let result = ValueModifier::Val(-30.0).apply_scaled(100.0, 1.0);
+        assert!((result - 70.0).abs() < 0.001);
+
+        // Sqrt subtraction (diminishing returns on subtraction too)
+        let result = ValueModifier::Val(-60.0).apply_scaled(100.0, 0.5);
+        // (100^2 - 60^2)^0.5 = sqrt(10000 - 3600) = sqrt(6400) = 80
+        assert!((result - 80.0).abs() < 0.001);
+
+        // Subtraction exceeding current value clamps to 0
+        let result = ValueModifier::Val(-150.0).apply_scaled(100.0, 0.5);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn scaling_constants_values() {
+        assert_eq!(scaling::LINEAR, 1.0);
+        assert_eq!(scaling::SQRT, 0.5);
+        assert!((scaling::CUBE_ROOT - 0.333333).abs() < 0.001);
+        assert_eq!(scaling::SQUARE, 2.0);
+        assert_eq!(scaling::CUBE, 3.0);
+    }
+
+    // ============================================================================
+    // StatusEffectApplication Tests
+    // ============================================================================
+
+    #[test]
+    fn status_effect_application_default() {
+        #[derive(Component)]
+        struct TestComponent;
+
+        let default = StatusEffectApplication::<TestComponent>::default();
+        assert_eq!(default.power, scaling::LINEAR);
+    }
+
+    // ============================================================================
+    // Integration Tests - Full Plugin System
+    // ============================================================================
+
+    /// Test component for integration tests
+    #[derive(Component, Default)]
+    struct TestSpeed {
+        value: f32,
+    }
+
+    impl TestSpeed {
+        fn new(value: f32) -> Self {
+            Self { value }
+        }
+    }
+
+    /// Test effect for modifying TestSpeed
+    #[derive(Event, Clone, Copy)]
+    struct TestSpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<TestSpeed> for TestSpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut TestSpeed, power: f32) {
+            component.value = self.0.apply_scaled(component.value, power);
+        }
+    }
+
+    #[test]
+    fn integration_plugin_registers_observer_and_resource() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+
+        app.update();
+
+        // Verify resource is inserted
+        assert!(
+            app.world()
+                .contains_resource::<StatusEffectApplication<TestSpeed>>()
+        );
+
+        // Verify default power is linear
+        let config = app.world().resource::<StatusEffectApplication<TestSpeed>>();
+        assert_eq!(config.power, scaling::LINEAR);
+    }
+
+    #[test]
+    fn integration_apply_status_effect_flat_linear() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+
+        // Spawn entity with component
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+
+        app.update();
+
+        // Trigger effect
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
+            entity,
+        });
 
-        // Spawn marker entity for visibility in entity inspectors
-        $app.world_mut()
-            .spawn((Name::new(marker_name), $crate::StatusEffectObserverMarker));
+        app.update();
 
-        // Register a global observer that responds to the effect on any entity
-        $app.add_observer($observer_fn);
-    }};
-}
+        // Verify effect was applied
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        assert!((speed.value - 120.0).abs() < 0.001);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn integration_apply_status_effect_percent_linear() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
 
-    // ============================================================================
-    // ValueModifier Unit Tests
-    // ============================================================================
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
 
-    #[test]
-    fn value_modifier_apply_linear() {
-        // Linear scaling (power = 1.0): standard addition
-        assert!((ValueModifier::Val(10.0).apply_scaled(100.0, 1.0) - 110.0).abs() < 0.001);
+        app.update();
 
-        // Linear percentage: +50% = 1.5x
-        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, 1.0);
-        assert!((result - 150.0).abs() < 0.001);
+        // Apply +50% effect
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
+            entity,
+        });
 
-        // Linear percentage: -10% = 0.9x
-        let result = ValueModifier::Percent(-10.0).apply_scaled(100.0, 1.0);
-        assert!((result - 90.0).abs() < 0.001);
+        app.update();
+
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        assert!((speed.value - 150.0).abs() < 0.001);
     }
 
     #[test]
-    fn value_modifier_apply_scaled_sqrt() {
-        // Square root scaling (power = 0.5): quadratic addition
-        // Formula: (current^2 + val^2)^0.5
-        let result = ValueModifier::Val(30.0).apply_scaled(40.0, 0.5);
-        // (40^2 + 30^2)^0.5 = sqrt(2500) = 50
-        assert!((result - 50.0).abs() < 0.001);
+    fn integration_apply_status_effect_with_sqrt_scaling() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            StatusEffectApplication::sqrt(),
+        ));
 
-        // Negative val: subtraction with scaling
-        let result = ValueModifier::Val(-30.0).apply_scaled(40.0, 0.5);
-        // (40^2 - 30^2)^0.5 = sqrt(700) = ~26.46
-        assert!((result - 26.46).abs() < 0.01);
+        let entity = app.world_mut().spawn(TestSpeed::new(40.0)).id();
 
-        // Subtraction clamped to 0
-        let result = ValueModifier::Val(-50.0).apply_scaled(30.0, 0.5);
-        // (30^2 - 50^2)^0.5 = sqrt(-1600) -> clamped to 0
-        assert_eq!(result, 0.0);
+        app.update();
+
+        // Apply +30 with sqrt scaling: sqrt(40^2 + 30^2) = 50
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(30.0)),
+            entity,
+        });
+
+        app.update();
+
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        assert!((speed.value - 50.0).abs() < 0.001);
     }
 
     #[test]
-    fn value_modifier_apply_scaled_percent() {
-        // Sqrt scaling: +50% -> 1.5^0.5 = ~1.2247x
-        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, 0.5);
-        // 100 * 1.5^0.5 = ~122.47
-        assert!((result - 122.47).abs() < 0.1);
+    fn integration_apply_status_effect_with_custom_power() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            StatusEffectApplication::with_power(0.7),
+        ));
 
-        // Sqrt scaling: -10% -> 0.9^0.5 = ~0.9487x
-        let result = ValueModifier::Percent(-10.0).apply_scaled(100.0, 0.5);
-        // 100 * 0.9^0.5 = ~94.87
-        assert!((result - 94.87).abs() < 0.1);
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
 
-        // Edge case: -100% = 0x, clamped
-        let result = ValueModifier::Percent(-100.0).apply_scaled(100.0, 0.5);
-        assert_eq!(result, 0.0);
+        app.update();
 
-        // Edge case: -150% would be negative multiplier, clamped to 0
-        let result = ValueModifier::Percent(-150.0).apply_scaled(100.0, 0.5);
-        assert_eq!(result, 0.0);
-    }
+        // Apply +50% with power=0.7: 100 * 1.5^0.7 = ~136.8
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
+            entity,
+        });
 
-    #[test]
-    fn value_modifier_constructors() {
-        let flat = ValueModifier::flat(10.0);
-        assert!(flat.is_flat());
-        assert!(!flat.is_percent());
-        assert_eq!(flat.flat_value(), 10.0);
-        assert_eq!(flat.percent_value(), 0.0);
+        app.update();
 
-        let percent = ValueModifier::percent(25.0);
-        assert!(!percent.is_flat());
-        assert!(percent.is_percent());
-        assert_eq!(percent.flat_value(), 0.0);
-        assert_eq!(percent.percent_value(), 25.0);
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        let expected = 100.0 * 1.5_f32.powf(0.7);
+        assert!((speed.value - expected).abs() < 0.1);
     }
 
     #[test]
-    fn value_modifier_scaled_by() {
-        let flat = ValueModifier::flat(10.0);
-        assert_eq!(flat.scaled_by(2.0), ValueModifier::Val(20.0));
+    fn integration_multiple_effects_stack() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
 
-        let percent = ValueModifier::percent(50.0);
-        assert_eq!(percent.scaled_by(0.5), ValueModifier::Percent(25.0));
-    }
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
 
-    #[test]
-    fn status_effect_application_presets() {
-        #[derive(Component)]
-        struct TestComponent;
+        app.update();
 
-        let linear = StatusEffectApplication::<TestComponent>::linear();
-        assert!((linear.power - 1.0).abs() < 0.001);
+        // Apply first effect: +20
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
+            entity,
+        });
 
-        let sqrt = StatusEffectApplication::<TestComponent>::sqrt();
-        assert!((sqrt.power - 0.5).abs() < 0.001);
+        app.update();
 
-        let cube_root = StatusEffectApplication::<TestComponent>::cube_root();
-        assert!((cube_root.power - (1.0 / 3.0)).abs() < 0.001);
+        // Apply second effect: +10%
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Percent(10.0)),
+            entity,
+        });
 
-        let square = StatusEffectApplication::<TestComponent>::square();
-        assert!((square.power - 2.0).abs() < 0.001);
+        app.update();
 
-        let custom = StatusEffectApplication::<TestComponent>::with_power(0.7);
-        assert!((custom.power - 0.7).abs() < 0.001);
+        // 100 + 20 = 120, then 120 * 1.1 = 132
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        assert!((speed.value - 132.0).abs() < 0.001);
     }
 
-    // ============================================================================
-    // ValueModifier Edge Case Tests
-    // ============================================================================
-
     #[test]
-    fn value_modifier_zero_current_value() {
-        // Adding to zero
-        let result = ValueModifier::Val(50.0).apply_scaled(0.0, 1.0);
-        assert!((result - 50.0).abs() < 0.001);
+    fn integration_effect_on_nonexistent_entity_no_panic() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
 
-        // Percentage on zero should stay zero
-        let result = ValueModifier::Percent(100.0).apply_scaled(0.0, 1.0);
-        assert_eq!(result, 0.0);
+        app.update();
 
-        // Zero with sqrt scaling
-        let result = ValueModifier::Val(50.0).apply_scaled(0.0, 0.5);
-        assert!((result - 50.0).abs() < 0.001);
+        // Trigger effect on entity that doesn't exist
+        let fake_entity = Entity::from_raw_u32(9999).unwrap();
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
+            entity: fake_entity,
+        });
+
+        // Should not panic
+        app.update();
     }
 
     #[test]
-    fn value_modifier_zero_modifier_value() {
-        // Zero flat value should not change current
-        let result = ValueModifier::Val(0.0).apply_scaled(100.0, 1.0);
-        assert!((result - 100.0).abs() < 0.001);
+    fn integration_effect_on_entity_without_component_auto_inserts() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
 
-        // Zero percent should not change current
-        let result = ValueModifier::Percent(0.0).apply_scaled(100.0, 1.0);
-        assert!((result - 100.0).abs() < 0.001);
-    }
+        // Spawn entity WITHOUT TestSpeed component
+        let entity = app.world_mut().spawn_empty().id();
 
-    #[test]
-    fn value_modifier_very_large_values() {
-        // Large flat value
-        let result = ValueModifier::Val(1000000.0).apply_scaled(100.0, 1.0);
-        assert!((result - 1000100.0).abs() < 1.0);
+        app.update();
 
-        // Large percentage (10x multiplier)
-        let result = ValueModifier::Percent(900.0).apply_scaled(100.0, 1.0);
-        assert!((result - 1000.0).abs() < 0.001);
-    }
+        // Trigger effect - should auto-insert component and apply effect
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
+            entity,
+        });
 
-    #[test]
-    fn value_modifier_small_values() {
-        // Very small flat value
-        let result = ValueModifier::Val(0.001).apply_scaled(100.0, 1.0);
-        assert!((result - 100.001).abs() < 0.0001);
+        // First update: observer runs, queues insert + re-trigger
+        app.update();
+        // Second update: re-triggered observer applies effect to inserted component
+        app.update();
 
-        // Very small percentage
-        let result = ValueModifier::Percent(0.1).apply_scaled(100.0, 1.0);
-        assert!((result - 100.1).abs() < 0.001);
+        // Component should now exist with default (0.0) + effect (20.0)
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        assert!((speed.value - 20.0).abs() < 0.001);
     }
 
     #[test]
-    fn value_modifier_cube_root_scaling() {
-        // Cube root scaling (power = 1/3): strong diminishing returns
-        // Formula: (current^3 + val^3)^(1/3)
-        let result = ValueModifier::Val(30.0).apply_scaled(40.0, scaling::CUBE_ROOT);
-        // (40^3 + 30^3)^(1/3) = (64000 + 27000)^(1/3) = 91000^(1/3) = ~45.0
-        assert!((result - 45.0).abs() < 0.5);
+    fn integration_auto_insert_applies_once_per_component() {
+        #[derive(Component, Default)]
+        struct TestArmor {
+            value: f32,
+        }
+
+        impl StatusEffectApplicator<TestArmor> for TestSpeedEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+
+            fn apply(&self, component: &mut TestArmor, power: f32) {
+                component.value = self.0.apply_scaled(component.value, power);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        app.add_plugins(StatusEffectPlugin::<TestArmor, TestSpeedEffect>::default());
+        let entity = app.world_mut().spawn_empty().id();
+        app.update();
 
-        // Cube root percentage
-        let result = ValueModifier::Percent(100.0).apply_scaled(100.0, scaling::CUBE_ROOT);
-        // 100 * 2^(1/3) = ~126
-        assert!((result - 126.0).abs() < 0.5);
+        // Each insert re-triggers the effect for its own component only
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
+            entity,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<TestSpeed>(entity).unwrap().value, 20.0);
+        assert_eq!(app.world().get::<TestArmor>(entity).unwrap().value, 20.0);
     }
 
     #[test]
-    fn value_modifier_square_scaling() {
-        // Square scaling (power = 2): increasing returns
-        // Formula: (sqrt(current) + sqrt(val))^2
-        let result = ValueModifier::Val(21.0).apply_scaled(100.0, scaling::SQUARE);
-        // (sqrt(100) + sqrt(21))^2 = (10 + 4.58)^2 = ~212.2
-        assert!((result - 212.2).abs() < 1.0);
+    fn integration_auto_insert_on_entity_despawned_mid_flush() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
 
-        // Square percentage
-        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, scaling::SQUARE);
-        // 100 * 1.5^2 = 225
-        assert!((result - 225.0).abs() < 0.001);
-    }
+        let entity = app.world_mut().spawn_empty().id();
+        app.update();
 
-    #[test]
-    fn value_modifier_cube_scaling() {
-        // Cube scaling (power = 3): strong increasing returns
-        let result = ValueModifier::Percent(50.0).apply_scaled(100.0, scaling::CUBE);
-        // 100 * 1.5^3 = 337.5
-        assert!((result - 337.5).abs() < 0.001);
-    }
+        // The despawn is queued but not yet applied when the observer sees the
+        // entity, so the insert and re-trigger land after it
+        app.world_mut().commands().entity(entity).despawn();
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
+            entity,
+        });
+        app.update();
+        app.update();
 
-    #[test]
-    fn value_modifier_apply_no_scaling() {
-        // Test the simple apply() method (linear, no scaling)
-        assert!((ValueModifier::Val(10.0).apply(100.0) - 110.0).abs() < 0.001);
-        assert!((ValueModifier::Percent(50.0).apply(100.0) - 150.0).abs() < 0.001);
-        assert!((ValueModifier::Percent(-25.0).apply(100.0) - 75.0).abs() < 0.001);
+        assert!(app.world().get_entity(entity).is_err());
+        let mut speeds = app.world_mut().query::<&TestSpeed>();
+        assert_eq!(speeds.iter(app.world()).count(), 0);
     }
 
     #[test]
-    fn value_modifier_default() {
-        let default = ValueModifier::default();
-        assert_eq!(default, ValueModifier::Val(0.0));
-        // Default should not change value
-        assert!((default.apply(100.0) - 100.0).abs() < 0.001);
-    }
+    fn integration_auto_insert_with_percent_effect() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
 
-    #[test]
-    fn value_modifier_scaled_by_zero() {
-        let flat = ValueModifier::flat(100.0);
-        assert_eq!(flat.scaled_by(0.0), ValueModifier::Val(0.0));
+        // Spawn entity WITHOUT TestSpeed component
+        let entity = app.world_mut().spawn_empty().id();
 
-        let percent = ValueModifier::percent(50.0);
-        assert_eq!(percent.scaled_by(0.0), ValueModifier::Percent(0.0));
-    }
+        app.update();
 
-    #[test]
-    fn value_modifier_scaled_by_negative() {
-        let flat = ValueModifier::flat(10.0);
-        assert_eq!(flat.scaled_by(-1.0), ValueModifier::Val(-10.0));
+        // Trigger percent effect on entity without component
+        // Default TestSpeed.value is 0.0, so +50% of 0 = 0
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
+            entity,
+        });
 
-        let percent = ValueModifier::percent(50.0);
-        assert_eq!(percent.scaled_by(-1.0), ValueModifier::Percent(-50.0));
+        app.update();
+        app.update();
+
+        // Component should exist with default value (percent of 0 is still 0)
+        let speed = app.world().get::<TestSpeed>(entity).unwrap();
+        assert!((speed.value - 0.0).abs() < 0.001);
     }
 
     #[test]
-    fn value_modifier_subtraction_with_various_scaling() {
-        // Linear subtraction
-        let result = ValueModifier::Val(-30.0).apply_scaled(100.0, 1.0);
-        assert!((result - 70.0).abs() < 0.001);
+    fn integration_auto_insert_with_custom_default() {
+        /// Component with non-zero default for testing
+        #[derive(Component)]
+        struct TestArmor {
+            value: f32,
+        }
 
-        // Sqrt subtraction (diminishing returns on subtraction too)
-        let result = ValueModifier::Val(-60.0).apply_scaled(100.0, 0.5);
-        // (100^2 - 60^2)^0.5 = sqrt(10000 - 3600) = sqrt(6400) = 80
-        assert!((result - 80.0).abs() < 0.001);
+        impl Default for TestArmor {
+            fn default() -> Self {
+                Self { value: 10.0 } // Non-zero default
+            }
+        }
 
-        // Subtraction exceeding current value clamps to 0
-        let result = ValueModifier::Val(-150.0).apply_scaled(100.0, 0.5);
-        assert_eq!(result, 0.0);
-    }
+        #[derive(Event, Clone, Copy)]
+        struct TestArmorEffect(ValueModifier);
 
-    #[test]
-    fn scaling_constants_values() {
-        assert_eq!(scaling::LINEAR, 1.0);
-        assert_eq!(scaling::SQRT, 0.5);
-        assert!((scaling::CUBE_ROOT - 0.333333).abs() < 0.001);
-        assert_eq!(scaling::SQUARE, 2.0);
-        assert_eq!(scaling::CUBE, 3.0);
-    }
+        impl StatusEffectApplicator<TestArmor> for TestArmorEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestArmor, power: f32) {
+                component.value = self.0.apply_scaled(component.value, power);
+            }
+        }
 
-    // ============================================================================
-    // StatusEffectApplication Tests
-    // ============================================================================
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestArmor, TestArmorEffect>::default());
 
-    #[test]
-    fn status_effect_application_default() {
-        #[derive(Component)]
-        struct TestComponent;
+        let entity = app.world_mut().spawn_empty().id();
 
-        let default = StatusEffectApplication::<TestComponent>::default();
-        assert_eq!(default.power, scaling::LINEAR);
-    }
+        app.update();
 
-    // ============================================================================
-    // Integration Tests - Full Plugin System
-    // ============================================================================
+        // Apply +50% to entity without component
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestArmorEffect(ValueModifier::Percent(50.0)),
+            entity,
+        });
 
-    /// Test component for integration tests
-    #[derive(Component, Default)]
-    struct TestSpeed {
-        value: f32,
-    }
+        app.update();
+        app.update();
 
-    impl TestSpeed {
-        fn new(value: f32) -> Self {
-            Self { value }
-        }
+        // Default is 10.0, +50% = 15.0
+        let armor = app.world().get::<TestArmor>(entity).unwrap();
+        assert!((armor.value - 15.0).abs() < 0.001);
     }
 
-    /// Test effect for modifying TestSpeed
-    #[derive(Event, Clone, Copy)]
-    struct TestSpeedEffect(ValueModifier);
+    #[test]
+    fn integration_applied_event_fires_once_per_application() {
+        #[derive(Component, Default)]
+        struct TestSpeed(f32);
 
-    impl StatusEffectApplicator<TestSpeed> for TestSpeedEffect {
-        fn modifier(&self) -> ValueModifier {
-            self.0
+        impl StatValue for TestSpeed {
+            fn stat_value(&self) -> f32 {
+                self.0
+            }
+            fn set_stat_value(&mut self, value: f32) {
+                self.0 = value;
+            }
         }
 
-        fn apply(&self, component: &mut TestSpeed, power: f32) {
-            component.value = self.0.apply_scaled(component.value, power);
+        #[derive(Event, Clone, Copy)]
+        struct TestSpeedEffect(ValueModifier);
+
+        impl StatusEffectApplicator<TestSpeed> for TestSpeedEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestSpeed, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
         }
-    }
 
-    #[test]
-    fn integration_plugin_registers_observer_and_resource() {
+        #[derive(Resource, Default)]
+        struct Fired(Vec<(Entity, Option<f32>, Option<f32>)>);
+
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            StatusEffectApplication::linear().with_stat_value(),
+        ));
+        app.init_resource::<Fired>();
+        app.add_observer(
+            |on: On<StatusEffectApplied<TestSpeedEffect>>, mut fired: ResMut<Fired>| {
+                fired.0.push((on.entity, on.old_value, on.new_value));
+            },
+        );
+        let present = app.world_mut().spawn(TestSpeed(100.0)).id();
+        let inserted = app.world_mut().spawn_empty().id();
+        let gone = app.world_mut().spawn_empty().id();
+        app.world_mut().despawn(gone);
+        app.update();
 
+        for entity in [present, inserted, gone] {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, TestSpeedEffect(ValueModifier::Val(10.0)));
+        }
         app.update();
 
-        // Verify resource is inserted
-        assert!(
-            app.world()
-                .contains_resource::<StatusEffectApplication<TestSpeed>>()
+        // Once for the auto-insert, after it; never for the despawned entity
+        assert_eq!(
+            app.world().resource::<Fired>().0,
+            vec![
+                (present, Some(100.0), Some(110.0)),
+                (inserted, Some(0.0), Some(10.0)),
+            ]
         );
-
-        // Verify default power is linear
-        let config = app.world().resource::<StatusEffectApplication<TestSpeed>>();
-        assert_eq!(config.power, scaling::LINEAR);
     }
 
     #[test]
-    fn integration_apply_status_effect_flat_linear() {
-        let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+    fn integration_applied_event_reports_scaling_loss() {
+        #[derive(Component, Default)]
+        struct TestSpeed(f32);
 
-        // Spawn entity with component
-        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        impl StatValue for TestSpeed {
+            fn stat_value(&self) -> f32 {
+                self.0
+            }
+            fn set_stat_value(&mut self, value: f32) {
+                self.0 = value;
+            }
+        }
 
-        app.update();
+        #[derive(Event, Clone, Copy)]
+        struct TestSpeedEffect(ValueModifier);
 
-        // Trigger effect
-        app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
-            entity,
-        });
+        impl StatusEffectApplicator<TestSpeed> for TestSpeedEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestSpeed, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
+        }
 
-        app.update();
+        #[derive(Resource, Default)]
+        struct Losses(Vec<f32>);
+
+        fn run(config: StatusEffectApplication<TestSpeed>) -> f32 {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins);
+            app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+                config,
+            ));
+            app.init_resource::<Losses>();
+            app.add_observer(
+                |on: On<StatusEffectApplied<TestSpeedEffect>>, mut losses: ResMut<Losses>| {
+                    losses.0.push(on.scaling_loss);
+                },
+            );
+            let entity = app.world_mut().spawn(TestSpeed(100.0)).id();
+            app.update();
 
-        // Verify effect was applied
-        let speed = app.world().get::<TestSpeed>(entity).unwrap();
-        assert!((speed.value - 120.0).abs() < 0.001);
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
+                entity,
+            });
+            app.update();
+
+            let losses = &app.world().resource::<Losses>().0;
+            assert_eq!(losses.len(), 1);
+            losses[0]
+        }
+
+        // 150 linear vs 100 * sqrt(1.5) = ~122.47
+        let loss = run(StatusEffectApplication::sqrt().with_stat_value());
+        assert!((loss - (150.0 - 122.474_49)).abs() < 0.001);
+
+        assert_eq!(
+            run(StatusEffectApplication::linear().with_stat_value()),
+            0.0
+        );
+        // Clamping 150 to 120 isn't lost to scaling
+        assert_eq!(
+            run(StatusEffectApplication::linear()
+                .with_stat_value()
+                .clamped(0.0, 120.0)),
+            0.0
+        );
     }
 
     #[test]
-    fn integration_apply_status_effect_percent_linear() {
+    fn integration_difficulty_scale_multiplies_modifier() {
+        #[derive(Component, Default)]
+        struct TestDamage {
+            value: f32,
+        }
+
+        #[derive(Event, Clone, Copy)]
+        struct TestDamageEffect(ValueModifier);
+
+        impl StatusEffectApplicator<TestDamage> for TestDamageEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestDamage, power: f32) {
+                component.value = self.0.apply_scaled(component.value, power);
+            }
+            fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+                Some(Self(modifier))
+            }
+        }
+
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        app.add_plugins(StatusEffectPlugin::<TestDamage, TestDamageEffect>::default());
 
-        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        let normal = app.world_mut().spawn(TestDamage { value: 100.0 }).id();
+        let hard = app.world_mut().spawn(TestDamage { value: 100.0 }).id();
+        app.update();
 
+        // Missing resource: authored strength
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestDamageEffect(ValueModifier::Val(20.0)),
+            entity: normal,
+        });
         app.update();
 
-        // Apply +50% effect
+        app.insert_resource(DifficultyScale(2.0));
         app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
-            entity,
+            effect: TestDamageEffect(ValueModifier::Val(20.0)),
+            entity: hard,
         });
-
         app.update();
 
-        let speed = app.world().get::<TestSpeed>(entity).unwrap();
-        assert!((speed.value - 150.0).abs() < 0.001);
+        assert_eq!(app.world().get::<TestDamage>(normal).unwrap().value, 120.0);
+        assert_eq!(app.world().get::<TestDamage>(hard).unwrap().value, 140.0);
     }
 
     #[test]
-    fn integration_apply_status_effect_with_sqrt_scaling() {
-        let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
-            StatusEffectApplication::sqrt(),
-        ));
-
-        let entity = app.world_mut().spawn(TestSpeed::new(40.0)).id();
+    fn integration_difficulty_scale_keeps_set_target() {
+        #[derive(Component, Default)]
+        struct TestDamage {
+            value: f32,
+        }
 
-        app.update();
+        #[derive(Event, Clone, Copy)]
+        struct TestDamageEffect(ValueModifier);
 
-        // Apply +30 with sqrt scaling: sqrt(40^2 + 30^2) = 50
-        app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Val(30.0)),
-            entity,
-        });
+        impl StatusEffectApplicator<TestDamage> for TestDamageEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestDamage, power: f32) {
+                component.value = self.0.apply_scaled(component.value, power);
+            }
+            fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+                Some(Self(modifier))
+            }
+        }
 
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestDamage, TestDamageEffect>::default());
+        app.insert_resource(DifficultyScale(2.0));
+        let entity = app.world_mut().spawn(TestDamage { value: 100.0 }).id();
         app.update();
 
-        let speed = app.world().get::<TestSpeed>(entity).unwrap();
-        assert!((speed.value - 50.0).abs() < 0.001);
+        for target in [0.0, 50.0] {
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: TestDamageEffect(ValueModifier::Set(target)),
+                entity,
+            });
+            app.update();
+            assert_eq!(app.world().get::<TestDamage>(entity).unwrap().value, target);
+        }
     }
 
     #[test]
-    fn integration_apply_status_effect_with_custom_power() {
+    fn integration_explicit_auto_insert_uses_resource_value() {
+        #[derive(Component, Clone, Default)]
+        struct TestStamina {
+            value: f32,
+        }
+
+        #[derive(Event, Clone, Copy)]
+        struct TestStaminaEffect(ValueModifier);
+
+        impl StatusEffectApplicator<TestStamina> for TestStaminaEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+            fn apply(&self, component: &mut TestStamina, power: f32) {
+                component.value = self.0.apply_scaled(component.value, power);
+            }
+        }
+
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
-            StatusEffectApplication::with_power(0.7),
+        app.add_plugins(StatusEffectPlugin::<TestStamina, TestStaminaEffect>::new(
+            StatusEffectApplication::default().explicit_auto_insert(),
         ));
+        app.insert_resource(AutoInsert::new(TestStamina { value: 100.0 }));
 
-        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        let entity = app.world_mut().spawn_empty().id();
 
         app.update();
 
-        // Apply +50% with power=0.7: 100 * 1.5^0.7 = ~136.8
+        // +50% on the explicit 100, not on Default's 0
         app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
+            effect: TestStaminaEffect(ValueModifier::Percent(50.0)),
             entity,
         });
 
+        app.update();
         app.update();
 
-        let speed = app.world().get::<TestSpeed>(entity).unwrap();
-        let expected = 100.0 * 1.5_f32.powf(0.7);
-        assert!((speed.value - expected).abs() < 0.1);
+        let stamina = app.world().get::<TestStamina>(entity).unwrap();
+        assert!((stamina.value - 150.0).abs() < 0.001);
     }
 
     #[test]
-    fn integration_multiple_effects_stack() {
+    fn integration_explicit_auto_insert_without_resource_drops_effect() {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            StatusEffectApplication::default().explicit_auto_insert(),
+        ));
 
-        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        let entity = app.world_mut().spawn_empty().id();
 
         app.update();
 
-        // Apply first effect: +20
         app.world_mut().commands().trigger(ApplyStatusEffect {
             effect: TestSpeedEffect(ValueModifier::Val(20.0)),
             entity,
         });
 
         app.update();
-
-        // Apply second effect: +10%
-        app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Percent(10.0)),
-            entity,
-        });
-
         app.update();
 
-        // 100 + 20 = 120, then 120 * 1.1 = 132
-        let speed = app.world().get::<TestSpeed>(entity).unwrap();
-        assert!((speed.value - 132.0).abs() < 0.001);
+        // Nothing inserted rather than silently using Default
+        assert!(app.world().get::<TestSpeed>(entity).is_none());
     }
 
     #[test]
-    fn integration_effect_on_nonexistent_entity_no_panic() {
-        let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+    fn integration_effect_opting_out_of_auto_insert_is_dropped() {
+        #[derive(Event, Clone, Copy)]
+        struct TestSlow(ValueModifier);
 
-        app.update();
+        impl StatusEffectApplicator<TestSpeed> for TestSlow {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
 
-        // Trigger effect on entity that doesn't exist
-        let fake_entity = Entity::from_raw_u32(9999).unwrap();
-        app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Val(20.0)),
-            entity: fake_entity,
-        });
+            fn apply(&self, component: &mut TestSpeed, power: f32) {
+                component.value = self.0.apply_scaled(component.value, power);
+            }
 
-        // Should not panic
-        app.update();
-    }
+            fn should_auto_insert(&self) -> bool {
+                false
+            }
+        }
 
-    #[test]
-    fn integration_effect_on_entity_without_component_auto_inserts() {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSlow>::default());
 
-        // Spawn entity WITHOUT TestSpeed component
         let entity = app.world_mut().spawn_empty().id();
+        app.update();
 
+        // The debuff doesn't create the component
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: TestSlow(ValueModifier::Val(-5.0)),
+            entity,
+        });
         app.update();
+        app.update();
+        assert!(app.world().get::<TestSpeed>(entity).is_none());
 
-        // Trigger effect - should auto-insert component and apply effect
+        // The buff does
         app.world_mut().commands().trigger(ApplyStatusEffect {
             effect: TestSpeedEffect(ValueModifier::Val(20.0)),
             entity,
         });
-
-        // First update: observer runs, queues insert + re-trigger
         app.update();
-        // Second update: re-triggered observer applies effect to inserted component
         app.update();
-
-        // Component should now exist with default (0.0) + effect (20.0)
         let speed = app.world().get::<TestSpeed>(entity).unwrap();
         assert!((speed.value - 20.0).abs() < 0.001);
-    }
-
-    #[test]
-    fn integration_auto_insert_with_percent_effect() {
-        let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
-
-        // Spawn entity WITHOUT TestSpeed component
-        let entity = app.world_mut().spawn_empty().id();
-
-        app.update();
 
-        // Trigger percent effect on entity without component
-        // Default TestSpeed.value is 0.0, so +50% of 0 = 0
+        // Once it exists, the debuff applies as usual
         app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestSpeedEffect(ValueModifier::Percent(50.0)),
+            effect: TestSlow(ValueModifier::Val(-5.0)),
             entity,
         });
-
-        app.update();
         app.update();
-
-        // Component should exist with default value (percent of 0 is still 0)
         let speed = app.world().get::<TestSpeed>(entity).unwrap();
-        assert!((speed.value - 0.0).abs() < 0.001);
+        assert!((speed.value - 15.0).abs() < 0.001);
     }
 
     #[test]
-    fn integration_auto_insert_with_custom_default() {
-        /// Component with non-zero default for testing
-        #[derive(Component)]
-        struct TestArmor {
-            value: f32,
-        }
+    fn integration_sparse_set_component_applies_auto_inserts_and_removes() {
+        #[derive(Component, Default)]
+        #[component(storage = "SparseSet")]
+        struct Rage(f32);
 
-        impl Default for TestArmor {
-            fn default() -> Self {
-                Self { value: 10.0 } // Non-zero default
+        impl StatValue for Rage {
+            fn stat_value(&self) -> f32 {
+                self.0
+            }
+
+            fn set_stat_value(&mut self, value: f32) {
+                self.0 = value;
             }
         }
 
         #[derive(Event, Clone, Copy)]
-        struct TestArmorEffect(ValueModifier);
+        struct RageEffect(ValueModifier);
 
-        impl StatusEffectApplicator<TestArmor> for TestArmorEffect {
+        impl StatusEffectApplicator<Rage> for RageEffect {
             fn modifier(&self) -> ValueModifier {
                 self.0
             }
-            fn apply(&self, component: &mut TestArmor, power: f32) {
-                component.value = self.0.apply_scaled(component.value, power);
+
+            fn apply(&self, component: &mut Rage, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
             }
         }
 
+        #[derive(Resource, Default)]
+        struct Changes(u32);
+
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(StatusEffectPlugin::<TestArmor, TestArmorEffect>::default());
-
-        let entity = app.world_mut().spawn_empty().id();
-
+        app.add_plugins(StatusEffectPlugin::<Rage, RageEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.init_resource::<Changes>();
+        app.add_systems(
+            Update,
+            |q: Query<(), Changed<Rage>>, mut changes: ResMut<Changes>| {
+                changes.0 += q.iter().count() as u32;
+            },
+        );
+        let present = app.world_mut().spawn(Rage(10.0)).id();
+        let missing = app.world_mut().spawn_empty().id();
         app.update();
+        app.world_mut().resource_mut::<Changes>().0 = 0;
 
-        // Apply +50% to entity without component
-        app.world_mut().commands().trigger(ApplyStatusEffect {
-            effect: TestArmorEffect(ValueModifier::Percent(50.0)),
-            entity,
-        });
-
+        // Apply to an existing sparse-set component
+        app.world_mut()
+            .commands()
+            .apply_effect(present, RageEffect(ValueModifier::Val(15.0)));
         app.update();
+        assert_eq!(app.world().get::<Rage>(present).unwrap().0, 25.0);
+        assert_eq!(app.world().resource::<Changes>().0, 1);
+
+        // Auto-insert and re-trigger within one update
+        app.world_mut()
+            .commands()
+            .apply_effect(missing, RageEffect(ValueModifier::Val(5.0)));
         app.update();
+        assert_eq!(app.world().get::<Rage>(missing).unwrap().0, 5.0);
 
-        // Default is 10.0, +50% = 15.0
-        let armor = app.world().get::<TestArmor>(entity).unwrap();
-        assert!((armor.value - 15.0).abs() < 0.001);
+        // Removal rebuilds from base
+        app.world_mut()
+            .commands()
+            .trigger(RemoveStatusEffect::<RageEffect>::new(present));
+        app.update();
+        assert_eq!(app.world().get::<Rage>(present).unwrap().0, 10.0);
+        assert!(app.world().get::<AppliedEffects<Rage>>(present).is_none());
     }
 
     // ============================================================================
@@ -1325,6 +5032,7 @@ mod tests {
             .query::<(Entity, &StatusEffectObserverMarker)>()
             .iter(app.world())
         {
+            #[allow(clippy::collapsible_if)]
             if let Some(name) = app.world().get::<Name>(entity) {
                 if name.as_str() == "MacroTestEffect_observer" {
                     found_name = true;
@@ -1339,6 +5047,29 @@ mod tests {
         );
     }
 
+    fn on_macro_test_effect_logged(_on: On<ApplyStatusEffect<MacroTestEffect>>) {}
+
+    #[test]
+    fn integration_macro_records_registrations() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        status_effect_observer!(app, MacroTestEffect, on_macro_test_effect);
+        status_effect_observer!(app, MacroTestEffect, on_macro_test_effect_logged);
+
+        let observers = app.world().resource::<StatusEffectObservers>();
+        let entries: Vec<_> = observers.for_effect("MacroTestEffect").collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].observer, "on_macro_test_effect");
+        assert_eq!(entries[1].observer, "on_macro_test_effect_logged");
+        assert_ne!(entries[0].marker, entries[1].marker);
+
+        for entry in entries {
+            let name = app.world().get::<Name>(entry.marker).unwrap();
+            assert_eq!(name.as_str(), "MacroTestEffect_observer");
+        }
+    }
+
     // ============================================================================
     // Integration Tests - Effect Stacking with Different Scaling
     // ============================================================================
@@ -1515,6 +5246,43 @@ mod tests {
         assert!((app.world().get::<TestSpeed>(entity3).unwrap().value - 150.0).abs() < 0.001);
     }
 
+    /// Same as above, with one batched trigger for many entities
+    #[test]
+    fn scenario_trigger_batch_matches_loop() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::default());
+
+        let batched: Vec<Entity> = (0..1000)
+            .map(|i| app.world_mut().spawn(TestSpeed::new(i as f32)).id())
+            .collect();
+        let looped: Vec<Entity> = (0..1000)
+            .map(|i| app.world_mut().spawn(TestSpeed::new(i as f32)).id())
+            .collect();
+        app.update();
+
+        let effect = TestSpeedEffect(ValueModifier::Percent(25.0));
+        ApplyStatusEffect::trigger_batch(
+            &mut app.world_mut().commands(),
+            effect,
+            batched.iter().copied(),
+        );
+        for &entity in &looped {
+            app.world_mut()
+                .commands()
+                .trigger(ApplyStatusEffect { effect, entity });
+        }
+        app.update();
+
+        for (i, (&batched, &looped)) in batched.iter().zip(&looped).enumerate() {
+            let expected = i as f32 * 1.25;
+            let batched = app.world().get::<TestSpeed>(batched).unwrap().value;
+            let looped = app.world().get::<TestSpeed>(looped).unwrap().value;
+            assert!((batched - expected).abs() < 0.001);
+            assert_eq!(batched, looped);
+        }
+    }
+
     // ============================================================================
     // API Verification Tests
     // ============================================================================
@@ -1532,6 +5300,18 @@ mod tests {
         assert_eq!(apply_event.entity.to_bits(), entity.to_bits());
     }
 
+    /// Test that ApplyStatusEffect::new fills the same fields as the literal
+    #[test]
+    fn api_apply_status_effect_new() {
+        let effect = TestSpeedEffect(ValueModifier::Val(10.0));
+        let entity = Entity::from_raw_u32(1).unwrap();
+
+        let constructed = ApplyStatusEffect::new(entity, effect);
+        let literal = ApplyStatusEffect { effect, entity };
+        assert_eq!(constructed.effect.0, literal.effect.0);
+        assert_eq!(constructed.entity, literal.entity);
+    }
+
     /// Test that ApplyStatusEffect can be cloned
     #[test]
     fn api_apply_status_effect_clone() {
@@ -1539,6 +5319,7 @@ mod tests {
         let entity = Entity::from_raw_u32(42).unwrap();
 
         let original = ApplyStatusEffect { effect, entity };
+        #[allow(clippy::clone_on_copy)]
         let cloned = original.clone();
 
         assert_eq!(original.effect.0, cloned.effect.0);
@@ -1698,4 +5479,113 @@ mod tests {
         assert!((app.world().get::<TestSpeed>(entity1).unwrap().value - 150.0).abs() < 0.001);
         assert!((app.world().get::<TestSpeed>(entity2).unwrap().value - 200.0).abs() < 0.001);
     }
+
+    /// Test invalid effects are rejected before they apply
+    #[test]
+    fn api_validate_rejects_invalid_effect() {
+        #[derive(Event, Clone, Copy)]
+        struct Heal(f32);
+
+        impl StatusEffectApplicator<TestSpeed> for Heal {
+            fn modifier(&self) -> ValueModifier {
+                ValueModifier::Val(self.0)
+            }
+
+            fn apply(&self, component: &mut TestSpeed, power: f32) {
+                component.value = self.modifier().apply_scaled(component.value, power);
+            }
+
+            fn validate(&self) -> Result<(), String> {
+                if self.0 < 0.0 {
+                    return Err(format!("heal amount {} is negative", self.0));
+                }
+                Ok(())
+            }
+        }
+
+        assert_eq!(
+            Heal(-5.0).validate(),
+            Err("heal amount -5 is negative".to_string())
+        );
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, Heal>::default());
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+
+        app.world_mut().commands().apply_effect(entity, Heal(-5.0));
+        app.update();
+        let expected = if cfg!(debug_assertions) { 100.0 } else { 95.0 };
+        assert_eq!(
+            app.world().get::<TestSpeed>(entity).unwrap().value,
+            expected
+        );
+
+        app.world_mut().commands().apply_effect(entity, Heal(5.0));
+        app.update();
+        assert_eq!(
+            app.world().get::<TestSpeed>(entity).unwrap().value,
+            expected + 5.0
+        );
+    }
+
+    /// Test negating a stat and back through the plugin
+    #[test]
+    fn api_negate_round_trip() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            StatusEffectApplication::sqrt(),
+        ));
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, TestSpeedEffect(ValueModifier::Negate));
+        app.update();
+        assert_eq!(app.world().get::<TestSpeed>(entity).unwrap().value, -100.0);
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, TestSpeedEffect(ValueModifier::Negate));
+        app.update();
+        assert_eq!(app.world().get::<TestSpeed>(entity).unwrap().value, 100.0);
+    }
+
+    #[cfg(feature = "strict")]
+    fn strict_app(config: StatusEffectApplication<TestSpeed>) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<TestSpeed, TestSpeedEffect>::new(
+            config,
+        ));
+        let entity = app.world_mut().spawn(TestSpeed::new(100.0)).id();
+        (app, entity)
+    }
+
+    #[cfg(all(feature = "strict", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "non-finite power")]
+    fn strict_panics_on_nan_power() {
+        let (mut app, entity) = strict_app(StatusEffectApplication::linear());
+        app.world_mut().commands().apply_effect_scaled(
+            entity,
+            TestSpeedEffect(ValueModifier::Val(10.0)),
+            f32::NAN,
+        );
+        app.update();
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn strict_accepts_valid_effects() {
+        let (mut app, entity) =
+            strict_app(StatusEffectApplication::linear().allow_excess_reductions());
+        app.world_mut()
+            .commands()
+            .apply_effect_scaled(entity, TestSpeedEffect(ValueModifier::Val(10.0)), 1.0)
+            .apply_effect(entity, TestSpeedEffect(ValueModifier::Percent(-150.0)));
+        app.update();
+        assert_eq!(app.world().get::<TestSpeed>(entity).unwrap().value, 0.0);
+    }
 }
@@ -0,0 +1,272 @@
+//! Stat locks.
+//!
+//! While an entity has [`StatLocked<C>`], every new effect targeting `C` on it
+//! is ignored. Effects applied before the lock keep their changes. Locks are
+//! applied with [`LockStatusEffect<C>`] and lifted by removing the marker or
+//! letting its duration run out. Both need `C`'s config to be
+//! [`lockable`](crate::StatusEffectApplication::lockable).
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::MutableComponent;
+
+/// Marks component `C` on this entity as immune to new effects.
+///
+/// Insert it directly or trigger [`LockStatusEffect<C>`]. Remove it to unlock.
+#[derive(Component)]
+pub struct StatLocked<C: MutableComponent> {
+    timer: Option<Timer>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> StatLocked<C> {
+    /// Locks `C` until the marker is removed.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            timer: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Locks `C` for the given number of seconds.
+    #[must_use]
+    pub fn for_secs(secs: f32) -> Self {
+        Self {
+            timer: Some(Timer::from_seconds(secs, TimerMode::Once)),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the seconds left on a timed lock, or `None` if it never expires.
+    #[must_use]
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.timer.as_ref().map(Timer::remaining_secs)
+    }
+}
+
+impl<C: MutableComponent> Default for StatLocked<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Locks component `C` on an entity against new effects.
+///
+/// Needs `C`'s config to be [`lockable`](crate::StatusEffectApplication::lockable).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// fn root_in_place(mut commands: Commands, entity: Entity) {
+///     // Speed can't be buffed or slowed for 3 seconds
+///     commands.trigger(LockStatusEffect::<Speed>::for_secs(entity, 3.0));
+/// }
+/// ```
+#[derive(EntityEvent)]
+pub struct LockStatusEffect<C: MutableComponent> {
+    /// The entity to lock
+    pub entity: Entity,
+    /// Lock duration in seconds, or `None` to lock until removed
+    pub duration: Option<f32>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> LockStatusEffect<C> {
+    /// Locks `C` on `entity` until [`StatLocked<C>`] is removed.
+    #[must_use]
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            duration: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Locks `C` on `entity` for the given number of seconds.
+    #[must_use]
+    pub fn for_secs(entity: Entity, secs: f32) -> Self {
+        Self {
+            entity,
+            duration: Some(secs),
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn lock_status_effect_observer<C: MutableComponent>(
+    on: On<LockStatusEffect<C>>,
+    mut commands: Commands,
+) {
+    let lock = on
+        .duration
+        .map_or_else(StatLocked::<C>::new, StatLocked::<C>::for_secs);
+    if let Ok(mut entity) = commands.get_entity(on.entity) {
+        entity.insert(lock);
+    }
+}
+
+/// Removes timed locks whose duration has run out.
+fn tick_stat_locks<C: MutableComponent>(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut StatLocked<C>)>,
+) {
+    for (entity, mut lock) in &mut q {
+        let Some(timer) = lock.timer.as_mut() else {
+            continue;
+        };
+        if timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<StatLocked<C>>();
+        }
+    }
+}
+
+/// Registers the lock observer and timer for component `C` once per app.
+pub(crate) struct StatLockPlugin<C: MutableComponent>(PhantomData<C>);
+
+impl<C: MutableComponent> Default for StatLockPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for StatLockPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(lock_status_effect_observer::<C>);
+        app.add_systems(Update, tick_stat_locks::<C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn on_rejected(&self, reason: RejectReason, commands: &mut Commands, _entity: Entity) {
+            commands.queue(move |world: &mut World| {
+                if let Some(mut rejections) = world.get_resource_mut::<Rejections>() {
+                    rejections.0.push(reason);
+                }
+            });
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Rejections(Vec<RejectReason>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().lockable(),
+        ));
+        app
+    }
+
+    fn buff(app: &mut App, entity: Entity) {
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: SpeedEffect(ValueModifier::Val(10.0)),
+            entity,
+        });
+        app.update();
+    }
+
+    #[test]
+    fn lock_blocks_new_effects_until_unlocked() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        // Applied before the lock: kept
+        buff(&mut app, entity);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+
+        app.world_mut()
+            .commands()
+            .trigger(LockStatusEffect::<Speed>::new(entity));
+        app.update();
+
+        buff(&mut app, entity);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<StatLocked<Speed>>();
+        buff(&mut app, entity);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 120.0);
+    }
+
+    #[test]
+    fn timed_lock_expires() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .trigger(LockStatusEffect::<Speed>::for_secs(entity, 0.5));
+        app.update();
+        buff(&mut app, entity);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+
+        app.update();
+        app.update();
+        assert!(app.world().get::<StatLocked<Speed>>(entity).is_none());
+        buff(&mut app, entity);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+    }
+
+    #[test]
+    fn locked_entity_rejects_as_immune() {
+        let mut app = app();
+        app.init_resource::<Rejections>();
+        let entity = app
+            .world_mut()
+            .spawn((Speed(100.0), StatLocked::<Speed>::new()))
+            .id();
+
+        buff(&mut app, entity);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+        assert_eq!(
+            app.world().resource::<Rejections>().0,
+            [RejectReason::Immune]
+        );
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<StatLocked<Speed>>();
+        buff(&mut app, entity);
+        assert_eq!(app.world().resource::<Rejections>().0.len(), 1);
+    }
+}
@@ -0,0 +1,239 @@
+//! Copying tracked effects between entities.
+//!
+//! [`copy_effects`](crate::StatusEffectCommandsExt::copy_effects) reads every
+//! tracked effect on one entity and applies an equivalent to another, e.g.
+//! for a "mimic the target's buffs" ability. Effects are rebuilt through the
+//! [registry](crate::StatusEffectRegistry), so their types need
+//! [`from_modifier`](crate::StatusEffectApplicator::from_modifier). Each entry
+//! is applied to the component it was tracked on only.
+
+use bevy::prelude::*;
+
+use crate::{PowerOverride, StatusEffectRegistry, with_override};
+
+/// Applies equivalents of `from`'s tracked effects to `to`, oldest first.
+pub(crate) fn copy_effects(world: &mut World, from: Entity, to: Entity) {
+    if world.get_entity(to).is_err() {
+        return;
+    }
+    let Some(registry) = world.get_resource::<StatusEffectRegistry>() else {
+        return;
+    };
+    // Each entry is copied onto its own component only, so effect types
+    // targeting several tracked components aren't applied once per tracker
+    let copies: Vec<_> = registry
+        .tracked()
+        .flat_map(|(component, read)| {
+            read(world, from)
+                .into_iter()
+                .map(move |effect| (component, effect))
+        })
+        .filter_map(|(component, effect)| {
+            let apply = registry.apply_to_fn(&effect.effect, component)?;
+            Some((apply, effect))
+        })
+        .collect();
+
+    for (apply, effect) in copies {
+        let modifier = effect.weighted_modifier();
+        let copied = match effect.power {
+            Some(power) => {
+                let mut copied = false;
+                with_override(world, PowerOverride::new(power), |world| {
+                    copied = apply(world, to, modifier);
+                });
+                copied
+            }
+            None => apply(world, to, modifier),
+        };
+        if !copied {
+            log_warn!(
+                "Skipping copy of {} to {to}: effect is not rebuildable",
+                effect.effect
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    impl StatValue for Armor {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Barrier(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for Barrier {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    /// Can't be rebuilt from a modifier, so it can't be copied
+    #[derive(Event, Clone, Copy)]
+    struct Blessing(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Blessing {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn copies_buffs_onto_another_entity() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Speed, Blessing>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, Barrier>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let a = app.world_mut().spawn((Speed(100.0), Armor(20.0))).id();
+        let b = app.world_mut().spawn(Speed(50.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(a, Haste(ValueModifier::Percent(50.0)))
+            .apply_effect(a, Blessing(ValueModifier::Val(5.0)))
+            .apply_effect(a, Barrier(ValueModifier::Val(10.0)));
+        app.update();
+        app.world_mut().commands().copy_effects(a, b);
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<Speed>(b).unwrap().0, 75.0);
+        // Auto-inserted with the copied barrier
+        assert_eq!(world.get::<Armor>(b).unwrap().0, 10.0);
+        let speed = world.get::<AppliedEffects<Speed>>(b).unwrap();
+        assert_eq!(speed.len(), 1);
+        assert!(speed.effects[0].is::<Haste>());
+        assert_eq!(speed.effects[0].modifier, ValueModifier::Percent(50.0));
+        let armor = world.get::<AppliedEffects<Armor>>(b).unwrap();
+        assert!(armor.effects[0].is::<Barrier>());
+
+        // The source keeps its effects
+        assert_eq!(world.get::<Speed>(a).unwrap().0, 155.0);
+        assert_eq!(world.get::<AppliedEffects<Speed>>(a).unwrap().len(), 2);
+    }
+
+    /// Targets both stats with one event
+    #[derive(Event, Clone, Copy)]
+    struct Vigor(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Vigor {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    impl StatusEffectApplicator<Armor> for Vigor {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    #[test]
+    fn effect_on_two_stats_is_copied_once_onto_each() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Vigor>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, Vigor>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let a = app.world_mut().spawn((Speed(100.0), Armor(20.0))).id();
+        let b = app.world_mut().spawn(Speed(50.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(a, Vigor(ValueModifier::Val(10.0)));
+        app.update();
+        app.world_mut().commands().copy_effects(a, b);
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<Speed>(b).unwrap().0, 60.0);
+        // Auto-inserted with the copied entry
+        assert_eq!(world.get::<Armor>(b).unwrap().0, 10.0);
+        assert_eq!(world.get::<AppliedEffects<Speed>>(b).unwrap().len(), 1);
+        assert_eq!(world.get::<AppliedEffects<Armor>>(b).unwrap().len(), 1);
+    }
+}
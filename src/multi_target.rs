@@ -0,0 +1,226 @@
+//! Effects that apply one modifier to several component types.
+//!
+//! A [`MultiTargetEffect`] holds a modifier and a list of targets, each pairing
+//! a component type with the effect type that modifies it. Triggering it with
+//! [`ApplyStatusEffect`] applies the modifier to every target component the
+//! entity has, through the regular per-component observers, once
+//! [`MultiTargetPlugin`] is added.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{ApplyStatusEffect, MutableComponent, StatusEffectApplicator, ValueModifier};
+
+type ApplyTargetFn = Arc<dyn Fn(&mut World, Entity, ValueModifier) + Send + Sync>;
+
+/// One modifier applied to several component types at once.
+///
+/// Targets the entity doesn't have are skipped rather than auto-inserted.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Attack(f32);
+///
+/// #[derive(Component, Default)]
+/// struct Defense(f32);
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct AttackModifier(ValueModifier);
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct DefenseModifier(ValueModifier);
+///
+/// impl StatusEffectApplicator<Attack> for AttackModifier {
+///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn apply(&self, component: &mut Attack, power: f32) {
+///         component.0 = self.0.apply_scaled(component.0, power);
+///     }
+/// }
+///
+/// impl StatusEffectApplicator<Defense> for DefenseModifier {
+///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn apply(&self, component: &mut Defense, power: f32) {
+///         component.0 = self.0.apply_scaled(component.0, power);
+///     }
+/// }
+///
+/// fn weaken(mut commands: Commands, entity: Entity) {
+///     let effect = MultiTargetEffect::new(ValueModifier::Percent(-10.0))
+///         .target::<Attack, _>(AttackModifier)
+///         .target::<Defense, _>(DefenseModifier);
+///     commands.trigger(ApplyStatusEffect { effect, entity });
+/// }
+/// ```
+#[derive(Event, Clone)]
+pub struct MultiTargetEffect {
+    /// Modifier applied to every target
+    pub modifier: ValueModifier,
+    targets: Vec<ApplyTargetFn>,
+}
+
+impl MultiTargetEffect {
+    /// Creates an effect with no targets.
+    #[must_use]
+    pub fn new(modifier: ValueModifier) -> Self {
+        Self {
+            modifier,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Adds component `C`, modified by the effect `make` builds from the modifier.
+    ///
+    /// Tuple-struct effects can pass their constructor, e.g. `SpeedModifier`.
+    #[must_use]
+    pub fn target<C, E>(mut self, make: fn(ValueModifier) -> E) -> Self
+    where
+        C: MutableComponent,
+        E: StatusEffectApplicator<C>,
+    {
+        self.targets.push(Arc::new(move |world, entity, modifier| {
+            if world.get::<C>(entity).is_some() {
+                world.trigger(ApplyStatusEffect {
+                    effect: make(modifier),
+                    entity,
+                });
+            }
+        }));
+        self
+    }
+
+    /// Returns the number of target component types.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns true if the effect has no targets.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+fn apply_multi_target_observer(
+    on: On<ApplyStatusEffect<MultiTargetEffect>>,
+    mut commands: Commands,
+) {
+    let entity = on.entity;
+    let modifier = on.effect.modifier;
+    for target in on.effect.targets.iter().cloned() {
+        commands.queue(move |world: &mut World| target(world, entity, modifier));
+    }
+}
+
+/// Registers the [`MultiTargetEffect`] observer.
+///
+/// Not added by [`StatusEffectPlugin`](crate::StatusEffectPlugin); add it
+/// once to apply multi-target effects.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(MultiTargetPlugin);
+/// ```
+pub struct MultiTargetPlugin;
+
+impl Plugin for MultiTargetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(apply_multi_target_observer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Attack(f32);
+
+    #[derive(Component, Default)]
+    struct Defense(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct AttackModifier(ValueModifier);
+
+    #[derive(Event, Clone, Copy)]
+    struct DefenseModifier(ValueModifier);
+
+    impl StatusEffectApplicator<Attack> for AttackModifier {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Attack, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    impl StatusEffectApplicator<Defense> for DefenseModifier {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Defense, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Attack, AttackModifier>::default());
+        app.add_plugins(StatusEffectPlugin::<Defense, DefenseModifier>::default());
+        app.add_plugins(MultiTargetPlugin);
+        app
+    }
+
+    fn weaken() -> MultiTargetEffect {
+        MultiTargetEffect::new(ValueModifier::Percent(-10.0))
+            .target::<Attack, _>(AttackModifier)
+            .target::<Defense, _>(DefenseModifier)
+    }
+
+    #[test]
+    fn one_trigger_weakens_both_stats() {
+        let mut app = app();
+        let entity = app.world_mut().spawn((Attack(50.0), Defense(20.0))).id();
+        app.update();
+
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: weaken(),
+            entity,
+        });
+        app.update();
+
+        assert!((app.world().get::<Attack>(entity).unwrap().0 - 45.0).abs() < 0.001);
+        assert!((app.world().get::<Defense>(entity).unwrap().0 - 18.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn missing_components_are_skipped() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Attack(50.0)).id();
+        app.update();
+
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: weaken(),
+            entity,
+        });
+        app.update();
+
+        assert!((app.world().get::<Attack>(entity).unwrap().0 - 45.0).abs() < 0.001);
+        assert!(app.world().get::<Defense>(entity).is_none());
+    }
+}
@@ -0,0 +1,321 @@
+//! Additive percent modifiers.
+//!
+//! By default each `Percent` modifier multiplies the current value, so +50%
+//! then +30% gives `base * 1.5 * 1.3`. Components configured with
+//! [`StatusEffectApplication::additive_percents`](crate::StatusEffectApplication::additive_percents)
+//! instead add percent points to a running sum kept in a [`PercentSum<C>`], and
+//! the value becomes `base * (1 + sum / 100)` at the configured power: +50%
+//! then +30% gives `base * 1.8`.
+//!
+//! Applying the negated percent removes it again: `Percent(-50)` subtracts 50
+//! points from the sum. Flat modifiers apply as usual and become part of the
+//! base.
+//!
+//! [`PercentCombineMode`] makes the same choice for reductions only, via
+//! [`StatusEffectApplication::combine_reductions`](crate::StatusEffectApplication::combine_reductions):
+//! with [`Additive`](PercentCombineMode::Additive), two -50% slows sum to
+//! -100% and bring the value to zero, while buffs still multiply.
+//!
+//! Summed percents write the value through the component's
+//! [`StatValue`](crate::StatValue) impl, so the effect's
+//! [`apply`](crate::StatusEffectApplicator::apply) and
+//! [`apply_from`](crate::StatusEffectApplicator::apply_from) aren't called
+//! for them. Side effects or fields other than the stat value belong in an
+//! observer of [`StatusEffectApplied`](crate::StatusEffectApplied) instead.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{
+    EPSILON, MutableComponent, PipelineContext, StatusEffectApplication, StatusEffectApplicator,
+    ValueModifier, pipeline,
+};
+
+/// How percent reductions on a component combine.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum PercentCombineMode {
+    /// Each reduction multiplies the current value: two -50% leave 25%.
+    /// Reductions never reach zero unless one is -100% on its own.
+    #[default]
+    Multiplicative,
+    /// Reductions add up in a [`PercentSum<C>`]: two -50% sum to -100% and
+    /// leave zero. Sums past -100% are floored at zero.
+    Additive,
+}
+
+/// Running sum of additive percent points applied to component `C`.
+#[derive(Component)]
+pub struct PercentSum<C: MutableComponent> {
+    base: f32,
+    sum: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> PercentSum<C> {
+    /// Returns the value before percent modifiers.
+    #[must_use]
+    pub fn base(&self) -> f32 {
+        self.base
+    }
+
+    /// Returns the sum of applied percent points.
+    #[must_use]
+    pub fn sum(&self) -> f32 {
+        self.sum
+    }
+
+    /// Returns the value `base * (1 + sum / 100)` at `power`.
+    #[must_use]
+    pub fn value(&self, power: f32) -> f32 {
+        ValueModifier::Percent(self.sum).apply_scaled(self.base, power)
+    }
+
+    /// Adds `percent` points to the sum.
+    ///
+    /// The base is recovered from `current` first, so flat changes applied
+    /// since the last percent are kept. Returns the new value at `power`.
+    pub(crate) fn add(&mut self, current: f32, percent: f32, power: f32) -> f32 {
+        let multiplier = ValueModifier::Percent(self.sum).apply_scaled(1.0, power);
+        // A zero multiplier loses the base; keep the last known one
+        if multiplier > EPSILON {
+            self.base = current / multiplier;
+        }
+        self.sum += percent;
+        self.value(power)
+    }
+}
+
+impl<C: MutableComponent> Default for PercentSum<C> {
+    fn default() -> Self {
+        Self {
+            base: 0.0,
+            sum: 0.0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Applies a percent `effect` to the sum instead of the component.
+///
+/// Returns the effect as applied, after resistance, and the overflow cut off
+/// by clamping.
+pub(crate) fn apply_effect<C, E>(
+    effect: &E,
+    component: &mut C,
+    sum: &mut PercentSum<C>,
+    config: &StatusEffectApplication<C>,
+    ctx: &PipelineContext,
+) -> (E, f32)
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let effect = pipeline::resist(effect, ctx);
+    let Some(current) = config.stat_value(component) else {
+        return (effect, 0.0);
+    };
+    let value = sum.add(current, effect.modifier().percent_value(), ctx.power);
+    config.set_stat_value(component, ctx.finish(value));
+    (effect, ctx.overflow(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Damage(f32);
+
+    impl StatValue for Damage {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct DamageEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Damage> for DamageEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Damage, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Enrage(ValueModifier);
+
+    impl StatusEffectApplicator<Damage> for Enrage {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Damage, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Weaken(ValueModifier);
+
+    impl StatusEffectApplicator<Damage> for Weaken {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Damage, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+
+        fn min_magnitude_after_resistance(&self) -> Option<f32> {
+            Some(5.0)
+        }
+    }
+
+    fn apply(app: &mut App, entity: Entity, modifier: ValueModifier) -> f32 {
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, DamageEffect(modifier));
+        app.update();
+        app.world().get::<Damage>(entity).unwrap().0
+    }
+
+    #[test]
+    fn percents_add_up_and_remove() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Damage, DamageEffect>::new(
+            StatusEffectApplication::linear().additive_percents(),
+        ));
+        let entity = app.world_mut().spawn(Damage(100.0)).id();
+
+        assert_eq!(apply(&mut app, entity, ValueModifier::Percent(50.0)), 150.0);
+        // Sum of 80%, not 1.5 * 1.3
+        assert_eq!(apply(&mut app, entity, ValueModifier::Percent(30.0)), 180.0);
+        assert_eq!(
+            app.world().get::<PercentSum<Damage>>(entity).unwrap().sum(),
+            80.0
+        );
+
+        // Removing the +50% leaves +30%
+        assert_eq!(
+            apply(&mut app, entity, ValueModifier::Percent(-50.0)),
+            130.0
+        );
+
+        // Flats join the base
+        assert_eq!(apply(&mut app, entity, ValueModifier::Val(13.0)), 143.0);
+        let value = apply(&mut app, entity, ValueModifier::Percent(-30.0));
+        assert!((value - 110.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn effects_share_one_sum() {
+        let config = StatusEffectApplication::linear().additive_percents();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Damage, DamageEffect>::new(
+            config.clone(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Damage, Enrage>::new(config));
+        let entity = app.world_mut().spawn(Damage(100.0)).id();
+
+        apply(&mut app, entity, ValueModifier::Percent(50.0));
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Enrage(ValueModifier::Percent(30.0)));
+        app.update();
+        assert_eq!(app.world().get::<Damage>(entity).unwrap().0, 180.0);
+    }
+
+    #[test]
+    fn resistance_keeps_the_minimum_magnitude_in_the_sum() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Damage, Weaken>::new(
+            StatusEffectApplication::linear().additive_percents(),
+        ));
+        let entity = app
+            .world_mut()
+            .spawn((Damage(100.0), crate::Resistance::<Damage>::new(0.9)))
+            .id();
+        let weaken = |app: &mut App, modifier| {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Weaken(modifier));
+            app.update();
+            app.world().get::<Damage>(entity).unwrap().0
+        };
+
+        // -20% resisted to -2%, floored at -5%
+        assert_eq!(weaken(&mut app, ValueModifier::Percent(-20.0)), 95.0);
+        // -100% resisted to -10%, above the floor, added to the sum
+        assert_eq!(weaken(&mut app, ValueModifier::Percent(-100.0)), 85.0);
+        let sum = app.world().get::<PercentSum<Damage>>(entity).unwrap().sum();
+        assert!((sum + 15.0).abs() < 1e-3);
+    }
+
+    fn slowed_twice(mode: PercentCombineMode) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Damage, DamageEffect>::new(
+            StatusEffectApplication::linear()
+                .tracked()
+                .combine_reductions(mode),
+        ));
+        let entity = app.world_mut().spawn(Damage(100.0)).id();
+        apply(&mut app, entity, ValueModifier::Percent(-50.0));
+        apply(&mut app, entity, ValueModifier::Percent(-50.0));
+        (app, entity)
+    }
+
+    #[test]
+    fn multiplicative_reductions_never_reach_zero() {
+        let (app, entity) = slowed_twice(PercentCombineMode::Multiplicative);
+        assert_eq!(app.world().get::<Damage>(entity).unwrap().0, 25.0);
+    }
+
+    #[test]
+    fn additive_reductions_sum_to_zero() {
+        let (mut app, entity) = slowed_twice(PercentCombineMode::Additive);
+        assert_eq!(app.world().get::<Damage>(entity).unwrap().0, 0.0);
+        assert_eq!(
+            app.world().get::<PercentSum<Damage>>(entity).unwrap().sum(),
+            -100.0
+        );
+
+        // Buffs still multiply, and removing a slow rebuilds with the sum
+        assert_eq!(apply(&mut app, entity, ValueModifier::Percent(20.0)), 0.0);
+        app.world_mut()
+            .commands()
+            .trigger(RemoveStatusEffect::<DamageEffect>::new(entity));
+        app.update();
+        let value = app.world().get::<Damage>(entity).unwrap().0;
+        assert!((value - 60.0).abs() < 1e-3);
+
+        // The rebuilt sum is kept: -50% and -25% leave a quarter of 120
+        assert_eq!(
+            app.world().get::<PercentSum<Damage>>(entity).unwrap().sum(),
+            -50.0
+        );
+        let value = apply(&mut app, entity, ValueModifier::Percent(-25.0));
+        assert!((value - 30.0).abs() < 1e-3);
+    }
+}
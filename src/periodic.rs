@@ -0,0 +1,420 @@
+//! Effects applied in ticks over time.
+//!
+//! [`ApplyPeriodicEffect<E>`] splits an effect's modifier across a number of
+//! ticks, one every `interval` seconds. A weight function shapes how much each
+//! tick gets, so damage over time can be even, front-loaded or back-loaded.
+//! Weights are normalized, so the ticks always add up to the full modifier.
+//!
+//! By default every tick is computed against the stat's value at that tick, so
+//! a percent DoT hits harder after a heal. [Snapshot](ApplyPeriodicEffect::snapshot)
+//! effects instead turn their modifier into a flat delta against the value at
+//! cast time and keep splitting that exact delta.
+
+use bevy::prelude::*;
+
+use crate::{
+    ApplyStatusEffect, MutableComponent, StatusEffectApplication, StatusEffectApplicator,
+    ValueModifier,
+};
+
+/// Weight of tick `tick` (zero-based) out of `ticks`.
+///
+/// Only the ratios matter; weights are normalized before use.
+pub type TickWeightFn = fn(tick: u32, ticks: u32) -> f32;
+
+/// Common tick weight curves.
+pub mod weights {
+    /// Every tick gets the same share.
+    #[must_use]
+    pub fn even(_tick: u32, _ticks: u32) -> f32 {
+        1.0
+    }
+
+    /// Early ticks get more, falling off linearly (4, 3, 2, 1 for four ticks).
+    #[must_use]
+    pub fn front_loaded(tick: u32, ticks: u32) -> f32 {
+        (ticks - tick) as f32
+    }
+
+    /// Late ticks get more, rising linearly (1, 2, 3, 4 for four ticks).
+    #[must_use]
+    pub fn back_loaded(tick: u32, _ticks: u32) -> f32 {
+        (tick + 1) as f32
+    }
+}
+
+/// Normalizes the weights of `ticks` ticks so they sum to one.
+///
+/// Negative weights count as zero. Falls back to even weights if none are
+/// positive.
+#[must_use]
+pub fn tick_fractions(ticks: u32, weight: TickWeightFn) -> Vec<f32> {
+    let raw: Vec<f32> = (0..ticks)
+        .map(|tick| weight(tick, ticks).max(0.0))
+        .collect();
+    let total: f32 = raw.iter().sum();
+    if total <= crate::EPSILON {
+        if ticks > 0 {
+            log_warn!("Tick weights sum to zero; splitting evenly");
+        }
+        return vec![1.0 / ticks as f32; ticks as usize];
+    }
+    raw.into_iter().map(|w| w / total).collect()
+}
+
+/// Applies `effect` to an entity over `ticks` ticks, one every `interval` seconds.
+///
+/// Each tick applies the effect [scaled](StatusEffectApplicator::scaled_by) by
+/// its share of the weights, so effects must implement
+/// [`from_modifier`](StatusEffectApplicator::from_modifier). Flat totals are
+/// split exactly; percents are split in percentage points. The effect's
+/// plugin must be built with
+/// [`periodic`](crate::StatusEffectPlugin::periodic).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Poison(ValueModifier);
+///
+/// fn envenom(mut commands: Commands, entity: Entity) {
+///     // 40 damage over 4 seconds, most of it up front
+///     commands.trigger(
+///         ApplyPeriodicEffect::new(entity, Poison(ValueModifier::Val(-40.0)), 4, 1.0)
+///             .with_weights(weights::front_loaded),
+///     );
+/// }
+/// ```
+#[derive(EntityEvent, Clone)]
+pub struct ApplyPeriodicEffect<E: Event + Clone> {
+    /// The target entity
+    pub entity: Entity,
+    /// The full effect, split across the ticks
+    pub effect: E,
+    /// Number of ticks
+    pub ticks: u32,
+    /// Seconds between ticks
+    pub interval: f32,
+    /// Share of the effect each tick gets
+    pub weights: TickWeightFn,
+    /// Compute the delta once at cast time instead of every tick
+    pub snapshot: bool,
+}
+
+impl<E: Event + Clone> ApplyPeriodicEffect<E> {
+    /// Splits `effect` evenly over `ticks` ticks, `interval` seconds apart.
+    #[must_use]
+    pub fn new(entity: Entity, effect: E, ticks: u32, interval: f32) -> Self {
+        Self {
+            entity,
+            effect,
+            ticks,
+            interval,
+            weights: weights::even,
+            snapshot: false,
+        }
+    }
+
+    /// Shapes the per-tick shares with a weight function.
+    #[must_use]
+    pub fn with_weights(mut self, weights: TickWeightFn) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Computes the flat delta against the stat's value when the effect
+    /// starts, and splits that delta across the ticks however the stat
+    /// changes afterwards.
+    ///
+    /// Needs a readable stat value (see
+    /// [`with_stat_value`](crate::StatusEffectApplication::with_stat_value));
+    /// otherwise the ticks are computed dynamically.
+    #[must_use]
+    pub fn snapshot(mut self) -> Self {
+        self.snapshot = true;
+        self
+    }
+}
+
+/// A running periodic effect.
+#[derive(Clone)]
+pub struct PeriodicEffect<E: Event + Clone> {
+    /// The full effect
+    pub effect: E,
+    fractions: Vec<f32>,
+    next: usize,
+    timer: Timer,
+}
+
+impl<E: Event + Clone> PeriodicEffect<E> {
+    /// Returns the number of ticks still to apply.
+    #[must_use]
+    pub fn remaining_ticks(&self) -> usize {
+        self.fractions.len() - self.next
+    }
+}
+
+/// Periodic effects of type `E` running on this entity.
+#[derive(Component)]
+pub struct PeriodicEffects<E: Event + Clone> {
+    /// Running effects in start order
+    pub effects: Vec<PeriodicEffect<E>>,
+}
+
+fn start_periodic_effect<E: Event + Clone>(
+    on: On<ApplyPeriodicEffect<E>>,
+    scale: Res<PeriodicScale<E>>,
+    mut commands: Commands,
+) {
+    let Ok(mut entity_commands) = commands.get_entity(on.entity) else {
+        return;
+    };
+    let snapshot = on.snapshot.then_some(scale.snapshot);
+    let mut periodic = PeriodicEffect {
+        effect: on.effect.clone(),
+        fractions: tick_fractions(on.ticks, on.weights),
+        next: 0,
+        timer: Timer::from_seconds(on.interval, TimerMode::Repeating),
+    };
+    entity_commands.queue(move |mut entity: EntityWorldMut| {
+        if let Some(snapshot) = snapshot
+            && let Some(effect) = snapshot(entity.world(), entity.id(), &periodic.effect)
+        {
+            periodic.effect = effect;
+        }
+        match entity.get_mut::<PeriodicEffects<E>>() {
+            Some(mut running) => running.effects.push(periodic),
+            None => {
+                entity.insert(PeriodicEffects {
+                    effects: vec![periodic],
+                });
+            }
+        }
+    });
+}
+
+/// Applies due ticks and drops finished periodic effects.
+fn tick_periodic_effects<E: Event + Clone>(
+    time: Res<Time>,
+    scale: Res<PeriodicScale<E>>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut PeriodicEffects<E>)>,
+) {
+    for (entity, mut running) in &mut q {
+        for periodic in &mut running.effects {
+            periodic.timer.tick(time.delta());
+            for _ in 0..periodic.timer.times_finished_this_tick() {
+                let Some(&fraction) = periodic.fractions.get(periodic.next) else {
+                    break;
+                };
+                periodic.next += 1;
+                commands.trigger(ApplyStatusEffect {
+                    effect: (scale.scale)(&periodic.effect, fraction),
+                    entity,
+                });
+            }
+        }
+        running
+            .effects
+            .retain(|periodic| periodic.remaining_ticks() > 0);
+        if running.effects.is_empty() {
+            commands.entity(entity).remove::<PeriodicEffects<E>>();
+        }
+    }
+}
+
+/// Returns `effect` as a flat delta against the stat's current value.
+///
+/// `None` if the stat can't be read or the effect can't be rebuilt.
+fn snapshot_effect<C, E>(world: &World, entity: Entity, effect: &E) -> Option<E>
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let config = world.get_resource::<StatusEffectApplication<C>>()?;
+    let current = config.stat_value(world.get::<C>(entity)?)?;
+    E::from_modifier(ValueModifier::Val(
+        effect.modifier().apply(current) - current,
+    ))
+}
+
+/// How to scale `E` for a single tick, and how to snapshot it.
+#[derive(Resource)]
+struct PeriodicScale<E> {
+    scale: fn(&E, f32) -> E,
+    snapshot: SnapshotFn<E>,
+}
+
+type SnapshotFn<E> = fn(&World, Entity, &E) -> Option<E>;
+
+/// Registers periodic application for effect type `E` once per app.
+pub(crate) struct PeriodicPlugin<E> {
+    scale: fn(&E, f32) -> E,
+    snapshot: SnapshotFn<E>,
+}
+
+impl<E: Event + Clone> PeriodicPlugin<E> {
+    pub fn new<C>() -> Self
+    where
+        C: MutableComponent,
+        E: StatusEffectApplicator<C>,
+    {
+        Self {
+            scale: <E as StatusEffectApplicator<C>>::scaled_by,
+            snapshot: snapshot_effect::<C, E>,
+        }
+    }
+}
+
+impl<E: Event + Clone> Plugin for PeriodicPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PeriodicScale {
+            scale: self.scale,
+            snapshot: self.snapshot,
+        });
+        app.add_observer(start_periodic_effect::<E>);
+        app.add_systems(Update, tick_periodic_effects::<E>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    impl StatValue for Health {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Poison(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Poison {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    fn health_per_tick(weights: TickWeightFn) -> Vec<f32> {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Poison>::default().periodic());
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+
+        app.world_mut().commands().trigger(
+            ApplyPeriodicEffect::new(entity, Poison(ValueModifier::Val(-40.0)), 4, 0.25)
+                .with_weights(weights),
+        );
+        let mut health = Vec::new();
+        for _ in 0..6 {
+            app.update();
+            health.push(app.world().get::<Health>(entity).unwrap().0);
+        }
+        assert!(app.world().get::<PeriodicEffects<Poison>>(entity).is_none());
+        health
+    }
+
+    #[test]
+    fn front_loaded_poison_deals_most_on_first_tick() {
+        let health = health_per_tick(weights::front_loaded);
+
+        let damage: Vec<f32> = std::iter::once(100.0)
+            .chain(health.iter().copied())
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|pair| pair[0] - pair[1])
+            .filter(|&damage| damage > 0.0)
+            .collect();
+        assert_eq!(damage.len(), 4);
+        assert!(damage.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!((damage[0] - 16.0).abs() < 0.001);
+
+        // Ticks add up to the full modifier
+        assert!((health.last().unwrap() - 60.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn tick_fractions_sum_to_one() {
+        for weights in [weights::even, weights::front_loaded, weights::back_loaded] {
+            let total: f32 = tick_fractions(5, weights).iter().sum();
+            assert!((total - 1.0).abs() < 0.0001);
+        }
+        assert_eq!(tick_fractions(2, |_, _| 0.0), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn snapshot_dot_keeps_cast_time_delta() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(
+            StatusEffectPlugin::<Health, Poison>::new(
+                StatusEffectApplication::linear().with_stat_value(),
+            )
+            .periodic(),
+        );
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let dynamic = app.world_mut().spawn(Health(100.0)).id();
+        let snapshot = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+
+        let poison = Poison(ValueModifier::Percent(-40.0));
+        app.world_mut()
+            .commands()
+            .trigger(ApplyPeriodicEffect::new(dynamic, poison, 4, 0.25));
+        app.world_mut()
+            .commands()
+            .trigger(ApplyPeriodicEffect::new(snapshot, poison, 4, 0.25).snapshot());
+        let health = |app: &App, entity| app.world().get::<Health>(entity).unwrap().0;
+        let next_tick = |app: &mut App| {
+            let before = health(app, dynamic);
+            for _ in 0..4 {
+                app.update();
+                if health(app, dynamic) != before {
+                    return;
+                }
+            }
+            panic!("no tick");
+        };
+
+        next_tick(&mut app);
+        assert_eq!(health(&app, dynamic), 90.0);
+        assert_eq!(health(&app, snapshot), 90.0);
+
+        // Healed mid-duration: the dynamic DoT scales up, the snapshot doesn't
+        app.world_mut().get_mut::<Health>(dynamic).unwrap().0 = 200.0;
+        app.world_mut().get_mut::<Health>(snapshot).unwrap().0 = 200.0;
+        next_tick(&mut app);
+        assert_eq!(health(&app, dynamic), 180.0);
+        assert_eq!(health(&app, snapshot), 190.0);
+    }
+}
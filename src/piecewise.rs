@@ -0,0 +1,288 @@
+//! Scaling that changes with the stat's magnitude.
+//!
+//! A [`PiecewiseScaling`] splits the value range at breakpoints and gives each
+//! segment its own power, e.g. linear up to 100 and diminishing above. Set it
+//! with [`StatusEffectApplication::piecewise`]; it then replaces the single
+//! configured power for that component.
+//!
+//! An effect that crosses a breakpoint is split there: the part up to the
+//! breakpoint scales at the power of the segment it started in, the rest at
+//! the next segment's power. Results are therefore continuous, both in the
+//! starting value and in the modifier. A value exactly at a breakpoint belongs
+//! to the segment above it.
+
+use bevy::prelude::*;
+
+use crate::{
+    MutableComponent, PipelineContext, StatusEffectApplication, StatusEffectApplicator,
+    ValueModifier, pipeline,
+};
+
+/// Per-segment powers over a stat's value range.
+///
+/// # Example
+///
+/// ```rust
+/// use msg_status_effect::{PiecewiseScaling, ValueModifier, scaling};
+///
+/// // Linear up to 100, square root above
+/// let curve = PiecewiseScaling::new(scaling::LINEAR).above(100.0, scaling::SQRT);
+/// assert_eq!(curve.power_at(100.0), scaling::SQRT);
+///
+/// // 80 + 20 reaches the breakpoint linearly, the last 30 add as sqrt(100^2 + 30^2)
+/// let value = curve.apply(80.0, ValueModifier::Val(50.0));
+/// assert!((value - 104.403).abs() < 0.001);
+/// ```
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct PiecewiseScaling {
+    /// Power below the first breakpoint
+    base: f32,
+    /// `(breakpoint, power)` pairs sorted by breakpoint
+    segments: Vec<(f32, f32)>,
+}
+
+impl PiecewiseScaling {
+    /// Creates a curve with a single segment at `power`.
+    #[must_use]
+    pub fn new(power: f32) -> Self {
+        strict_assert!(
+            power.is_finite() && power > 0.0,
+            "Piecewise scaling power {power} must be positive"
+        );
+        Self {
+            base: power,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Uses `power` for values at or above `breakpoint`, up to the next one.
+    ///
+    /// Adding a breakpoint twice replaces its power.
+    #[must_use]
+    pub fn above(mut self, breakpoint: f32, power: f32) -> Self {
+        strict_assert!(
+            power.is_finite() && power > 0.0,
+            "Piecewise scaling power {power} above {breakpoint} must be positive"
+        );
+        match self
+            .segments
+            .binary_search_by(|(at, _)| at.total_cmp(&breakpoint))
+        {
+            Ok(index) => self.segments[index].1 = power,
+            Err(index) => self.segments.insert(index, (breakpoint, power)),
+        }
+        self
+    }
+
+    /// Returns the breakpoints in ascending order.
+    pub fn breakpoints(&self) -> impl Iterator<Item = f32> + '_ {
+        self.segments.iter().map(|(at, _)| *at)
+    }
+
+    /// Returns the power of the segment containing `value`.
+    ///
+    /// Values exactly at a breakpoint use the segment above it.
+    #[must_use]
+    pub fn power_at(&self, value: f32) -> f32 {
+        self.segments
+            .iter()
+            .rev()
+            .find(|(at, _)| *at <= value)
+            .map_or(self.base, |(_, power)| *power)
+    }
+
+    /// Returns the power of the segment just below `value`, used when
+    /// decreasing from it.
+    fn power_below(&self, value: f32) -> f32 {
+        self.segments
+            .iter()
+            .rev()
+            .find(|(at, _)| *at < value)
+            .map_or(self.base, |(_, power)| *power)
+    }
+
+    /// Applies `modifier` to `current`, splitting it at every breakpoint it
+    /// crosses.
+    ///
+    /// `Negate` and negative values apply in one step at the power of the
+    /// segment containing `current`. `Set` returns its target.
+    #[must_use]
+    pub fn apply(&self, current: f32, modifier: ValueModifier) -> f32 {
+        let rising = match modifier {
+            ValueModifier::Val(v) => v >= 0.0,
+            ValueModifier::Percent(p) => p >= 0.0,
+            ValueModifier::Multiply(factor) => factor >= 1.0,
+            ValueModifier::Set(target) => return target,
+            ValueModifier::Negate => return modifier.apply_scaled(current, self.power_at(current)),
+        };
+        if current < 0.0 {
+            return modifier.apply_scaled(current, self.power_at(current));
+        }
+
+        let mut value = current;
+        let mut modifier = modifier;
+        loop {
+            let (power, limit) = if rising {
+                let limit = self.breakpoints().find(|at| *at > value);
+                (self.power_at(value), limit)
+            } else {
+                let limit = self
+                    .breakpoints()
+                    .filter(|at| *at < value && *at > 0.0)
+                    .last();
+                (self.power_below(value), limit)
+            };
+            let next = modifier.apply_scaled(value, power);
+            match limit {
+                Some(at) if (rising && next > at) || (!rising && next < at) => {
+                    modifier = remainder(modifier, value, at, power);
+                    value = at;
+                }
+                _ => return next,
+            }
+        }
+    }
+}
+
+/// Returns what is left of `modifier` after it moved a value from `from` to
+/// `to` at `power`.
+fn remainder(modifier: ValueModifier, from: f32, to: f32, power: f32) -> ValueModifier {
+    let inv_p = 1.0 / power;
+    match modifier {
+        ValueModifier::Val(v) => {
+            let used = (to.powf(inv_p) - from.powf(inv_p)).abs();
+            let left = (v.abs().powf(inv_p) - used).max(0.0).powf(power);
+            ValueModifier::Val(left.copysign(v))
+        }
+        ValueModifier::Percent(p) => {
+            let multiplier = (1.0 + p / 100.0).max(0.0);
+            let used = (to / from).powf(inv_p);
+            ValueModifier::Percent((multiplier / used - 1.0) * 100.0)
+        }
+        ValueModifier::Multiply(factor) => {
+            let used = (to / from).powf(inv_p);
+            ValueModifier::Multiply(factor.max(0.0) / used)
+        }
+        ValueModifier::Set(_) | ValueModifier::Negate => modifier,
+    }
+}
+
+/// Applies `effect` to the component along the configured piecewise or
+/// custom curve.
+///
+/// Writes through the component's stat value instead of the effect's
+/// [`apply`](StatusEffectApplicator::apply). Returns the effect as applied,
+/// after resistance, and the overflow cut off by clamping.
+pub(crate) fn apply_effect<C, E>(
+    effect: &E,
+    component: &mut C,
+    config: &StatusEffectApplication<C>,
+    ctx: &PipelineContext,
+) -> (E, f32)
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let effect = pipeline::resist(effect, ctx);
+    let Some(value) = config
+        .stat_value(component)
+        .and_then(|current| config.apply_curve(current, effect.modifier()))
+    else {
+        return (effect, 0.0);
+    };
+    config.set_stat_value(component, ctx.finish(value));
+    (effect, ctx.overflow(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn curve() -> PiecewiseScaling {
+        PiecewiseScaling::new(scaling::LINEAR).above(100.0, scaling::SQRT)
+    }
+
+    #[test]
+    fn splits_modifiers_at_breakpoints() {
+        let curve = curve();
+        // Below the breakpoint, linear
+        assert_eq!(curve.apply(50.0, ValueModifier::Val(30.0)), 80.0);
+        // At the breakpoint, the upper segment
+        let at = curve.apply(100.0, ValueModifier::Val(30.0));
+        assert!((at - 10900f32.sqrt()).abs() < 0.001);
+        // 80 * 1.25 reaches 100 linearly, the remaining 1.2x scales as sqrt
+        let percent = curve.apply(80.0, ValueModifier::Percent(50.0));
+        assert!((percent - 100.0 * 1.2f32.sqrt()).abs() < 0.001);
+        // Decreasing splits the same way: 50 = sqrt(30^2 + 40^2), the first 30
+        // bring it down to 100 and the other 40 subtract linearly
+        let down = curve.apply(at, ValueModifier::Val(-50.0));
+        assert!((down - 60.0).abs() < 0.001);
+
+        // Continuous on either side of the breakpoint
+        let below = curve.apply(99.999, ValueModifier::Val(30.0));
+        assert!((below - at).abs() < 0.01);
+    }
+
+    #[test]
+    fn effects_crossing_a_breakpoint_use_each_segment() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::linear()
+                .tracked()
+                .piecewise(curve()),
+        ));
+        let entity = app.world_mut().spawn(Speed(80.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Haste(ValueModifier::Val(50.0)));
+        app.update();
+        // 20 linear points reach 100, the other 30 add as sqrt(100^2 + 30^2)
+        let speed = app.world().get::<Speed>(entity).unwrap().0;
+        assert!((speed - 10900f32.sqrt()).abs() < 0.001);
+
+        // sqrt(10900 - 30^2) lands exactly on the breakpoint
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Haste(ValueModifier::Val(-30.0)));
+        app.update();
+        let speed = app.world().get::<Speed>(entity).unwrap().0;
+        assert!((speed - 100.0).abs() < 0.001);
+
+        // Removal replays along the same curve
+        app.world_mut()
+            .commands()
+            .remove_effect_everywhere::<Haste>();
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 80.0);
+    }
+}
@@ -0,0 +1,475 @@
+//! The application pipeline.
+//!
+//! Every application runs the same steps, in this order:
+//!
+//! 1. **Resistance**: harmful modifiers are scaled down by the target's
+//!    [`Resistance<C>`], but no further than the effect's
+//!    [floor](crate::StatusEffectApplicator::min_magnitude_after_resistance).
+//!    The effect is rebuilt with
+//!    [`from_modifier`](crate::StatusEffectApplicator::from_modifier), so
+//!    effects without it aren't resisted.
+//! 2. **Scaled apply**: the effect applies at the configured power.
+//! 3. **Clamp**: the result is clamped to the component's
+//!    [bounds](crate::StatusEffectApplication::clamped).
+//! 4. **Round**: the result is [rounded](crate::StatusEffectApplication::rounded).
+//!
+//! Order matters: resisting the modifier before a non-linear apply differs
+//! from resisting the change afterwards, and rounding a clamped value can
+//! land outside the bounds. [`apply_pipeline`] runs the steps on a bare value;
+//! the observer runs them on the component.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{MutableComponent, StatusEffectApplication, StatusEffectApplicator, ValueModifier};
+
+/// Fraction of harmful effects on component `C` this entity ignores.
+///
+/// `0.25` scales a `Val(-20)` to `Val(-15)` and a `Percent(-20)` to
+/// `Percent(-15)`. Beneficial effects are unaffected. Values are clamped to
+/// `[0, 1]`.
+///
+/// Resisted effects are rebuilt with
+/// [`from_modifier`](crate::StatusEffectApplicator::from_modifier). Effects
+/// that don't implement it apply at full strength, with a warning the first
+/// time.
+#[derive(Component)]
+pub struct Resistance<C: MutableComponent> {
+    /// Fraction of harmful modifiers ignored
+    pub fraction: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> Resistance<C> {
+    /// Creates a resistance ignoring `fraction` of harmful modifiers.
+    #[must_use]
+    pub fn new(fraction: f32) -> Self {
+        Self {
+            fraction,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Settings for one run of the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipelineContext {
+    /// Power for the scaled apply
+    pub power: f32,
+    /// Fraction of harmful modifiers ignored
+    pub resistance: f32,
+    /// Inclusive `(min, max)` bounds for the result
+    pub bounds: Option<(f32, f32)>,
+    /// Round the result to the nearest integer
+    pub round: bool,
+}
+
+impl PipelineContext {
+    /// Creates a context with only a power: no resistance, bounds or rounding.
+    #[must_use]
+    pub fn new(power: f32) -> Self {
+        Self {
+            power,
+            resistance: 0.0,
+            bounds: None,
+            round: false,
+        }
+    }
+
+    /// Returns the factor resistance scales `modifier` by.
+    #[must_use]
+    pub fn resistance_factor(&self, modifier: ValueModifier) -> f32 {
+        let harmful = modifier.signed_points() < 0.0;
+        if harmful {
+            1.0 - self.resistance.clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the factor resistance scales `modifier` by, keeping a harmful
+    /// modifier at least `floor` strong.
+    ///
+    /// The floor never makes a modifier stronger than it was: `Val(-3)` with a
+    /// floor of 5 stays at most `Val(-3)`.
+    #[must_use]
+    pub fn floored_resistance_factor(&self, modifier: ValueModifier, floor: f32) -> f32 {
+        let factor = self.resistance_factor(modifier);
+        let magnitude = modifier.signed_points().abs();
+        if factor == 1.0 || magnitude == 0.0 {
+            return factor;
+        }
+        factor.max((floor.abs() / magnitude).min(1.0))
+    }
+
+    /// Clamps, then rounds, an applied value.
+    #[must_use]
+    pub fn finish(&self, value: f32) -> f32 {
+        let value = self.clamp(value);
+        if self.round { value.round() } else { value }
+    }
+
+    /// Clamps a value to the bounds, if any.
+    #[must_use]
+    pub fn clamp(&self, value: f32) -> f32 {
+        match self.bounds {
+            Some((min, max)) => value.clamp(min, max),
+            None => value,
+        }
+    }
+
+    /// Returns how far clamping cuts `value`: positive above the maximum,
+    /// negative below the minimum, zero within bounds.
+    #[must_use]
+    pub fn overflow(&self, value: f32) -> f32 {
+        value - self.clamp(value)
+    }
+}
+
+/// Runs the full pipeline on a bare value: resistance, scaled apply, clamp, round.
+///
+/// # Example
+///
+/// ```rust
+/// use msg_status_effect::{PipelineContext, ValueModifier, apply_pipeline};
+///
+/// let ctx = PipelineContext {
+///     resistance: 0.5,
+///     round: true,
+///     ..PipelineContext::new(1.0)
+/// };
+/// // -15 after resistance, then rounded
+/// assert_eq!(apply_pipeline(40.4, ValueModifier::Val(-30.0), &ctx), 25.0);
+/// ```
+#[must_use]
+pub fn apply_pipeline(current: f32, modifier: ValueModifier, ctx: &PipelineContext) -> f32 {
+    let modifier = modifier.scaled_by(ctx.resistance_factor(modifier));
+    ctx.finish(modifier.apply_scaled(current, ctx.power))
+}
+
+/// Scales `effect` down by the target's resistance.
+///
+/// Goes through [`scaled_by`](StatusEffectApplicator::scaled_by), so effects
+/// without [`from_modifier`](StatusEffectApplicator::from_modifier) come back
+/// unresisted; that's reported once rather than passing silently.
+pub(crate) fn resist<C, E>(effect: &E, ctx: &PipelineContext) -> E
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let factor = match effect.min_magnitude_after_resistance() {
+        Some(floor) => ctx.floored_resistance_factor(effect.modifier(), floor),
+        None => ctx.resistance_factor(effect.modifier()),
+    };
+    if factor == 1.0 {
+        return effect.clone();
+    }
+    let resisted = effect.scaled_by(factor);
+    let ignored = resisted.modifier() == effect.modifier();
+    strict_assert!(
+        !ignored,
+        "{} ignores Resistance<{}>: it needs from_modifier to be scaled",
+        std::any::type_name::<E>(),
+        std::any::type_name::<C>()
+    );
+    if ignored {
+        log_warn_once!(
+            "{} ignores Resistance<{}>: it needs from_modifier to be scaled",
+            std::any::type_name::<E>(),
+            std::any::type_name::<C>()
+        );
+    }
+    resisted
+}
+
+/// Runs the pipeline for `effect` on a component.
+///
+/// Returns the effect as applied, after resistance, and the
+/// [overflow](PipelineContext::overflow) cut off by clamping. Clamping and
+/// rounding need the component's stat value to be readable and are skipped
+/// otherwise.
+pub(crate) fn apply_effect<C, E>(
+    effect: &E,
+    component: &mut C,
+    config: &StatusEffectApplication<C>,
+    ctx: &PipelineContext,
+    source: Option<Entity>,
+) -> (E, f32)
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let effect = resist(effect, ctx);
+    effect.apply_from(component, ctx.power, source);
+    let Some(value) = config.stat_value(component) else {
+        return (effect, 0.0);
+    };
+    let finished = ctx.finish(value);
+    if finished != value {
+        config.set_stat_value(component, finished);
+    }
+    (effect, ctx.overflow(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn resistance_applies_before_scaled_apply() {
+        let ctx = PipelineContext {
+            resistance: 0.5,
+            round: true,
+            ..PipelineContext::new(scaling::SQRT)
+        };
+        // sqrt(40^2 - 15^2) = 37.08
+        assert_eq!(apply_pipeline(40.0, ValueModifier::Val(-30.0), &ctx), 37.0);
+
+        // Halving the change afterwards would give 40 - (40 - sqrt(700)) / 2 = 33.23
+        let full = ValueModifier::Val(-30.0).apply_scaled(40.0, scaling::SQRT);
+        assert_eq!((40.0 - (40.0 - full) * 0.5f32).round(), 33.0);
+    }
+
+    #[test]
+    fn clamp_applies_before_round() {
+        let ctx = PipelineContext {
+            bounds: Some((0.0, 10.6)),
+            round: true,
+            ..PipelineContext::new(scaling::LINEAR)
+        };
+        // Clamped to 10.6, then rounded to 11; rounding first would clamp to 10.6
+        assert_eq!(apply_pipeline(8.0, ValueModifier::Val(3.0), &ctx), 11.0);
+
+        // Beneficial effects aren't resisted
+        let resisted = PipelineContext {
+            resistance: 0.5,
+            ..PipelineContext::new(scaling::LINEAR)
+        };
+        assert_eq!(
+            apply_pipeline(8.0, ValueModifier::Val(3.0), &resisted),
+            11.0
+        );
+    }
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    impl StatValue for Armor {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct ArmorEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for ArmorEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    #[test]
+    fn observer_runs_the_pipeline() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::new(
+            StatusEffectApplication::linear()
+                .clamped(0.0, 50.0)
+                .rounded(),
+        ));
+        let entity = app
+            .world_mut()
+            .spawn((Armor(20.0), Resistance::<Armor>::new(0.25)))
+            .id();
+
+        // -10.2 resisted to -7.65, then rounded
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Val(-10.2)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 12.0);
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Val(100.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 50.0);
+    }
+
+    #[test]
+    fn one_sided_bounds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::new(
+            StatusEffectApplication::linear().with_bounds(None, Some(200.0)),
+        ));
+        let entity = app.world_mut().spawn(Armor(150.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Val(1000.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 200.0);
+        // Unbounded below
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Set(-50.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, -50.0);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::new(
+            StatusEffectApplication::linear().with_bounds(Some(0.0), None),
+        ));
+        let entity = app.world_mut().spawn(Armor(150.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Val(1000.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 1150.0);
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Set(-50.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 0.0);
+    }
+
+    /// Slow that keeps at least 5% through any resistance
+    #[derive(Event, Clone, Copy)]
+    struct Slow(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for Slow {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+
+        fn min_magnitude_after_resistance(&self) -> Option<f32> {
+            Some(5.0)
+        }
+    }
+
+    #[test]
+    fn resistance_keeps_the_minimum_magnitude() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, Slow>::default());
+        let entity = app
+            .world_mut()
+            .spawn((Armor(100.0), Resistance::<Armor>::new(0.9)))
+            .id();
+        let slow = |app: &mut App, modifier| {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Slow(modifier));
+            app.update();
+            app.world().get::<Armor>(entity).unwrap().0
+        };
+
+        // -20% resisted to -2%, floored at -5%
+        assert_eq!(slow(&mut app, ValueModifier::Percent(-20.0)), 95.0);
+        // -100% resisted to -10%, above the floor
+        assert_eq!(slow(&mut app, ValueModifier::Percent(-100.0)), 85.5);
+        // The floor never strengthens a weaker effect
+        assert_eq!(slow(&mut app, ValueModifier::Val(-3.0)), 82.5);
+        // Buffs ignore both resistance and the floor
+        assert_eq!(slow(&mut app, ValueModifier::Val(1.0)), 83.5);
+    }
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    impl StatValue for Health {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Shield(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Heal(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Heal {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn overflow(&self, excess: f32, commands: &mut Commands, entity: Entity) {
+            if excess > 0.0 {
+                commands.apply_effect(entity, ShieldEffect(ValueModifier::Val(excess)));
+            }
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct ShieldEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Shield> for ShieldEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Shield, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn overheal_overflows_into_shield() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Heal>::new(
+            StatusEffectApplication::linear().clamped(0.0, 100.0),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Shield, ShieldEffect>::default());
+        let entity = app.world_mut().spawn(Health(90.0)).id();
+
+        // Within bounds: nothing overflows
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Heal(ValueModifier::Val(5.0)));
+        app.update();
+        assert!(app.world().get::<Shield>(entity).is_none());
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Heal(ValueModifier::Val(25.0)));
+        app.update();
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 100.0);
+        assert_eq!(app.world().get::<Shield>(entity).unwrap().0, 20.0);
+    }
+}
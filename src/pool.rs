@@ -0,0 +1,373 @@
+//! Prebuilt applicator for current/max components.
+//!
+//! Health, mana and similar pools have a current value bounded by a maximum.
+//! [`ValueModifier::Set`] needs its target up front, so "heal to full" would
+//! need to know the maximum. Implement [`CurrentMax`] on the component and use
+//! [`MaxHealthApplicator`] instead: it clamps every change to `[0, max]` and can
+//! set the current value straight to the maximum.
+//!
+//! [`CurrentMaxApplicator`] also changes the maximum, keeping the pool's ratio,
+//! with its own modifier and power.
+
+use bevy::prelude::*;
+
+use crate::{MutableComponent, StatusEffectApplicator, ValueModifier};
+
+/// A component with a current value bounded by a maximum.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// impl CurrentMax for Health {
+///     fn current(&self) -> f32 { self.current }
+///     fn max(&self) -> f32 { self.max }
+///     fn set_current(&mut self, value: f32) { self.current = value; }
+/// }
+/// ```
+pub trait CurrentMax {
+    /// Returns the current value.
+    fn current(&self) -> f32;
+
+    /// Returns the maximum value.
+    fn max(&self) -> f32;
+
+    /// Overwrites the current value.
+    fn set_current(&mut self, value: f32);
+
+    /// Overwrites the maximum.
+    ///
+    /// Needed for [`CurrentMaxApplicator`] to change the maximum. The default
+    /// leaves it as is and warns.
+    fn set_max(&mut self, value: f32) {
+        log_warn!(
+            "Ignoring new max {value} on {}: CurrentMax::set_max isn't implemented",
+            std::any::type_name::<Self>()
+        );
+    }
+}
+
+/// Changes the current value of a [`CurrentMax`] component, clamped to `[0, max]`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn drink_elixir(mut commands: Commands, entity: Entity) {
+///     commands.apply_effect(entity, MaxHealthApplicator::heal_to_full());
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub enum MaxHealthApplicator {
+    /// Applies the modifier to the current value
+    Modify(ValueModifier),
+    /// Sets the current value to the maximum
+    HealToFull,
+}
+
+impl MaxHealthApplicator {
+    /// Applies `modifier` to the current value.
+    #[must_use]
+    pub fn new(modifier: ValueModifier) -> Self {
+        Self::Modify(modifier)
+    }
+
+    /// Sets the current value to the maximum.
+    #[must_use]
+    pub fn heal_to_full() -> Self {
+        Self::HealToFull
+    }
+}
+
+impl<C: MutableComponent + CurrentMax> StatusEffectApplicator<C> for MaxHealthApplicator {
+    /// Returns the modifier, or a zero modifier for [`HealToFull`](Self::HealToFull),
+    /// whose amount depends on the target.
+    fn modifier(&self) -> ValueModifier {
+        match self {
+            Self::Modify(modifier) => *modifier,
+            Self::HealToFull => ValueModifier::default(),
+        }
+    }
+
+    fn apply(&self, component: &mut C, power: f32) {
+        let max = component.max().max(0.0);
+        let value = match self {
+            Self::Modify(modifier) => modifier.apply_scaled(component.current(), power),
+            Self::HealToFull => max,
+        };
+        component.set_current(value.clamp(0.0, max));
+    }
+
+    fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+        Some(Self::Modify(modifier))
+    }
+
+    fn scaled_by(&self, factor: f32) -> Self {
+        match self {
+            Self::Modify(modifier) => Self::Modify(modifier.scaled_by(factor)),
+            Self::HealToFull => Self::HealToFull,
+        }
+    }
+}
+
+/// Changes the maximum and/or current value of a [`CurrentMax`] component.
+///
+/// - The **max** modifier rescales the current value to keep the ratio, e.g.
+///   50/100 raised to a max of 150 becomes 75/150. It applies first, at
+///   [`max_power`](Self::with_max_power) if set, otherwise at the component's
+///   power.
+/// - The **current** modifier then applies to the current value, clamped to
+///   `[0, max]` unless [overflow](Self::allow_overflow) is allowed.
+///
+/// Neither modifier ever lets the current value exceed the maximum unless
+/// overflow is allowed. Maximums below zero are treated as zero.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn level_up(mut commands: Commands, entity: Entity) {
+///     // +20% max health, keeping the ratio, then heal 10
+///     commands.apply_effect(
+///         entity,
+///         CurrentMaxApplicator::both(ValueModifier::Val(10.0), ValueModifier::Percent(20.0)),
+///     );
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct CurrentMaxApplicator {
+    /// Modifier for the current value
+    pub current: Option<ValueModifier>,
+    /// Modifier for the maximum
+    pub max: Option<ValueModifier>,
+    /// Power for the max modifier, `None` for the component's power
+    pub max_power: Option<f32>,
+    /// Whether the current modifier may push the current value above the maximum
+    pub overflow: bool,
+}
+
+impl CurrentMaxApplicator {
+    /// Changes the current value, clamped to the maximum.
+    #[must_use]
+    pub fn current(modifier: ValueModifier) -> Self {
+        Self {
+            current: Some(modifier),
+            max: None,
+            max_power: None,
+            overflow: false,
+        }
+    }
+
+    /// Changes the maximum, keeping the ratio of current to max.
+    #[must_use]
+    pub fn max(modifier: ValueModifier) -> Self {
+        Self {
+            current: None,
+            max: Some(modifier),
+            max_power: None,
+            overflow: false,
+        }
+    }
+
+    /// Changes the maximum, keeping the ratio, then the current value.
+    #[must_use]
+    pub fn both(current: ValueModifier, max: ValueModifier) -> Self {
+        Self {
+            current: Some(current),
+            max: Some(max),
+            max_power: None,
+            overflow: false,
+        }
+    }
+
+    /// Applies the max modifier at `power` instead of the component's power.
+    #[must_use]
+    pub fn with_max_power(mut self, power: f32) -> Self {
+        self.max_power = Some(power);
+        self
+    }
+
+    /// Lets the current modifier push the current value above the maximum,
+    /// e.g. for overheal shields.
+    #[must_use]
+    pub fn allow_overflow(mut self) -> Self {
+        self.overflow = true;
+        self
+    }
+}
+
+impl<C: MutableComponent + CurrentMax> StatusEffectApplicator<C> for CurrentMaxApplicator {
+    /// Returns the current modifier, or the max modifier if there is none.
+    fn modifier(&self) -> ValueModifier {
+        self.current.or(self.max).unwrap_or_default()
+    }
+
+    fn apply(&self, component: &mut C, power: f32) {
+        let mut current = component.current();
+        let mut max = component.max().max(0.0);
+        if let Some(modifier) = self.max {
+            let old_max = max;
+            max = modifier
+                .apply_scaled(old_max, self.max_power.unwrap_or(power))
+                .max(0.0);
+            if old_max > 0.0 {
+                current *= max / old_max;
+            }
+            component.set_max(max);
+        }
+        if let Some(modifier) = self.current {
+            current = modifier.apply_scaled(current, power);
+        }
+        let current = if self.overflow {
+            current.max(0.0)
+        } else {
+            current.clamp(0.0, max)
+        };
+        component.set_current(current);
+    }
+
+    fn scaled_by(&self, factor: f32) -> Self {
+        Self {
+            current: self.current.map(|modifier| modifier.scaled_by(factor)),
+            max: self.max.map(|modifier| modifier.scaled_by(factor)),
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    impl CurrentMax for Health {
+        fn current(&self) -> f32 {
+            self.current
+        }
+
+        fn max(&self) -> f32 {
+            self.max
+        }
+
+        fn set_current(&mut self, value: f32) {
+            self.current = value;
+        }
+
+        fn set_max(&mut self, value: f32) {
+            self.max = value;
+        }
+    }
+
+    fn apply(current: f32, effect: MaxHealthApplicator) -> f32 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, MaxHealthApplicator>::default());
+        app.insert_resource(DifficultyScale(2.0));
+        let entity = app
+            .world_mut()
+            .spawn(Health {
+                current,
+                max: 100.0,
+            })
+            .id();
+
+        app.world_mut().commands().apply_effect(entity, effect);
+        app.update();
+        app.world().get::<Health>(entity).unwrap().current
+    }
+
+    #[test]
+    fn heal_to_full_sets_current_to_max() {
+        assert_eq!(apply(30.0, MaxHealthApplicator::heal_to_full()), 100.0);
+    }
+
+    #[test]
+    fn overheal_clamps_to_max() {
+        // Doubled by difficulty: +40 on 80
+        let heal = MaxHealthApplicator::new(ValueModifier::Val(20.0));
+        assert_eq!(apply(80.0, heal), 100.0);
+
+        let hit = MaxHealthApplicator::new(ValueModifier::Val(-20.0));
+        assert_eq!(apply(80.0, hit), 40.0);
+    }
+
+    fn apply_pool(current: f32, max: f32, effect: CurrentMaxApplicator) -> (f32, f32) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, CurrentMaxApplicator>::new(
+            StatusEffectApplication::sqrt(),
+        ));
+        let entity = app.world_mut().spawn(Health { current, max }).id();
+
+        app.world_mut().commands().apply_effect(entity, effect);
+        app.update();
+        let health = app.world().get::<Health>(entity).unwrap();
+        (health.current, health.max)
+    }
+
+    fn assert_pool(actual: (f32, f32), expected: (f32, f32)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 0.001 && (actual.1 - expected.1).abs() < 0.001,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn max_target_keeps_the_ratio() {
+        let raise = CurrentMaxApplicator::max(ValueModifier::Percent(50.0)).with_max_power(1.0);
+        assert_pool(apply_pool(50.0, 100.0, raise), (75.0, 150.0));
+
+        let lower = CurrentMaxApplicator::max(ValueModifier::Val(-50.0)).with_max_power(1.0);
+        assert_pool(apply_pool(100.0, 100.0, lower), (50.0, 50.0));
+
+        // At the component's sqrt power: sqrt(60^2 + 80^2)
+        let raise = CurrentMaxApplicator::max(ValueModifier::Val(80.0));
+        assert_pool(apply_pool(30.0, 60.0, raise), (50.0, 100.0));
+    }
+
+    #[test]
+    fn current_target_clamps_to_max() {
+        // sqrt(99^2 + 30^2) is past the max
+        let heal = CurrentMaxApplicator::current(ValueModifier::Val(30.0));
+        assert_pool(apply_pool(99.0, 100.0, heal), (100.0, 100.0));
+        let hit = CurrentMaxApplicator::current(ValueModifier::Set(-10.0));
+        assert_pool(apply_pool(90.0, 100.0, hit), (0.0, 100.0));
+
+        // Overflow only when asked for
+        let overheal = heal.allow_overflow();
+        assert_pool(apply_pool(99.0, 100.0, overheal), (10701f32.sqrt(), 100.0));
+    }
+
+    #[test]
+    fn both_targets_raise_max_before_current() {
+        let effect =
+            CurrentMaxApplicator::both(ValueModifier::Val(30.0), ValueModifier::Percent(50.0))
+                .with_max_power(1.0);
+        // 40/100 -> 60/150, then sqrt(60^2 + 30^2) on the current
+        assert_pool(apply_pool(40.0, 100.0, effect), (4500f32.sqrt(), 150.0));
+
+        // Raising max doesn't let a heal past the new max
+        let effect =
+            CurrentMaxApplicator::both(ValueModifier::Val(100.0), ValueModifier::Percent(20.0))
+                .with_max_power(1.0);
+        assert_pool(apply_pool(100.0, 100.0, effect), (120.0, 120.0));
+    }
+}
@@ -0,0 +1,204 @@
+//! Previews of what an application would do, without applying it.
+
+use bevy::prelude::*;
+
+use crate::{
+    AppliedEffects, DifficultyScale, MutableComponent, StatusEffectApplication,
+    StatusEffectApplicator, ValueModifier, kind_power,
+};
+
+/// Returns how much one more stack of effect `E` with `modifier` would add
+/// to component `C` on `entity`.
+///
+/// Accounts for the component's power (or the effect's
+/// [`kind_powers`](StatusEffectApplicator::kind_powers)) and
+/// [`DifficultyScale`], assuming the effect applies its modifier with
+/// [`apply_scaled`](ValueModifier::apply_scaled). Returns 0 if the entity is
+/// at the effect's [`max_stacks`](StatusEffectApplicator::max_stacks), or if
+/// `C` is missing or its value isn't readable.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Haste(ValueModifier);
+///
+/// impl StatusEffectApplicator<Speed> for Haste {
+///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn apply(&self, component: &mut Speed, power: f32) {
+///         component.0 = self.0.apply_scaled(component.0, power);
+///     }
+/// }
+///
+/// fn tooltip(world: &World, player: Entity) -> String {
+///     let gain = next_stack_value::<Speed, Haste>(world, player, ValueModifier::Percent(30.0));
+///     format!("next Haste stack: {gain:+.0} speed")
+/// }
+/// ```
+#[must_use]
+pub fn next_stack_value<C, E>(world: &World, entity: Entity, modifier: ValueModifier) -> f32
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let Some(config) = world.get_resource::<StatusEffectApplication<C>>() else {
+        return 0.0;
+    };
+    let Some(current) = world
+        .get::<C>(entity)
+        .and_then(|component| config.stat_value(component))
+    else {
+        return 0.0;
+    };
+    if let Some(max) = E::max_stacks() {
+        let stacks = world
+            .get::<AppliedEffects<C>>(entity)
+            .map_or(0, |applied| applied.of_type::<E>().count());
+        if stacks >= max {
+            return 0.0;
+        }
+    }
+
+    let modifier = world
+        .get_resource::<DifficultyScale>()
+        .map_or(modifier, |difficulty| modifier.scaled_by(difficulty.0));
+    let power = E::from_modifier(modifier)
+        .and_then(|effect| kind_power(effect.kind_powers(), modifier))
+        .unwrap_or_else(|| config.power_for(modifier));
+    modifier.apply_scaled(current, power) - current
+}
+
+/// Returns the steady-state value of an aura that adds `percent_per_tick` of
+/// `base` every tick while the accumulated bonus decays.
+///
+/// Each tick the bonus above base first loses `decay_per_tick` of itself,
+/// then gains `base * percent_per_tick / 100`:
+///
+/// `bonus' = bonus * (1 - decay) + base * percent / 100`
+///
+/// The fixed point is `base * (1 + percent / (100 * decay))`. Returns `None`
+/// if the bonus never settles: a decay of zero or less grows without bound,
+/// and a decay of two or more oscillates ever wider.
+///
+/// # Example
+///
+/// ```rust
+/// use msg_status_effect::equilibrium;
+///
+/// // +5% per tick, 10% of the bonus lost per tick: settles at +50%
+/// assert_eq!(equilibrium(100.0, 5.0, 0.1), Some(150.0));
+/// assert_eq!(equilibrium(100.0, 5.0, 0.0), None);
+/// ```
+#[must_use]
+pub fn equilibrium(base: f32, percent_per_tick: f32, decay_per_tick: f32) -> Option<f32> {
+    if decay_per_tick <= 0.0 || decay_per_tick >= 2.0 {
+        return None;
+    }
+    Some(base * (1.0 + percent_per_tick / (100.0 * decay_per_tick)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    /// Capped at three stacks
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn max_stacks() -> Option<usize> {
+            Some(3)
+        }
+    }
+
+    const STACK: ValueModifier = ValueModifier::Percent(30.0);
+
+    #[test]
+    fn next_stack_previews_marginal_gain_until_cap() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        let mut gains = Vec::new();
+        for _ in 0..3 {
+            let before = app.world().get::<Speed>(entity).unwrap().0;
+            let preview = next_stack_value::<Speed, Haste>(app.world(), entity, STACK);
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Haste(STACK));
+            app.update();
+            let gain = app.world().get::<Speed>(entity).unwrap().0 - before;
+            assert!((preview - gain).abs() < 0.001);
+            gains.push(gain);
+        }
+
+        // The third stack adds less under sqrt than it would linearly
+        let before_third = 100.0 + gains[0] + gains[1];
+        assert!(gains[2] < STACK.apply(before_third) - before_third);
+
+        // At the cap: nothing to gain, and further stacks are dropped
+        assert_eq!(
+            next_stack_value::<Speed, Haste>(app.world(), entity, STACK),
+            0.0
+        );
+        let capped = app.world().get::<Speed>(entity).unwrap().0;
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Haste(STACK));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, capped);
+    }
+
+    #[test]
+    fn equilibrium_matches_iterated_ticks() {
+        let (base, percent, decay) = (80.0, 4.0, 0.2);
+        let mut bonus = 0.0f32;
+        for _ in 0..200 {
+            bonus = bonus * (1.0 - decay) + base * percent / 100.0;
+        }
+        let settled = equilibrium(base, percent, decay).unwrap();
+        assert!((settled - (base + bonus)).abs() < 1e-3);
+        assert!((settled - 96.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn equilibrium_without_decay_diverges() {
+        assert_eq!(equilibrium(100.0, 5.0, 0.0), None);
+        assert_eq!(equilibrium(100.0, 5.0, -0.1), None);
+        assert_eq!(equilibrium(100.0, 5.0, 2.0), None);
+        // Nothing added: settles at base
+        assert_eq!(equilibrium(100.0, 0.0, 0.5), Some(100.0));
+    }
+}
@@ -0,0 +1,717 @@
+//! Deferred application queue.
+//!
+//! Components configured with [`StatusEffectApplication::deferred`](crate::StatusEffectApplication::deferred)
+//! don't apply effects as soon as they are triggered. Instead, the applications
+//! are queued and drained once per frame, ordered so that effects declaring
+//! [`StatusEffectApplicator::depends_on`](crate::StatusEffectApplicator::depends_on)
+//! run after the effect types they depend on.
+//!
+//! Components configured with [`StatusEffectApplication::coalesced`](crate::StatusEffectApplication::coalesced)
+//! additionally merge identical applications before the drain.
+//!
+//! Applications drain in [`PostUpdate`] by default. Components configured with
+//! [`StatusEffectApplication::applied_in`](crate::StatusEffectApplication::applied_in)
+//! drain in the chosen schedule instead, in the [`StatusEffectDrain`] set.
+
+use std::any::TypeId;
+use std::collections::VecDeque;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+
+use crate::{ApplyStatusEffect, MutableComponent, StatusEffectApplicator, ValueModifier};
+
+type ApplyFn = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// A single queued application, erased over its effect type.
+struct QueuedEffect {
+    schedule: InternedScheduleLabel,
+    effect_type: TypeId,
+    effect_name: &'static str,
+    entity: Entity,
+    modifier: ValueModifier,
+    depends_on: Vec<TypeId>,
+    coalesce: Option<Coalesce>,
+    apply: ApplyFn,
+}
+
+/// An application waiting in the [`StatusEffectQueue`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PendingEffect {
+    /// Type name of the effect
+    pub effect: &'static str,
+    /// Modifier the effect was triggered with, merged if coalesced
+    pub modifier: ValueModifier,
+}
+
+impl PendingEffect {
+    /// Returns true if this is an application of effect type `E`.
+    #[must_use]
+    pub fn is<E>(&self) -> bool {
+        self.effect == std::any::type_name::<E>()
+    }
+}
+
+/// What's needed to merge a queued application with identical ones.
+pub(crate) struct Coalesce {
+    entity: Entity,
+    modifier: ValueModifier,
+    rebuild: fn(Entity, ValueModifier) -> Option<ApplyFn>,
+}
+
+impl Coalesce {
+    /// Coalescing info for effect type `E` targeting component `C`.
+    pub fn of<C, E>(entity: Entity, modifier: ValueModifier) -> Self
+    where
+        C: MutableComponent,
+        E: StatusEffectApplicator<C>,
+    {
+        Self {
+            entity,
+            modifier,
+            rebuild: rebuild_application::<C, E>,
+        }
+    }
+
+    fn key(&self, effect_type: TypeId) -> (TypeId, Entity, bool) {
+        (effect_type, self.entity, self.modifier.is_flat())
+    }
+}
+
+fn rebuild_application<C, E>(entity: Entity, modifier: ValueModifier) -> Option<ApplyFn>
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let effect = E::from_modifier(modifier)?;
+    Some(Box::new(move |world: &mut World| {
+        world.trigger(ApplyStatusEffect { effect, entity });
+    }))
+}
+
+/// Merges modifiers of the same kind: flats add, percent multipliers and
+/// factors multiply.
+fn merge_modifiers(modifiers: impl Iterator<Item = ValueModifier>) -> ValueModifier {
+    let mut flat = None;
+    let mut multiplier = 1.0;
+    for modifier in modifiers {
+        match modifier {
+            ValueModifier::Val(v) => *flat.get_or_insert(0.0) += v,
+            ValueModifier::Percent(p) => multiplier *= (1.0 + p / 100.0).max(0.0),
+            ValueModifier::Multiply(factor) => multiplier *= factor.max(0.0),
+            // Sign flips and overrides are never coalesced
+            ValueModifier::Set(_) | ValueModifier::Negate => {}
+        }
+    }
+    match flat {
+        Some(v) => ValueModifier::Val(v),
+        None => ValueModifier::Percent((multiplier - 1.0) * 100.0),
+    }
+}
+
+/// Merges queued applications sharing effect type, entity and modifier kind.
+///
+/// Each merged group takes the place of its first entry. Groups whose effect
+/// can't be rebuilt from the merged modifier are left as they are.
+fn coalesce(pending: Vec<QueuedEffect>) -> Vec<QueuedEffect> {
+    let mut groups: HashMap<(TypeId, Entity, bool), Vec<usize>> = HashMap::default();
+    for (index, entry) in pending.iter().enumerate() {
+        if let Some(coalesce) = &entry.coalesce {
+            groups
+                .entry(coalesce.key(entry.effect_type))
+                .or_default()
+                .push(index);
+        }
+    }
+
+    let mut slots: Vec<_> = pending.into_iter().map(Some).collect();
+    let mut merged = Vec::with_capacity(slots.len());
+    for index in 0..slots.len() {
+        let Some(entry) = slots[index].take() else {
+            continue;
+        };
+        let group = entry
+            .coalesce
+            .as_ref()
+            .and_then(|coalesce| groups.get(&coalesce.key(entry.effect_type)))
+            .filter(|group| group.len() > 1);
+        let Some(group) = group else {
+            merged.push(entry);
+            continue;
+        };
+
+        let modifier = merge_modifiers(group.iter().map(|&member| {
+            let member = if member == index {
+                &entry
+            } else {
+                slots[member]
+                    .as_ref()
+                    .expect("group members are visited once")
+            };
+            member
+                .coalesce
+                .as_ref()
+                .expect("grouped entries coalesce")
+                .modifier
+        }));
+        let coalesce = entry.coalesce.as_ref().expect("grouped entries coalesce");
+        match (coalesce.rebuild)(coalesce.entity, modifier) {
+            Some(apply) => {
+                for &member in &group[1..] {
+                    slots[member] = None;
+                }
+                merged.push(QueuedEffect {
+                    modifier,
+                    coalesce: None,
+                    apply,
+                    ..entry
+                });
+            }
+            None => merged.push(entry),
+        }
+    }
+    merged
+}
+
+/// Pending applications for components using deferred application.
+///
+/// Filled by the status effect observers and drained in [`PostUpdate`], or
+/// in the schedule chosen with
+/// [`applied_in`](crate::StatusEffectApplication::applied_in).
+#[derive(Resource, Default)]
+pub struct StatusEffectQueue {
+    pending: Vec<QueuedEffect>,
+    draining: bool,
+}
+
+impl StatusEffectQueue {
+    /// Returns the number of applications waiting for the next drain.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns true if no applications are waiting.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the applications waiting for `entity`, in trigger order.
+    ///
+    /// Identical applications on coalesced components show up separately
+    /// until the drain merges them.
+    #[must_use]
+    pub fn pending_effects(&self, entity: Entity) -> Vec<PendingEffect> {
+        self.pending
+            .iter()
+            .filter(|entry| entry.entity == entity)
+            .map(|entry| PendingEffect {
+                effect: entry.effect_name,
+                modifier: entry.modifier,
+            })
+            .collect()
+    }
+
+    /// Returns true while the queue is being drained.
+    ///
+    /// Applications triggered during the drain are applied immediately.
+    #[must_use]
+    pub fn is_draining(&self) -> bool {
+        self.draining
+    }
+
+    /// Drops every application waiting for `entity`.
+    pub(crate) fn forget(&mut self, entity: Entity) {
+        self.pending.retain(|entry| entry.entity != entity);
+    }
+
+    /// Queues an application of effect type `E` for the next drain of `schedule`.
+    pub(crate) fn push<E: Event + Clone>(
+        &mut self,
+        schedule: InternedScheduleLabel,
+        entity: Entity,
+        modifier: ValueModifier,
+        depends_on: &[TypeId],
+        coalesce: Option<Coalesce>,
+        apply: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) {
+        self.pending.push(QueuedEffect {
+            schedule,
+            effect_type: TypeId::of::<E>(),
+            effect_name: std::any::type_name::<E>(),
+            entity,
+            modifier,
+            depends_on: depends_on.to_vec(),
+            coalesce,
+            apply: Box::new(apply),
+        });
+    }
+}
+
+/// Orders queued effects so dependencies apply first.
+///
+/// Uses Kahn's algorithm with edges found through a map from effect type to
+/// entries. Entries apply in insertion order unless waiting on a dependency,
+/// and then as soon as their last dependency has applied, so the result is
+/// stable. Returns `None` if the dependencies form a cycle.
+fn dependency_order(queue: &[QueuedEffect]) -> Option<Vec<usize>> {
+    let len = queue.len();
+    let mut by_type: HashMap<TypeId, Vec<usize>> = HashMap::default();
+    for (index, entry) in queue.iter().enumerate() {
+        by_type.entry(entry.effect_type).or_default().push(index);
+    }
+
+    let mut in_degree = vec![0usize; len];
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); len];
+    for (index, entry) in queue.iter().enumerate() {
+        for (position, dependency) in entry.depends_on.iter().enumerate() {
+            if entry.depends_on[..position].contains(dependency) {
+                continue;
+            }
+            let Some(others) = by_type.get(dependency) else {
+                continue;
+            };
+            for &other in others {
+                if index == other {
+                    return None;
+                }
+                dependents[other].push(index);
+                in_degree[index] += 1;
+            }
+        }
+    }
+
+    let mut ready: VecDeque<usize> = (0..len).filter(|&index| in_degree[index] == 0).collect();
+    let mut order = Vec::with_capacity(len);
+    while let Some(next) = ready.pop_front() {
+        order.push(next);
+        for &dependent in &dependents[next] {
+            in_degree[dependent] -= 1;
+            if in_degree[dependent] == 0 {
+                ready.push_back(dependent);
+            }
+        }
+    }
+    (order.len() == len).then_some(order)
+}
+
+/// Applies all queued effects in dependency order, after coalescing.
+///
+/// Dependency cycles are reported as an error and the frame's queue falls
+/// back to insertion order. The scheduled drains only apply their own
+/// schedule's entries; call this to apply everything at once. Does nothing
+/// if no component is [deferred](crate::StatusEffectApplication::deferred).
+pub fn drain_status_effect_queue(world: &mut World) {
+    drain(world, None);
+}
+
+/// Drains the entries queued for `schedule`, or all entries.
+fn drain(world: &mut World, schedule: Option<InternedScheduleLabel>) {
+    let pending = {
+        let Some(mut queue) = world.get_resource_mut::<StatusEffectQueue>() else {
+            return;
+        };
+        let (pending, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut queue.pending)
+            .into_iter()
+            .partition(|entry| schedule.is_none_or(|schedule| entry.schedule == schedule));
+        queue.pending = rest;
+        if pending.is_empty() {
+            return;
+        }
+        queue.draining = true;
+        pending
+    };
+    let pending = coalesce(pending);
+
+    let order = dependency_order(&pending).unwrap_or_else(|| {
+        let names: Vec<_> = pending.iter().map(|entry| entry.effect_name).collect();
+        log_error!(
+            "Cyclic status effect dependencies among {:?}; applying in insertion order",
+            names
+        );
+        (0..pending.len()).collect()
+    });
+
+    let mut slots: Vec<_> = pending.into_iter().map(Some).collect();
+    for index in order {
+        if let Some(entry) = slots[index].take() {
+            (entry.apply)(world);
+            // Flush so auto-insert re-triggers land inside the drain
+            world.flush();
+        }
+    }
+
+    if let Some(mut queue) = world.get_resource_mut::<StatusEffectQueue>() {
+        queue.draining = false;
+    }
+}
+
+/// System set containing the queue's drain system in each drained schedule.
+///
+/// Order systems before it to have their triggers apply in the same frame.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatusEffectDrain;
+
+/// Schedules that already have a drain system.
+#[derive(Resource, Default)]
+struct DrainSchedules(HashSet<InternedScheduleLabel>);
+
+/// Adds a drain system for `schedule` unless it already has one.
+pub(crate) fn add_drain_schedule(app: &mut App, schedule: InternedScheduleLabel) {
+    let mut schedules = app.world_mut().get_resource_or_init::<DrainSchedules>();
+    if !schedules.0.insert(schedule) {
+        return;
+    }
+    app.add_systems(
+        schedule,
+        (move |world: &mut World| drain(world, Some(schedule))).in_set(StatusEffectDrain),
+    );
+}
+
+/// Registers the queue resource and its [`PostUpdate`] drain once per app.
+pub(crate) struct StatusEffectQueuePlugin;
+
+impl Plugin for StatusEffectQueuePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StatusEffectQueue>();
+        add_drain_schedule(app, PostUpdate.intern());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    /// Reduces armor; applied before damage.
+    #[derive(Event, Clone, Copy)]
+    struct ArmorShred(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for ArmorShred {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    /// Damage mitigated by armor at application time.
+    #[derive(Component, Default)]
+    struct DamageTaken(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Damage(f32);
+
+    impl StatusEffectApplicator<DamageTaken> for Damage {
+        fn modifier(&self) -> ValueModifier {
+            ValueModifier::Val(self.0)
+        }
+
+        fn apply(&self, component: &mut DamageTaken, _power: f32) {
+            component.0 += self.0;
+        }
+
+        fn depends_on(&self) -> &[TypeId] {
+            const { &[TypeId::of::<ArmorShred>()] }
+        }
+    }
+
+    /// Records the armor value seen when damage lands.
+    #[derive(Resource, Default)]
+    struct ArmorWhenDamaged(Vec<f32>);
+
+    fn record_armor(
+        on: On<ApplyStatusEffect<Damage>>,
+        q: Query<&Armor>,
+        mut seen: ResMut<ArmorWhenDamaged>,
+        queue: Res<StatusEffectQueue>,
+    ) {
+        if queue.is_draining()
+            && let Ok(armor) = q.get(on.entity)
+        {
+            seen.0.push(armor.0);
+        }
+    }
+
+    fn deferred_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorShred>::new(
+            StatusEffectApplication::default().deferred(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<DamageTaken, Damage>::new(
+            StatusEffectApplication::default().deferred(),
+        ));
+        app
+    }
+
+    #[test]
+    fn dependent_effect_applies_after_dependency_regardless_of_trigger_order() {
+        let mut app = deferred_app();
+        app.init_resource::<ArmorWhenDamaged>();
+        app.add_observer(record_armor);
+
+        let entity = app.world_mut().spawn((Armor(50.0), DamageTaken(0.0))).id();
+        app.update();
+
+        // Damage is triggered first but depends on the shred
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: Damage(10.0),
+            entity,
+        });
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: ArmorShred(ValueModifier::Val(-20.0)),
+            entity,
+        });
+
+        // Nothing applied until the queue drains
+        assert_eq!(app.world().resource::<StatusEffectQueue>().len(), 2);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 50.0);
+
+        app.update();
+
+        assert!(app.world().resource::<StatusEffectQueue>().is_empty());
+        assert_eq!(app.world().resource::<ArmorWhenDamaged>().0, vec![30.0]);
+        assert_eq!(app.world().get::<DamageTaken>(entity).unwrap().0, 10.0);
+    }
+
+    #[test]
+    fn pending_effects_lists_queued_applications_until_drained() {
+        let mut app = deferred_app();
+        let entity = app.world_mut().spawn((Armor(50.0), DamageTaken(0.0))).id();
+        let other = app.world_mut().spawn(Armor(50.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorShred(ValueModifier::Val(-20.0)))
+            .apply_effect(entity, Damage(10.0))
+            .apply_effect(other, ArmorShred(ValueModifier::Percent(50.0)));
+        app.world_mut().flush();
+
+        let queue = app.world().resource::<StatusEffectQueue>();
+        let pending = queue.pending_effects(entity);
+        assert_eq!(pending.len(), 2);
+        assert!(pending[0].is::<ArmorShred>());
+        assert_eq!(pending[0].modifier, ValueModifier::Val(-20.0));
+        assert!(pending[1].is::<Damage>());
+        assert_eq!(
+            queue.pending_effects(other),
+            [PendingEffect {
+                effect: std::any::type_name::<ArmorShred>(),
+                modifier: ValueModifier::Percent(50.0),
+            }]
+        );
+
+        app.update();
+        let queue = app.world().resource::<StatusEffectQueue>();
+        assert!(queue.pending_effects(entity).is_empty());
+        assert!(queue.pending_effects(other).is_empty());
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 30.0);
+    }
+
+    #[test]
+    fn deferred_auto_insert_applies_in_same_drain() {
+        let mut app = deferred_app();
+        let entity = app.world_mut().spawn_empty().id();
+        app.update();
+
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: ArmorShred(ValueModifier::Val(15.0)),
+            entity,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 15.0);
+    }
+
+    #[derive(Component, Default)]
+    struct Poison(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct PoisonTick(ValueModifier);
+
+    impl StatusEffectApplicator<Poison> for PoisonTick {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Poison, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    fn poison_after_ticks(config: StatusEffectApplication<Poison>, ticks: &[ValueModifier]) -> f32 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Poison, PoisonTick>::new(config));
+        let entity = app.world_mut().spawn(Poison(30.0)).id();
+        app.update();
+
+        for &tick in ticks {
+            app.world_mut().trigger(ApplyStatusEffect {
+                effect: PoisonTick(tick),
+                entity,
+            });
+        }
+        app.update();
+        app.world().get::<Poison>(entity).unwrap().0
+    }
+
+    #[test]
+    fn coalesced_flats_apply_as_one_sum() {
+        let ticks = [ValueModifier::Val(1.0); 10];
+        let sequential = poison_after_ticks(StatusEffectApplication::sqrt().deferred(), &ticks);
+        let coalesced = poison_after_ticks(StatusEffectApplication::sqrt().coalesced(), &ticks);
+
+        // sqrt(30^2 + 10 * 1^2) vs sqrt(30^2 + 10^2)
+        assert!((sequential - 910.0f32.sqrt()).abs() < 0.001);
+        assert!((coalesced - 1000.0f32.sqrt()).abs() < 0.001);
+    }
+
+    #[test]
+    fn coalesced_percents_multiply_separately_from_flats() {
+        let ticks = [
+            ValueModifier::Percent(10.0),
+            ValueModifier::Val(10.0),
+            ValueModifier::Percent(10.0),
+        ];
+        let coalesced = poison_after_ticks(StatusEffectApplication::linear().coalesced(), &ticks);
+
+        // Percents merge into +21% in the first slot, then the flat applies
+        assert!((coalesced - (30.0 * 1.21 + 10.0)).abs() < 0.001);
+    }
+
+    /// Effect types that depend on each other.
+    #[derive(Event, Clone, Copy)]
+    struct CycleA;
+
+    #[derive(Event, Clone, Copy)]
+    struct CycleB;
+
+    impl StatusEffectApplicator<Armor> for CycleA {
+        fn modifier(&self) -> ValueModifier {
+            ValueModifier::Val(1.0)
+        }
+
+        fn apply(&self, component: &mut Armor, _power: f32) {
+            component.0 = component.0 * 10.0 + 1.0;
+        }
+
+        fn depends_on(&self) -> &[TypeId] {
+            const { &[TypeId::of::<CycleB>()] }
+        }
+    }
+
+    impl StatusEffectApplicator<Armor> for CycleB {
+        fn modifier(&self) -> ValueModifier {
+            ValueModifier::Val(2.0)
+        }
+
+        fn apply(&self, component: &mut Armor, _power: f32) {
+            component.0 = component.0 * 10.0 + 2.0;
+        }
+
+        fn depends_on(&self) -> &[TypeId] {
+            const { &[TypeId::of::<CycleA>()] }
+        }
+    }
+
+    #[test]
+    fn cyclic_dependencies_fall_back_to_insertion_order() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, CycleA>::new(
+            StatusEffectApplication::default().deferred(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, CycleB>::new(
+            StatusEffectApplication::default().deferred(),
+        ));
+
+        let entity = app.world_mut().spawn(Armor(0.0)).id();
+        app.update();
+
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: CycleB,
+            entity,
+        });
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: CycleA,
+            entity,
+        });
+        app.update();
+
+        // B then A, as triggered
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 21.0);
+    }
+
+    /// Armor values seen by systems around the drain, in run order.
+    #[derive(Resource, Default)]
+    struct ArmorSeen(Vec<f32>);
+
+    fn see_armor(q: Query<&Armor>, mut seen: ResMut<ArmorSeen>) {
+        seen.0.push(q.single().unwrap().0);
+    }
+
+    fn shred_once(amount: f32) -> impl FnMut(Commands, Query<Entity, With<Armor>>, Local<bool>) {
+        move |mut commands, q, mut done| {
+            if !*done {
+                *done = true;
+                commands.apply_effect(q.single().unwrap(), ArmorShred(ValueModifier::Val(amount)));
+            }
+        }
+    }
+
+    #[test]
+    fn applications_drain_in_chosen_schedule() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorShred>::new(
+            StatusEffectApplication::default().applied_in(PostUpdate),
+        ));
+        app.init_resource::<ArmorSeen>();
+        app.add_systems(Update, shred_once(-10.0));
+        app.add_systems(
+            PostUpdate,
+            (
+                see_armor.before(StatusEffectDrain),
+                see_armor.after(StatusEffectDrain),
+            ),
+        );
+        app.add_systems(Last, shred_once(-5.0));
+        app.world_mut().spawn(Armor(50.0));
+
+        // Update's trigger applies in this frame's drain, Last's in the next
+        app.update();
+        assert_eq!(app.world().resource::<ArmorSeen>().0, vec![50.0, 40.0]);
+        assert_eq!(app.world().resource::<StatusEffectQueue>().len(), 1);
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<ArmorSeen>().0,
+            vec![50.0, 40.0, 40.0, 35.0]
+        );
+    }
+
+    #[test]
+    fn drain_without_deferred_components_does_nothing() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorShred>::default());
+        app.add_systems(PostUpdate, drain_status_effect_queue);
+
+        app.update();
+        assert!(!app.world().contains_resource::<StatusEffectQueue>());
+    }
+}
@@ -0,0 +1,156 @@
+//! Effects on a random subset of entities.
+//!
+//! [`apply_to_random`] picks `count` entities matching a query filter and
+//! applies an effect to each, for "random N enemies are poisoned". Picks come
+//! from the [`StatusEffectRng`] resource, which can be seeded for replays and
+//! tests. Requires the `rand` feature.
+
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+
+use crate::ApplyStatusEffect;
+
+/// Random source for [`apply_to_random`].
+///
+/// Inserted with OS entropy on first use unless the app provides one. Insert
+/// [`StatusEffectRng::seeded`] for deterministic picks.
+#[derive(Resource)]
+pub struct StatusEffectRng(pub StdRng);
+
+impl StatusEffectRng {
+    /// Creates a generator with a fixed seed.
+    #[must_use]
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for StatusEffectRng {
+    fn default() -> Self {
+        Self(StdRng::from_os_rng())
+    }
+}
+
+/// Applies `effect` to `count` random entities matching filter `F`.
+///
+/// With fewer matches than `count`, every match gets the effect. Returns the
+/// picked entities, ordered by index. Candidates are ordered by index before
+/// picking, so the same seed and entities give the same picks.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::apply_to_random;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Poison(ValueModifier);
+///
+/// fn plague(world: &mut World) {
+///     apply_to_random::<With<Enemy>, _>(world, Poison(ValueModifier::Val(-5.0)), 3);
+/// }
+/// ```
+pub fn apply_to_random<F, E>(world: &mut World, effect: E, count: usize) -> Vec<Entity>
+where
+    F: QueryFilter + 'static,
+    E: Event + Clone,
+{
+    let mut candidates: Vec<Entity> = world.query_filtered::<Entity, F>().iter(world).collect();
+    candidates.sort_by_key(|entity| entity.index());
+    let mut rng = world.get_resource_or_insert_with(StatusEffectRng::default);
+    let mut picked = candidates.into_iter().choose_multiple(&mut rng.0, count);
+    picked.sort_by_key(|entity| entity.index());
+    for &entity in &picked {
+        world.trigger(ApplyStatusEffect {
+            effect: effect.clone(),
+            entity,
+        });
+    }
+    world.flush();
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    #[derive(Component)]
+    struct Enemy;
+
+    #[derive(Event, Clone, Copy)]
+    struct Poison(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Poison {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    /// Poisons 3 of 8 enemies with a fixed seed, returning the poisoned ones.
+    fn poison_three(seed: u64) -> Vec<Entity> {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Poison>::default());
+        app.insert_resource(StatusEffectRng::seeded(seed));
+        app.world_mut().spawn(Health(100.0));
+        for _ in 0..8 {
+            app.world_mut().spawn((Health(100.0), Enemy));
+        }
+
+        let world = app.world_mut();
+        let picked = apply_to_random::<With<Enemy>, _>(world, Poison(ValueModifier::Val(-5.0)), 3);
+        assert_eq!(picked.len(), 3);
+        let mut poisoned: Vec<Entity> = world
+            .query::<(Entity, &Health)>()
+            .iter(world)
+            .filter(|(_, health)| health.0 == 95.0)
+            .map(|(entity, _)| entity)
+            .collect();
+        poisoned.sort_by_key(|entity| entity.index());
+        assert_eq!(poisoned, picked);
+        assert!(
+            picked
+                .iter()
+                .all(|&entity| world.get::<Enemy>(entity).is_some())
+        );
+        picked
+    }
+
+    #[test]
+    fn seeded_picks_are_deterministic() {
+        assert_eq!(poison_three(7), poison_three(7));
+    }
+
+    #[test]
+    fn fewer_matches_than_count_applies_to_all() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Poison>::default());
+        let enemies = [
+            app.world_mut().spawn((Health(100.0), Enemy)).id(),
+            app.world_mut().spawn((Health(100.0), Enemy)).id(),
+        ];
+
+        let picked =
+            apply_to_random::<With<Enemy>, _>(app.world_mut(), Poison(ValueModifier::Val(-5.0)), 5);
+        assert_eq!(picked, enemies);
+        for entity in enemies {
+            assert_eq!(app.world().get::<Health>(entity).unwrap().0, 95.0);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Human-readable reasons for combat logs.
+//!
+//! With the effect's plugin built
+//! [`with_reasons`](crate::StatusEffectPlugin::with_reasons), triggering
+//! [`ApplyStatusEffectReason`] instead of [`ApplyStatusEffect`] applies the
+//! effect the same way, and the reason is
+//! carried through to [`StatusEffectApplied::reason`](crate::StatusEffectApplied::reason)
+//! and, for tracked components, [`AppliedEffect::reason`](crate::AppliedEffect::reason).
+//! Applications without a reason report an empty one.
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{ApplyStatusEffect, with_override};
+
+/// Applies an effect to an entity, recording why.
+///
+/// Needs the effect's plugin built
+/// [`with_reasons`](crate::StatusEffectPlugin::with_reasons).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Burn(ValueModifier);
+///
+/// fn fireball_hit(mut commands: Commands, target: Entity, caster: &Name) {
+///     commands.trigger(ApplyStatusEffectReason {
+///         effect: Burn(ValueModifier::Val(-12.0)),
+///         entity: target,
+///         reason: format!("from Fireball cast by {caster}").into(),
+///     });
+/// }
+/// ```
+#[derive(EntityEvent, Clone)]
+pub struct ApplyStatusEffectReason<E: Event + Clone> {
+    /// The effect to apply
+    pub effect: E,
+    /// The target entity
+    pub entity: Entity,
+    /// Why the effect was applied
+    pub reason: Cow<'static, str>,
+}
+
+/// Reason for the application currently being triggered.
+///
+/// Only present for the duration of one trigger.
+#[derive(Resource)]
+pub(crate) struct ApplyReason(pub(crate) Cow<'static, str>);
+
+/// Triggers `event` with `reason` attached.
+pub(crate) fn trigger_with_reason<E>(
+    world: &mut World,
+    event: ApplyStatusEffect<E>,
+    reason: Cow<'static, str>,
+) where
+    E: Event + Clone,
+{
+    with_override(world, ApplyReason(reason), |world| world.trigger(event));
+}
+
+fn apply_status_effect_reason_observer<E: Event + Clone>(
+    on: On<ApplyStatusEffectReason<E>>,
+    mut commands: Commands,
+) {
+    let ApplyStatusEffectReason {
+        effect,
+        entity,
+        reason,
+    } = on.event().clone();
+    commands.queue(move |world: &mut World| {
+        trigger_with_reason(world, ApplyStatusEffect { effect, entity }, reason);
+    });
+}
+
+/// Registers reasoned applications of effect `E` once per app.
+pub(crate) struct ReasonPlugin<E: Event + Clone>(PhantomData<E>);
+
+impl<E: Event + Clone> Default for ReasonPlugin<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: Event + Clone> Plugin for ReasonPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(apply_status_effect_reason_observer::<E>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    impl StatValue for Health {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Burn(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Burn {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<String>);
+
+    #[test]
+    fn reason_propagates_to_applied_event() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(
+            StatusEffectPlugin::<Health, Burn>::new(StatusEffectApplication::linear().tracked())
+                .with_reasons(),
+        );
+        app.init_resource::<Log>();
+        app.add_observer(|on: On<StatusEffectApplied<Burn>>, mut log: ResMut<Log>| {
+            log.0.push(on.reason.to_string());
+        });
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+
+        app.world_mut().commands().trigger(ApplyStatusEffectReason {
+            effect: Burn(ValueModifier::Val(-12.0)),
+            entity,
+            reason: "from Fireball cast by Goblin".into(),
+        });
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Burn(ValueModifier::Val(-3.0)));
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 85.0);
+        assert_eq!(
+            app.world().resource::<Log>().0,
+            ["from Fireball cast by Goblin", ""]
+        );
+        let applied = app.world().get::<AppliedEffects<Health>>(entity).unwrap();
+        assert_eq!(applied.effects[0].reason, "from Fireball cast by Goblin");
+        assert!(applied.effects[1].reason.is_empty());
+    }
+
+    #[test]
+    fn reason_survives_auto_insert() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(
+            StatusEffectPlugin::<Health, Burn>::new(StatusEffectApplication::linear().tracked())
+                .with_reasons(),
+        );
+        app.init_resource::<Log>();
+        app.add_observer(|on: On<StatusEffectApplied<Burn>>, mut log: ResMut<Log>| {
+            log.0.push(on.reason.to_string());
+        });
+        let entity = app.world_mut().spawn_empty().id();
+
+        app.world_mut().commands().trigger(ApplyStatusEffectReason {
+            effect: Burn(ValueModifier::Val(12.0)),
+            entity,
+            reason: "from Regrowth".into(),
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 12.0);
+        assert_eq!(app.world().resource::<Log>().0, ["from Regrowth"]);
+        let applied = app.world().get::<AppliedEffects<Health>>(entity).unwrap();
+        assert_eq!(applied.effects[0].reason, "from Regrowth");
+    }
+}
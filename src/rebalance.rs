@@ -0,0 +1,145 @@
+//! Rebalancing tracked stacks when an effect is removed.
+//!
+//! Normally removing a tracked effect just rebuilds the stat from the rest.
+//! With [`RebalanceOnRemove<C>`] on the entity, the remaining effects grow
+//! (or shrink) proportionally so the stack keeps adding up to a budget.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{AppliedEffects, EPSILON, MutableComponent};
+
+/// Keeps the tracked effects on component `C` adding up to `budget` when one
+/// is removed.
+///
+/// The total is the sum of modifier values, flat amounts and percent points
+/// alike, with multipliers counted as their percent equivalent. After a removal, every remaining modifier is scaled by
+/// `budget / total`. Removing the last effect leaves the base.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Armor(f32);
+///
+/// fn spawn_guardian(mut commands: Commands) {
+///     // Ward stacks share 30 armor between them
+///     commands.spawn((Armor(10.0), RebalanceOnRemove::<Armor>::new(30.0)));
+/// }
+/// ```
+#[derive(Component)]
+pub struct RebalanceOnRemove<C: MutableComponent> {
+    /// Total the remaining modifiers are scaled to
+    pub budget: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> RebalanceOnRemove<C> {
+    /// Keeps the stack adding up to `budget`.
+    #[must_use]
+    pub fn new(budget: f32) -> Self {
+        Self {
+            budget,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Scales the remaining effects so they add up to the budget.
+///
+/// Stacks whose total is zero can't be scaled and are left as they are.
+pub(crate) fn rebalance<C: MutableComponent>(
+    applied: &mut AppliedEffects<C>,
+    policy: &RebalanceOnRemove<C>,
+) {
+    let total: f32 = applied
+        .effects
+        .iter()
+        .map(|effect| effect.modifier.signed_points())
+        .sum();
+    if total.abs() <= EPSILON {
+        return;
+    }
+    let factor = policy.budget / total;
+    for effect in &mut applied.effects {
+        effect.modifier = effect.modifier.scaled_by(factor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    impl StatValue for Armor {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Ward(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for Ward {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn removal_rebalances_remaining_stacks_to_budget() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, Ward>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let entity = app
+            .world_mut()
+            .spawn((Armor(10.0), RebalanceOnRemove::<Armor>::new(30.0)))
+            .id();
+
+        for amount in [5.0, 10.0, 15.0] {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Ward(ValueModifier::Val(amount)));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 40.0);
+
+        let remove = |app: &mut App| {
+            app.world_mut()
+                .commands()
+                .trigger(RemoveStatusEffect::<Ward>::new(entity));
+            app.update();
+        };
+
+        // The 10 and 15 stacks grow to 12 and 18
+        remove(&mut app);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 40.0);
+        let applied = app.world().get::<AppliedEffects<Armor>>(entity).unwrap();
+        let modifiers: Vec<_> = applied.effects.iter().map(|e| e.modifier).collect();
+        assert_eq!(
+            modifiers,
+            [ValueModifier::Val(12.0), ValueModifier::Val(18.0)]
+        );
+
+        remove(&mut app);
+        remove(&mut app);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 10.0);
+    }
+}
@@ -0,0 +1,283 @@
+//! Opt-in recording of effect applications for deterministic replay.
+//!
+//! Insert a [`StatusEffectRecorder`] resource to start recording. Every
+//! application is appended to its [`StatusEffectLog`], which can be serialized,
+//! stored with a bug report, and later re-applied to a fresh world with
+//! [`replay`].
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{StatusEffectRegistry, ValueModifier};
+
+/// Serde helper storing entities as their raw bits.
+pub(crate) mod entity_bits {
+    use bevy::prelude::Entity;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(entity: &Entity, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(entity.to_bits())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Entity, D::Error> {
+        let bits = u64::deserialize(deserializer)?;
+        Entity::try_from_bits(bits).ok_or_else(|| D::Error::custom("invalid entity bits"))
+    }
+}
+
+/// A single recorded effect application.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEffect {
+    /// Frame the effect was applied on
+    pub frame: u32,
+    /// Entity the effect was applied to, as seen in the recording world
+    #[serde(with = "entity_bits")]
+    pub entity: Entity,
+    /// Type name of the effect
+    pub effect: String,
+    /// Modifier carried by the effect
+    pub modifier: ValueModifier,
+}
+
+/// Serializable, ordered log of effect applications.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffectLog {
+    /// Applications in the order they happened
+    pub entries: Vec<RecordedEffect>,
+}
+
+/// Records every effect application while present in the world.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// app.init_resource::<StatusEffectRecorder>();
+///
+/// // ... run the session, then grab the log
+/// let log = app.world_mut().resource_mut::<StatusEffectRecorder>().take_log();
+/// assert!(log.entries.is_empty());
+/// ```
+#[derive(Resource, Default)]
+pub struct StatusEffectRecorder {
+    /// Applications recorded so far
+    pub log: StatusEffectLog,
+}
+
+impl StatusEffectRecorder {
+    /// Takes the recorded log, leaving an empty one behind.
+    #[must_use]
+    pub fn take_log(&mut self) -> StatusEffectLog {
+        std::mem::take(&mut self.log)
+    }
+
+    pub(crate) fn record<E>(&mut self, frame: u32, entity: Entity, modifier: ValueModifier) {
+        self.log.entries.push(RecordedEffect {
+            frame,
+            entity,
+            effect: std::any::type_name::<E>().to_string(),
+            modifier,
+        });
+    }
+}
+
+/// Re-applies a recorded log to `world`, in order.
+///
+/// `entity_map` maps entities from the recording world to their counterparts
+/// in `world`. Entries for unmapped entities or for effect types that don't
+/// implement [`from_modifier`](crate::StatusEffectApplicator::from_modifier)
+/// are skipped with a warning. Effects on deferred components are queued for
+/// the next drain.
+pub fn replay(log: &StatusEffectLog, world: &mut World, entity_map: &EntityHashMap<Entity>) {
+    for entry in &log.entries {
+        let Some(&entity) = entity_map.get(&entry.entity) else {
+            log_warn!(
+                "Skipping replay of {}: unmapped entity {}",
+                entry.effect,
+                entry.entity
+            );
+            continue;
+        };
+        let apply = world
+            .get_resource::<StatusEffectRegistry>()
+            .and_then(|registry| registry.apply_modifier_fn(&entry.effect));
+        strict_assert!(
+            apply.is_some(),
+            "Replayed effect {} is not registered",
+            entry.effect
+        );
+        if !apply.is_some_and(|apply| apply(world, entity, entry.modifier)) {
+            log_warn!(
+                "Skipping replay of {}: effect is not replayable",
+                entry.effect
+            );
+            continue;
+        }
+        world.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct ArmorEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for ArmorEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::sqrt(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::default());
+        app
+    }
+
+    #[test]
+    fn recorder_captures_applications_with_frames() {
+        let mut app = app();
+        app.init_resource::<StatusEffectRecorder>();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: SpeedEffect(ValueModifier::Percent(50.0)),
+            entity,
+        });
+        app.update();
+        app.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: SpeedEffect(ValueModifier::Val(10.0)),
+            entity,
+        });
+        app.update();
+
+        let log = &app.world().resource::<StatusEffectRecorder>().log;
+        assert_eq!(log.entries.len(), 2);
+        assert_eq!(log.entries[0].entity, entity);
+        assert_eq!(log.entries[0].modifier, ValueModifier::Percent(50.0));
+        assert!(log.entries[0].effect.ends_with("SpeedEffect"));
+        assert!(log.entries[1].frame > log.entries[0].frame);
+    }
+
+    #[test]
+    fn replay_reproduces_final_state_in_fresh_world() {
+        // Record a short session
+        let mut recording = app();
+        recording.init_resource::<StatusEffectRecorder>();
+        let player = recording
+            .world_mut()
+            .spawn((Speed(100.0), Armor(10.0)))
+            .id();
+        let enemy = recording.world_mut().spawn(Speed(80.0)).id();
+        recording.update();
+
+        for (entity, modifier) in [
+            (player, ValueModifier::Percent(50.0)),
+            (enemy, ValueModifier::Val(-30.0)),
+            (player, ValueModifier::Val(40.0)),
+        ] {
+            recording.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: SpeedEffect(modifier),
+                entity,
+            });
+            recording.update();
+        }
+        // Enemy had no armor: auto-inserted then applied
+        recording.world_mut().commands().trigger(ApplyStatusEffect {
+            effect: ArmorEffect(ValueModifier::Val(5.0)),
+            entity: enemy,
+        });
+        recording.update();
+        recording.update();
+
+        let log = recording
+            .world_mut()
+            .resource_mut::<StatusEffectRecorder>()
+            .take_log();
+
+        // Round-trip through a serialized form
+        let json = serde_json::to_string(&log).unwrap();
+        let log: StatusEffectLog = serde_json::from_str(&json).unwrap();
+
+        // Fresh world with different entity ids
+        let mut fresh = app();
+        fresh.world_mut().spawn_empty();
+        let new_player = fresh.world_mut().spawn((Speed(100.0), Armor(10.0))).id();
+        let new_enemy = fresh.world_mut().spawn(Speed(80.0)).id();
+        assert_ne!(new_player, player);
+
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(player, new_player);
+        entity_map.insert(enemy, new_enemy);
+        replay(&log, fresh.world_mut(), &entity_map);
+
+        for (old, new) in [(player, new_player), (enemy, new_enemy)] {
+            let expected = recording.world().get::<Speed>(old).unwrap().0;
+            let actual = fresh.world().get::<Speed>(new).unwrap().0;
+            assert!((expected - actual).abs() < 0.001);
+        }
+        assert_eq!(fresh.world().get::<Armor>(new_enemy).unwrap().0, 5.0);
+        assert_eq!(fresh.world().get::<Armor>(new_player).unwrap().0, 10.0);
+    }
+
+    #[test]
+    fn replay_skips_unmapped_entities() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        let log = StatusEffectLog {
+            entries: vec![RecordedEffect {
+                frame: 0,
+                entity,
+                effect: std::any::type_name::<SpeedEffect>().to_string(),
+                modifier: ValueModifier::Val(10.0),
+            }],
+        };
+
+        replay(&log, app.world_mut(), &EntityHashMap::default());
+
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+    }
+}
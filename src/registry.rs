@@ -0,0 +1,169 @@
+//! Type-erased registry of status effect types.
+//!
+//! Each [`StatusEffectPlugin`](crate::StatusEffectPlugin) records its effect
+//! type here, so tools that only know an effect by name (replay, save files,
+//! data-driven content) can construct and apply it. Components configured
+//! with a readable stat value are recorded too, for
+//! [stat snapshots](crate::snapshot_stats). Every component's config and
+//! tracked effects can be captured in a [save](crate::StatusEffectSave), and
+//! tracked components can have their effects
+//! [copied](crate::StatusEffectCommandsExt::copy_effects) between entities or
+//! [reset](crate::StatusEffectCommandsExt::reset_all_stats) all at once.
+//!
+//! Entries are kept sorted by type name, so iterating them visits components
+//! in the same order on every run, whatever order the plugins were added in.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use crate::save::SaveFns;
+use crate::{
+    AppliedEffect, AppliedEffects, ApplyStatusEffect, ComponentScope, MutableComponent,
+    StatusEffectApplication, StatusEffectApplicator, ValueModifier, tracking, with_override,
+};
+
+/// Type-erased application of an effect built from a modifier.
+///
+/// Returns false if the effect type can't be built from a modifier.
+pub type ApplyModifierFn = fn(&mut World, Entity, ValueModifier) -> bool;
+
+/// Type-erased read of an entity's stat value.
+///
+/// Returns `None` if the entity doesn't have the component.
+pub type ReadStatFn = fn(&World, Entity) -> Option<f32>;
+
+/// Type-erased read of an entity's tracked effects, empty if it has none.
+pub(crate) type ReadTrackedFn = fn(&World, Entity) -> Vec<AppliedEffect>;
+
+/// Type-erased reset of an entity's tracked stat to its base.
+pub(crate) type ResetStatFn = fn(&mut World, Entity);
+
+/// Registered effect types and stat components, keyed by their type name.
+#[derive(Resource, Default)]
+pub struct StatusEffectRegistry {
+    effects: BTreeMap<&'static str, ApplyModifierFn>,
+    /// Applications limited to one component, keyed by effect, then component
+    scoped: BTreeMap<&'static str, BTreeMap<&'static str, ApplyModifierFn>>,
+    stats: BTreeMap<&'static str, ReadStatFn>,
+    saves: BTreeMap<&'static str, SaveFns>,
+    tracked: BTreeMap<&'static str, ReadTrackedFn>,
+    resets: BTreeMap<&'static str, ResetStatFn>,
+}
+
+impl StatusEffectRegistry {
+    /// Registers effect type `E` targeting component `C`.
+    pub(crate) fn register<C, E>(&mut self)
+    where
+        C: MutableComponent,
+        E: StatusEffectApplicator<C>,
+    {
+        self.effects
+            .insert(std::any::type_name::<E>(), apply_from_modifier::<C, E>);
+        self.scoped
+            .entry(std::any::type_name::<E>())
+            .or_default()
+            .insert(std::any::type_name::<C>(), apply_to_component::<C, E>);
+    }
+
+    /// Returns true if an effect with this type name is registered.
+    #[must_use]
+    pub fn contains(&self, effect: &str) -> bool {
+        self.effects.contains_key(effect)
+    }
+
+    /// Returns the type-erased apply function for a registered effect.
+    #[must_use]
+    pub fn apply_modifier_fn(&self, effect: &str) -> Option<ApplyModifierFn> {
+        self.effects.get(effect).copied()
+    }
+
+    /// Returns the apply function for a registered effect that only reaches
+    /// `component`, for effect types that target several components.
+    pub(crate) fn apply_to_fn(&self, effect: &str, component: &str) -> Option<ApplyModifierFn> {
+        self.scoped.get(effect)?.get(component).copied()
+    }
+
+    /// Registers component `C` as stat-bearing.
+    pub(crate) fn register_stat<C: MutableComponent>(&mut self) {
+        self.stats
+            .insert(std::any::type_name::<C>(), read_stat::<C>);
+    }
+
+    /// Registers component `C` for save blobs.
+    pub(crate) fn register_save<C: MutableComponent>(&mut self) {
+        self.saves
+            .insert(std::any::type_name::<C>(), SaveFns::of::<C>());
+    }
+
+    /// Registers component `C` as tracked.
+    pub(crate) fn register_tracked<C: MutableComponent>(&mut self) {
+        self.tracked
+            .insert(std::any::type_name::<C>(), read_tracked::<C>);
+        self.resets
+            .insert(std::any::type_name::<C>(), tracking::reset_stat::<C>);
+    }
+
+    /// Iterates the tracked components and their effect readers.
+    pub(crate) fn tracked(&self) -> impl Iterator<Item = (&'static str, ReadTrackedFn)> + '_ {
+        self.tracked.iter().map(|(&name, &read)| (name, read))
+    }
+
+    /// Iterates the tracked components' reset functions.
+    pub(crate) fn resets(&self) -> impl Iterator<Item = ResetStatFn> + '_ {
+        self.resets.values().copied()
+    }
+
+    /// Iterates the saveable components and their export and import functions.
+    pub(crate) fn saves(&self) -> impl Iterator<Item = (&'static str, SaveFns)> + '_ {
+        self.saves.iter().map(|(&name, &save)| (name, save))
+    }
+
+    /// Returns the export and import functions of a saveable component.
+    pub(crate) fn save_fns(&self, component: &str) -> Option<SaveFns> {
+        self.saves.get(component).copied()
+    }
+
+    /// Iterates the stat-bearing components and their readers, sorted by name.
+    pub fn stats(&self) -> impl Iterator<Item = (&'static str, ReadStatFn)> + '_ {
+        self.stats.iter().map(|(&name, &read)| (name, read))
+    }
+}
+
+/// Reads `C`'s stat value through its configured accessor.
+fn read_stat<C: MutableComponent>(world: &World, entity: Entity) -> Option<f32> {
+    let config = world.get_resource::<StatusEffectApplication<C>>()?;
+    config.stat_value(world.get::<C>(entity)?)
+}
+
+/// Clones the entity's tracked effects on `C`.
+fn read_tracked<C: MutableComponent>(world: &World, entity: Entity) -> Vec<AppliedEffect> {
+    world
+        .get::<AppliedEffects<C>>(entity)
+        .map(|applied| applied.effects.clone())
+        .unwrap_or_default()
+}
+
+/// Builds `E` from a modifier and triggers it on `entity`.
+fn apply_from_modifier<C, E>(world: &mut World, entity: Entity, modifier: ValueModifier) -> bool
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    let Some(effect) = E::from_modifier(modifier) else {
+        return false;
+    };
+    world.trigger(ApplyStatusEffect { effect, entity });
+    true
+}
+
+/// Builds `E` from a modifier and applies it to `entity`'s `C` only.
+fn apply_to_component<C, E>(world: &mut World, entity: Entity, modifier: ValueModifier) -> bool
+where
+    C: MutableComponent,
+    E: StatusEffectApplicator<C>,
+{
+    with_override(world, ComponentScope::of::<C>(), |world| {
+        apply_from_modifier::<C, E>(world, entity, modifier)
+    })
+}
@@ -0,0 +1,245 @@
+//! Render world access to active effects.
+//!
+//! Enabled by the `render` feature. [`StatusEffectRenderPlugin<C>`] keeps an
+//! [`ActiveEffectsSummary<C>`] next to every [`AppliedEffects<C>`] and extracts
+//! it into the render world, so materials and shaders can react to buff state
+//! without walking the full effect list.
+
+use std::marker::PhantomData;
+
+use bevy::ecs::query::QueryItem;
+use bevy::prelude::*;
+use bevy::render::RenderApp;
+use bevy::render::extract_component::{ExtractComponent, ExtractComponentPlugin};
+
+use crate::{AppliedEffects, MutableComponent};
+
+/// Compact view of the effects tracked on component `C`.
+///
+/// Recomputed only when the entity's [`AppliedEffects<C>`] changes.
+#[derive(Component)]
+pub struct ActiveEffectsSummary<C: MutableComponent> {
+    /// Number of tracked effects
+    pub count: u32,
+    /// Effects with a positive modifier
+    pub buffs: u32,
+    /// Effects with a negative modifier
+    pub debuffs: u32,
+    /// Sum of all flat modifiers
+    pub flat: f32,
+    /// Product of all percent multipliers and factors, unscaled
+    pub multiplier: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> ActiveEffectsSummary<C> {
+    /// Summarizes the tracked effects.
+    #[must_use]
+    pub fn from_applied(applied: &AppliedEffects<C>) -> Self {
+        let mut summary = Self {
+            count: 0,
+            buffs: 0,
+            debuffs: 0,
+            flat: 0.0,
+            multiplier: 1.0,
+            _marker: PhantomData,
+        };
+        for effect in &applied.effects {
+            let modifier = effect.weighted_modifier();
+            let value = modifier.signed_points();
+            summary.count += 1;
+            if value > 0.0 {
+                summary.buffs += 1;
+            } else if value < 0.0 {
+                summary.debuffs += 1;
+            }
+            summary.flat += modifier.flat_value();
+            summary.multiplier *= (1.0 + modifier.percent_value() / 100.0).max(0.0)
+                * modifier.multiply_value().max(0.0);
+        }
+        summary
+    }
+}
+
+impl<C: MutableComponent> Clone for ActiveEffectsSummary<C> {
+    fn clone(&self) -> Self {
+        Self {
+            count: self.count,
+            buffs: self.buffs,
+            debuffs: self.debuffs,
+            flat: self.flat,
+            multiplier: self.multiplier,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: MutableComponent> PartialEq for ActiveEffectsSummary<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+            && self.buffs == other.buffs
+            && self.debuffs == other.debuffs
+            && self.flat == other.flat
+            && self.multiplier == other.multiplier
+    }
+}
+
+impl<C: MutableComponent> std::fmt::Debug for ActiveEffectsSummary<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ActiveEffectsSummary")
+            .field("count", &self.count)
+            .field("buffs", &self.buffs)
+            .field("debuffs", &self.debuffs)
+            .field("flat", &self.flat)
+            .field("multiplier", &self.multiplier)
+            .finish()
+    }
+}
+
+impl<C: MutableComponent> ExtractComponent for ActiveEffectsSummary<C> {
+    type QueryData = &'static Self;
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(item: QueryItem<'_, '_, Self::QueryData>) -> Option<Self::Out> {
+        Some(item.clone())
+    }
+}
+
+/// Recomputes summaries for entities whose tracked effects changed.
+pub fn update_active_effects_summaries<C: MutableComponent>(
+    mut commands: Commands,
+    mut q: Query<
+        (
+            Entity,
+            &AppliedEffects<C>,
+            Option<&mut ActiveEffectsSummary<C>>,
+        ),
+        Changed<AppliedEffects<C>>,
+    >,
+    mut removed: RemovedComponents<AppliedEffects<C>>,
+) {
+    for (entity, applied, summary) in &mut q {
+        let new = ActiveEffectsSummary::from_applied(applied);
+        match summary {
+            Some(mut summary) => {
+                summary.set_if_neq(new);
+            }
+            None => {
+                commands.entity(entity).insert(new);
+            }
+        }
+    }
+    for entity in removed.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<ActiveEffectsSummary<C>>();
+        }
+    }
+}
+
+/// Maintains and extracts [`ActiveEffectsSummary<C>`] for tracked component `C`.
+///
+/// Requires `C` to be [tracked](crate::StatusEffectApplication::tracked). The
+/// summary is only extracted when a render sub-app exists, so add this after
+/// bevy's render plugin.
+pub struct StatusEffectRenderPlugin<C: MutableComponent>(PhantomData<C>);
+
+impl<C: MutableComponent> Default for StatusEffectRenderPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for StatusEffectRenderPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_active_effects_summaries::<C>);
+        if app.get_sub_app(RenderApp).is_some() {
+            app.add_plugins(ExtractComponentPlugin::<ActiveEffectsSummary<C>>::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn extracted_summary_matches_source_effects() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        app.add_plugins(StatusEffectRenderPlugin::<Speed>::default());
+
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        for modifier in [
+            ValueModifier::Percent(50.0),
+            ValueModifier::Val(-20.0),
+            ValueModifier::Val(30.0),
+        ] {
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: SpeedEffect(modifier),
+                entity,
+            });
+        }
+        app.update();
+        app.update();
+
+        let world = app.world();
+        let summary = world.get::<ActiveEffectsSummary<Speed>>(entity).unwrap();
+        let extracted = ActiveEffectsSummary::<Speed>::extract_component(summary).unwrap();
+        let source = world.get::<AppliedEffects<Speed>>(entity).unwrap();
+
+        assert_eq!(extracted, ActiveEffectsSummary::from_applied(source));
+        assert_eq!(extracted.count, 3);
+        assert_eq!(extracted.buffs, 2);
+        assert_eq!(extracted.debuffs, 1);
+        assert_eq!(extracted.flat, 10.0);
+        assert_eq!(extracted.multiplier, 1.5);
+
+        // Unchanged effects leave the summary untouched
+        let before = world
+            .entity(entity)
+            .get_ref::<ActiveEffectsSummary<Speed>>()
+            .unwrap()
+            .last_changed();
+        app.update();
+        let after = app
+            .world()
+            .entity(entity)
+            .get_ref::<ActiveEffectsSummary<Speed>>()
+            .unwrap()
+            .last_changed();
+        assert_eq!(before, after);
+    }
+}
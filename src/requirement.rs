@@ -0,0 +1,105 @@
+//! Component requirements on targets.
+//!
+//! Effects returning component types from
+//! [`required_components`](crate::StatusEffectApplicator::required_components)
+//! only apply to entities that have all of them, e.g. a slow that only lands on
+//! `Grounded` enemies. Applications to other entities are rejected with
+//! [`RejectReason::MissingComponent`](crate::RejectReason::MissingComponent).
+//! The check happens when the effect is triggered: adding the component later
+//! doesn't apply it retroactively.
+
+use std::any::TypeId;
+
+use bevy::ecs::archetype::Archetypes;
+use bevy::ecs::component::Components;
+use bevy::ecs::entity::Entities;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Reads which components an entity has without borrowing them.
+#[derive(SystemParam)]
+pub(crate) struct ComponentPresence<'w> {
+    entities: &'w Entities,
+    archetypes: &'w Archetypes,
+    components: &'w Components,
+}
+
+impl ComponentPresence<'_> {
+    /// Returns true if `entity` has every component type in `required`.
+    ///
+    /// Entities that aren't spawned have none.
+    pub(crate) fn has_all(&self, entity: Entity, required: &[TypeId]) -> bool {
+        if required.is_empty() {
+            return true;
+        }
+        let Some(archetype) = self
+            .entities
+            .get_spawned(entity)
+            .ok()
+            .and_then(|location| self.archetypes.get(location.archetype_id))
+        else {
+            return false;
+        };
+        required.iter().all(|&type_id| {
+            self.components
+                .get_id(type_id)
+                .is_some_and(|id| archetype.contains(id))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    #[derive(Component)]
+    struct Grounded;
+
+    #[derive(Event, Clone, Copy)]
+    struct Slow(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Slow {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn required_components(&self) -> &[TypeId] {
+            const { &[TypeId::of::<Grounded>()] }
+        }
+    }
+
+    #[test]
+    fn only_grounded_targets_are_slowed() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Slow>::default());
+        let grounded = app.world_mut().spawn((Speed(100.0), Grounded)).id();
+        let airborne = app.world_mut().spawn(Speed(100.0)).id();
+        let missing = app.world_mut().spawn_empty().id();
+        app.update();
+
+        for entity in [grounded, airborne, missing] {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Slow(ValueModifier::Percent(-50.0)));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Speed>(grounded).unwrap().0, 50.0);
+        assert_eq!(app.world().get::<Speed>(airborne).unwrap().0, 100.0);
+        // Not auto-inserted either
+        assert!(app.world().get::<Speed>(missing).is_none());
+
+        // Landing later doesn't apply the earlier slow
+        app.world_mut().entity_mut(airborne).insert(Grounded);
+        app.update();
+        assert_eq!(app.world().get::<Speed>(airborne).unwrap().0, 100.0);
+    }
+}
@@ -0,0 +1,327 @@
+//! Save blobs of every component's scaling config and tracked effects.
+//!
+//! [`export_world_status_effects`] captures the scaling part of each
+//! registered [`StatusEffectApplication<C>`] along with every
+//! [`AppliedEffects<C>`] in the world into one serializable
+//! [`StatusEffectSave`]. [`import_world_status_effects`] restores it into a
+//! world with the same plugins, mapping saved entities to their new
+//! counterparts and rebuilding each stat from the restored effects.
+
+use std::collections::BTreeMap;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::recording::entity_bits;
+use crate::{
+    AppliedEffect, AppliedEffects, MutableComponent, PercentSum, StatusEffectApplication,
+    StatusEffectRegistry, tracking,
+};
+
+/// Scaling settings of one component's config.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScalingSave {
+    /// Power scaling for effect application
+    pub power: f32,
+    /// Inclusive bounds applied values are clamped to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<(f32, f32)>,
+    /// Round applied values to the nearest integer
+    #[serde(default)]
+    pub round: bool,
+}
+
+/// Tracked effects on one entity.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TrackerSave {
+    /// Entity the effects were on, as seen in the saving world
+    #[serde(with = "entity_bits")]
+    pub entity: Entity,
+    /// Stat value before the first tracked effect was applied
+    pub base: f32,
+    /// Tracked effects in application order
+    pub effects: Vec<AppliedEffect>,
+}
+
+/// Config and tracked effects of one component type.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComponentSave {
+    /// Scaling settings of the component's config
+    pub config: ScalingSave,
+    /// Tracked effects per entity, ordered by entity index
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trackers: Vec<TrackerSave>,
+}
+
+/// Serializable snapshot of all status effect state in a world.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffectSave {
+    /// Saved state keyed by component type name
+    pub components: BTreeMap<String, ComponentSave>,
+}
+
+/// Type-erased export and import of one component type.
+#[derive(Clone, Copy)]
+pub(crate) struct SaveFns {
+    pub export: fn(&World) -> Option<ComponentSave>,
+    pub import: fn(&mut World, &ComponentSave, &EntityHashMap<Entity>),
+}
+
+impl SaveFns {
+    pub fn of<C: MutableComponent>() -> Self {
+        Self {
+            export: export_component::<C>,
+            import: import_component::<C>,
+        }
+    }
+}
+
+/// Captures every registered component's scaling config and tracked effects.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::ecs::entity::EntityHashMap;
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+/// use msg_status_effect::{export_world_status_effects, import_world_status_effects};
+///
+/// fn reload(saved: &mut World, fresh: &mut World, entity_map: &EntityHashMap<Entity>) {
+///     let save = export_world_status_effects(saved);
+///     import_world_status_effects(fresh, &save, entity_map);
+/// }
+/// ```
+#[must_use]
+pub fn export_world_status_effects(world: &World) -> StatusEffectSave {
+    let Some(registry) = world.get_resource::<StatusEffectRegistry>() else {
+        return StatusEffectSave::default();
+    };
+    let components = registry
+        .saves()
+        .filter_map(|(name, save)| Some((name.to_string(), (save.export)(world)?)))
+        .collect();
+    StatusEffectSave { components }
+}
+
+/// Restores a save into `world`.
+///
+/// `entity_map` maps entities from the saving world to their counterparts in
+/// `world`. Each component's config takes the saved scaling, and mapped
+/// entities get their tracked effects back with the stat rebuilt from them.
+/// Components that aren't registered in `world`, unmapped entities and
+/// entities without the component are skipped with a warning.
+pub fn import_world_status_effects(
+    world: &mut World,
+    save: &StatusEffectSave,
+    entity_map: &EntityHashMap<Entity>,
+) {
+    for (name, component) in &save.components {
+        let import = world
+            .get_resource::<StatusEffectRegistry>()
+            .and_then(|registry| registry.save_fns(name));
+        strict_assert!(import.is_some(), "Saved component {name} is not registered");
+        let Some(save) = import else {
+            log_warn!("Skipping import of {name}: component is not registered");
+            continue;
+        };
+        (save.import)(world, component, entity_map);
+    }
+}
+
+fn export_component<C: MutableComponent>(world: &World) -> Option<ComponentSave> {
+    let config = world.get_resource::<StatusEffectApplication<C>>()?;
+    let mut trackers: Vec<TrackerSave> = world
+        .try_query::<(Entity, &AppliedEffects<C>)>()
+        .map(|mut query| {
+            query
+                .iter(world)
+                .map(|(entity, applied)| TrackerSave {
+                    entity,
+                    base: applied.base,
+                    effects: applied.effects.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    trackers.sort_by_key(|tracker| tracker.entity.index());
+    Some(ComponentSave {
+        config: ScalingSave {
+            power: config.power,
+            bounds: config.bounds,
+            round: config.round,
+        },
+        trackers,
+    })
+}
+
+fn import_component<C: MutableComponent>(
+    world: &mut World,
+    save: &ComponentSave,
+    entity_map: &EntityHashMap<Entity>,
+) {
+    let Some(mut config) = world.get_resource_mut::<StatusEffectApplication<C>>() else {
+        return;
+    };
+    config.power = save.config.power;
+    config.bounds = save.config.bounds;
+    config.round = save.config.round;
+    let config = config.clone();
+
+    for tracker in &save.trackers {
+        let Some(&entity) = entity_map.get(&tracker.entity) else {
+            log_warn!(
+                "Skipping import of {} effects: unmapped entity {}",
+                std::any::type_name::<C>(),
+                tracker.entity
+            );
+            continue;
+        };
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            log_warn!(
+                "Skipping import of {} effects: {entity} doesn't exist",
+                std::any::type_name::<C>()
+            );
+            continue;
+        };
+        let mut applied = AppliedEffects::<C>::new(tracker.base);
+        applied.effects.clone_from(&tracker.effects);
+        let Some(mut component) = entity_mut.get_mut::<C>() else {
+            log_warn!(
+                "Skipping import of {} effects: {entity} doesn't have the component",
+                std::any::type_name::<C>()
+            );
+            continue;
+        };
+        let mut sum = PercentSum::<C>::default();
+        if tracking::rebuild(
+            &config,
+            component.bypass_change_detection(),
+            &applied,
+            Some(&mut sum),
+        ) {
+            component.set_changed();
+        }
+        entity_mut.insert(applied);
+        if config.uses_percent_sum() {
+            entity_mut.insert(sum);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    impl StatValue for Health {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    impl StatValue for Armor {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Vigor(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Vigor {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Harden(ValueModifier);
+
+    impl StatusEffectApplicator<Armor> for Harden {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app(armor: StatusEffectApplication<Armor>) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Vigor>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, Harden>::new(armor.tracked()));
+        app
+    }
+
+    #[test]
+    fn round_trip_restores_configs_and_effects() {
+        let mut saved = app(StatusEffectApplication::sqrt().clamped(0.0, 500.0));
+        let knight = saved.world_mut().spawn((Health(100.0), Armor(100.0))).id();
+        let mage = saved.world_mut().spawn((Health(60.0), Armor(20.0))).id();
+        let mut commands = saved.world_mut().commands();
+        commands
+            .apply_effect(knight, Vigor(ValueModifier::Val(50.0)))
+            .apply_effect(knight, Vigor(ValueModifier::Percent(10.0)))
+            .apply_effect(knight, Harden(ValueModifier::Val(44.0)))
+            .apply_effect(mage, Vigor(ValueModifier::Val(-10.0)));
+        saved.update();
+
+        let json = serde_json::to_string(&export_world_status_effects(saved.world())).unwrap();
+        let save: StatusEffectSave = serde_json::from_str(&json).unwrap();
+        assert_eq!(save.components.len(), 2);
+
+        // Fresh world, linear config, entities spawned in a different order
+        let mut loaded = app(StatusEffectApplication::linear());
+        loaded.world_mut().spawn(Health(1.0));
+        let new_mage = loaded.world_mut().spawn((Health(60.0), Armor(20.0))).id();
+        let new_knight = loaded.world_mut().spawn((Health(100.0), Armor(100.0))).id();
+        let entity_map = EntityHashMap::from_iter([(knight, new_knight), (mage, new_mage)]);
+        import_world_status_effects(loaded.world_mut(), &save, &entity_map);
+
+        let world = loaded.world();
+        assert_eq!(
+            world.resource::<StatusEffectApplication<Armor>>().power,
+            0.5
+        );
+        for (old, new) in [(knight, new_knight), (mage, new_mage)] {
+            let saved = saved.world();
+            assert_eq!(
+                world.get::<Health>(new).unwrap().0,
+                saved.get::<Health>(old).unwrap().0
+            );
+            assert_eq!(
+                world.get::<AppliedEffects<Health>>(new).unwrap().effects,
+                saved.get::<AppliedEffects<Health>>(old).unwrap().effects
+            );
+        }
+        assert_eq!(world.get::<Health>(new_knight).unwrap().0, 165.0);
+        assert_eq!(
+            world.get::<Armor>(new_knight).unwrap().0,
+            saved.world().get::<Armor>(knight).unwrap().0
+        );
+        assert!(world.get::<AppliedEffects<Armor>>(new_mage).is_none());
+    }
+}
@@ -0,0 +1,327 @@
+//! Meta-buffs that amplify incoming effects.
+//!
+//! While an entity has [`ScalingMultiplier<C>`], every new effect targeting
+//! `C` on it is [scaled](crate::StatusEffectApplicator::scaled_by) by the
+//! multiplier, e.g. "your next 3 buffs are 50% stronger". Effects applied
+//! before it keep their values. Multipliers are applied with
+//! [`ScalingModifierEffect<C>`] and last for a number of applications, a
+//! duration, or until removed. Both the event and timed multipliers need
+//! `C`'s config to be [`amplifiable`](crate::StatusEffectApplication::amplifiable).
+//!
+//! A charge is only spent when the multiplier rebuilt the effect and the
+//! application went through. Effects without
+//! [`from_modifier`](crate::StatusEffectApplicator::from_modifier), and ones
+//! clamping cut to nothing, apply unscaled and leave the charges alone.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::MutableComponent;
+
+/// Scales new effects targeting component `C` on this entity.
+///
+/// Insert it directly or trigger [`ScalingModifierEffect<C>`]. Applying a
+/// new one replaces the old.
+#[derive(Component)]
+pub struct ScalingMultiplier<C: MutableComponent> {
+    /// Factor incoming effects are scaled by
+    pub multiplier: f32,
+    charges: Option<u32>,
+    timer: Option<Timer>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> ScalingMultiplier<C> {
+    /// Scales every effect on `C` until the marker is removed.
+    #[must_use]
+    pub fn new(multiplier: f32) -> Self {
+        Self {
+            multiplier,
+            charges: None,
+            timer: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Scales the next `count` effects on `C`.
+    #[must_use]
+    pub fn for_next(multiplier: f32, count: u32) -> Self {
+        Self {
+            charges: Some(count),
+            ..Self::new(multiplier)
+        }
+    }
+
+    /// Scales effects on `C` for the given number of seconds.
+    #[must_use]
+    pub fn for_secs(multiplier: f32, secs: f32) -> Self {
+        Self {
+            timer: Some(Timer::from_seconds(secs, TimerMode::Once)),
+            ..Self::new(multiplier)
+        }
+    }
+
+    /// Returns the applications left, or `None` if not limited by count.
+    #[must_use]
+    pub fn charges(&self) -> Option<u32> {
+        self.charges
+    }
+
+    /// Returns the seconds left, or `None` if not limited by time.
+    #[must_use]
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.timer.as_ref().map(Timer::remaining_secs)
+    }
+
+    /// Returns the multiplier if any applications are left.
+    pub(crate) fn factor(&self) -> Option<f32> {
+        (!self.is_spent()).then_some(self.multiplier)
+    }
+
+    /// Uses up one application.
+    pub(crate) fn consume(&mut self) {
+        if let Some(charges) = &mut self.charges {
+            *charges = charges.saturating_sub(1);
+        }
+    }
+
+    /// Returns true once every charge is used up.
+    pub(crate) fn is_spent(&self) -> bool {
+        self.charges == Some(0)
+    }
+}
+
+/// Amplifies new effects targeting component `C` on an entity.
+///
+/// Needs `C`'s config to be
+/// [`amplifiable`](crate::StatusEffectApplication::amplifiable).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Attack(f32);
+///
+/// fn empower(mut commands: Commands, entity: Entity) {
+///     // The next 3 attack buffs are 50% stronger
+///     commands.trigger(ScalingModifierEffect::<Attack>::for_next(entity, 1.5, 3));
+/// }
+/// ```
+#[derive(EntityEvent)]
+pub struct ScalingModifierEffect<C: MutableComponent> {
+    /// The entity to amplify
+    pub entity: Entity,
+    /// Factor incoming effects are scaled by
+    pub multiplier: f32,
+    /// Number of applications to amplify, or `None` for no limit
+    pub charges: Option<u32>,
+    /// Duration in seconds, or `None` for no limit
+    pub duration: Option<f32>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> ScalingModifierEffect<C> {
+    /// Amplifies effects on `entity` until [`ScalingMultiplier<C>`] is removed.
+    #[must_use]
+    pub fn new(entity: Entity, multiplier: f32) -> Self {
+        Self {
+            entity,
+            multiplier,
+            charges: None,
+            duration: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Amplifies the next `count` effects on `entity`.
+    #[must_use]
+    pub fn for_next(entity: Entity, multiplier: f32, count: u32) -> Self {
+        Self {
+            charges: Some(count),
+            ..Self::new(entity, multiplier)
+        }
+    }
+
+    /// Amplifies effects on `entity` for the given number of seconds.
+    #[must_use]
+    pub fn for_secs(entity: Entity, multiplier: f32, secs: f32) -> Self {
+        Self {
+            duration: Some(secs),
+            ..Self::new(entity, multiplier)
+        }
+    }
+}
+
+fn scaling_modifier_observer<C: MutableComponent>(
+    on: On<ScalingModifierEffect<C>>,
+    mut commands: Commands,
+) {
+    let multiplier = ScalingMultiplier::<C> {
+        charges: on.charges,
+        timer: on
+            .duration
+            .map(|secs| Timer::from_seconds(secs, TimerMode::Once)),
+        ..ScalingMultiplier::new(on.multiplier)
+    };
+    if let Ok(mut entity) = commands.get_entity(on.entity) {
+        entity.insert(multiplier);
+    }
+}
+
+/// Removes multipliers whose duration has run out.
+fn tick_scaling_multipliers<C: MutableComponent>(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut ScalingMultiplier<C>)>,
+) {
+    for (entity, mut multiplier) in &mut q {
+        let Some(timer) = multiplier.timer.as_mut() else {
+            continue;
+        };
+        if timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<ScalingMultiplier<C>>();
+        }
+    }
+}
+
+/// Registers the multiplier observer and timer for component `C` once per app.
+pub(crate) struct ScalingModifierPlugin<C: MutableComponent>(PhantomData<C>);
+
+impl<C: MutableComponent> Default for ScalingModifierPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for ScalingModifierPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(scaling_modifier_observer::<C>);
+        app.add_systems(Update, tick_scaling_multipliers::<C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Attack(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Empower(ValueModifier);
+
+    impl StatusEffectApplicator<Attack> for Empower {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Attack, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+    }
+
+    fn setup() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Attack, Empower>::new(
+            StatusEffectApplication::linear().amplifiable(),
+        ));
+        let entity = app.world_mut().spawn(Attack(100.0)).id();
+        (app, entity)
+    }
+
+    fn empower(app: &mut App, entity: Entity, amount: f32) -> f32 {
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Empower(ValueModifier::Val(amount)));
+        app.update();
+        app.world().get::<Attack>(entity).unwrap().0
+    }
+
+    #[test]
+    fn amplifies_next_buff_then_expires() {
+        let (mut app, entity) = setup();
+        assert_eq!(empower(&mut app, entity, 10.0), 110.0);
+
+        app.world_mut()
+            .trigger(ScalingModifierEffect::<Attack>::for_next(entity, 1.5, 1));
+        app.world_mut().flush();
+        // Already applied buffs are untouched
+        assert_eq!(app.world().get::<Attack>(entity).unwrap().0, 110.0);
+
+        assert_eq!(empower(&mut app, entity, 10.0), 125.0);
+        assert!(
+            app.world()
+                .get::<ScalingMultiplier<Attack>>(entity)
+                .is_none()
+        );
+        assert_eq!(empower(&mut app, entity, 10.0), 135.0);
+    }
+
+    #[test]
+    fn timed_multiplier_expires() {
+        let (mut app, entity) = setup();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            500,
+        )));
+        app.world_mut()
+            .trigger(ScalingModifierEffect::<Attack>::for_secs(entity, 2.0, 1.0));
+        app.update();
+
+        assert_eq!(empower(&mut app, entity, 10.0), 120.0);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(
+            app.world()
+                .get::<ScalingMultiplier<Attack>>(entity)
+                .is_none()
+        );
+        assert_eq!(empower(&mut app, entity, 10.0), 130.0);
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Rally(f32);
+
+    impl StatusEffectApplicator<Attack> for Rally {
+        fn modifier(&self) -> ValueModifier {
+            ValueModifier::Val(self.0)
+        }
+
+        fn apply(&self, component: &mut Attack, _power: f32) {
+            component.0 += self.0;
+        }
+    }
+
+    #[test]
+    fn effects_that_cant_be_rebuilt_keep_the_charge() {
+        let (mut app, entity) = setup();
+        app.add_plugins(StatusEffectPlugin::<Attack, Rally>::new(
+            StatusEffectApplication::linear().amplifiable(),
+        ));
+        app.world_mut()
+            .trigger(ScalingModifierEffect::<Attack>::for_next(entity, 1.5, 1));
+        app.world_mut().flush();
+
+        app.world_mut().commands().apply_effect(entity, Rally(10.0));
+        app.update();
+        assert_eq!(app.world().get::<Attack>(entity).unwrap().0, 110.0);
+        let multiplier = app.world().get::<ScalingMultiplier<Attack>>(entity);
+        assert_eq!(multiplier.and_then(ScalingMultiplier::charges), Some(1));
+
+        assert_eq!(empower(&mut app, entity, 10.0), 125.0);
+    }
+}
@@ -0,0 +1,174 @@
+//! Temporary scaling for a block of applications.
+//!
+//! [`with_scaling`] applies effects on a component at a given power for the
+//! duration of a closure, like
+//! [`apply_effect_scaled`](crate::StatusEffectCommandsExt::apply_effect_scaled)
+//! does for a single effect. The configured power is never touched, and the
+//! scope ends even if the closure panics. Scopes nest: the innermost one
+//! decides the power, and each restores the one active when it started.
+
+use bevy::prelude::*;
+
+use crate::{MutableComponent, PowerOverride, with_override};
+
+/// Runs `f` with effects on `C` applied at `power`.
+///
+/// The power takes precedence over
+/// [`kind_powers`](crate::StatusEffectApplicator::kind_powers) and
+/// [sign powers](crate::StatusEffectApplication::with_sign_powers),
+/// and is recorded on [tracked](crate::StatusEffectApplication::tracked)
+/// entries, so rebuilds keep it. Deferred effects queued inside the scope
+/// drain at it too. Effects on other components are unaffected, but an inner
+/// scope for another component ends this one's power until it returns.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Haste(ValueModifier);
+///
+/// fn cheat_buffs(world: &mut World, player: Entity) {
+///     with_scaling::<Speed, _>(world, scaling::LINEAR, |world| {
+///         world.commands().apply_effect(player, Haste(ValueModifier::Percent(50.0)));
+///         world.flush();
+///     });
+/// }
+/// ```
+pub fn with_scaling<C, R>(world: &mut World, power: f32, f: impl FnOnce(&mut World) -> R) -> R
+where
+    C: MutableComponent,
+{
+    with_override(world, PowerOverride::of::<C>(power), f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Slow(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Slow {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn haste(world: &mut World, entity: Entity, percent: f32) {
+        world
+            .commands()
+            .apply_effect(entity, Haste(ValueModifier::Percent(percent)));
+        world.flush();
+    }
+
+    fn speed(world: &World, entity: Entity) -> f32 {
+        world.get::<Speed>(entity).unwrap().0
+    }
+
+    #[test]
+    fn scope_applies_at_power_and_restores() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::sqrt(),
+        ));
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        let world = app.world_mut();
+
+        with_scaling::<Speed, _>(world, scaling::LINEAR, |world| {
+            haste(world, entity, 50.0);
+            assert_eq!(speed(world, entity), 150.0);
+            // The inner scope decides until it returns
+            with_scaling::<Speed, _>(world, scaling::SQUARE, |world| {
+                haste(world, entity, 10.0);
+            });
+            let squared = ValueModifier::Percent(10.0).apply_scaled(150.0, scaling::SQUARE);
+            assert_eq!(speed(world, entity), squared);
+            haste(world, entity, 10.0);
+            assert_eq!(speed(world, entity), squared * 1.1);
+        });
+        assert_eq!(
+            world.resource::<StatusEffectApplication<Speed>>().power,
+            scaling::SQRT
+        );
+
+        // Panics still end the scope
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            with_scaling::<Speed, ()>(world, scaling::LINEAR, |_| panic!("inside scope"));
+        }));
+        assert!(result.is_err());
+        world.entity_mut(entity).insert(Speed(100.0));
+        haste(world, entity, 50.0);
+        assert_eq!(
+            speed(world, entity),
+            ValueModifier::Percent(50.0).apply_scaled(100.0, scaling::SQRT)
+        );
+    }
+
+    #[test]
+    fn scoped_power_survives_rebuild() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Speed, Slow>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        let entity = app.world_mut().spawn(Speed(40.0)).id();
+        let world = app.world_mut();
+
+        with_scaling::<Speed, _>(world, scaling::LINEAR, |world| {
+            haste(world, entity, 50.0);
+        });
+        assert_eq!(speed(world, entity), 60.0);
+        world
+            .commands()
+            .apply_effect(entity, Slow(ValueModifier::Val(-10.0)));
+        world.flush();
+
+        // Removing the slow replays the haste at the scope's power
+        world
+            .commands()
+            .trigger(RemoveStatusEffect::<Slow>::new(entity));
+        world.flush();
+        assert_eq!(speed(world, entity), 60.0);
+    }
+}
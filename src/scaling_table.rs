@@ -0,0 +1,189 @@
+//! Precomputed scaling results for hot paths.
+//!
+//! [`ScalingTable<C>`] caches [`apply_scaled`](crate::ValueModifier::apply_scaled)
+//! for a known set of current values and modifiers, e.g. integer stats and a
+//! handful of fixed percents, so lookups skip `powf`. Inputs outside the table
+//! are computed live.
+
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::{MutableComponent, StatusEffectApplication, ValueModifier, value_bits};
+
+/// A current value and modifier, keyed by their bits.
+#[derive(Clone, Copy)]
+struct Input {
+    current: f32,
+    modifier: ValueModifier,
+}
+
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        value_bits(self.current) == value_bits(other.current)
+            && self.modifier.same_effect(&other.modifier)
+    }
+}
+
+impl Eq for Input {}
+
+impl Hash for Input {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        value_bits(self.current).hash(state);
+        self.modifier.hash_bits(state);
+    }
+}
+
+/// `apply_scaled` results for component `C`, precomputed at its power.
+///
+/// Built from the component's config with
+/// [`from_config`](Self::from_config), then filled with
+/// [`with_inputs`](Self::with_inputs). Only the single power is covered:
+/// piecewise and custom curves, per-kind powers and clamping aren't applied.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+/// use msg_status_effect::ScalingTable;
+///
+/// #[derive(Component)]
+/// struct Armor(f32);
+///
+/// let config = StatusEffectApplication::<Armor>::sqrt();
+/// let table = ScalingTable::from_config(&config).with_inputs(
+///     (0..=100).map(|armor| armor as f32),
+///     [ValueModifier::Val(5.0), ValueModifier::Percent(-10.0)],
+/// );
+///
+/// // Precomputed
+/// assert!(table.lookup(40.0, ValueModifier::Val(5.0)).is_some());
+/// // Computed live
+/// let live = ValueModifier::Val(5.0).apply_scaled(40.5, config.power);
+/// assert_eq!(table.apply(40.5, ValueModifier::Val(5.0)), live);
+/// ```
+#[derive(Resource)]
+pub struct ScalingTable<C: MutableComponent> {
+    power: f32,
+    results: HashMap<Input, f32>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> ScalingTable<C> {
+    /// Creates an empty table at `config`'s power.
+    #[must_use]
+    pub fn from_config(config: &StatusEffectApplication<C>) -> Self {
+        Self {
+            power: config.power,
+            results: HashMap::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Precomputes every combination of `currents` and `modifiers`.
+    ///
+    /// NaN inputs can't be looked up and are skipped.
+    #[must_use]
+    pub fn with_inputs(
+        mut self,
+        currents: impl IntoIterator<Item = f32>,
+        modifiers: impl IntoIterator<Item = ValueModifier>,
+    ) -> Self {
+        let modifiers: Vec<_> = modifiers
+            .into_iter()
+            .filter(|modifier| modifier.same_effect(modifier))
+            .collect();
+        for current in currents.into_iter().filter(|current| !current.is_nan()) {
+            for &modifier in &modifiers {
+                self.results.insert(
+                    Input { current, modifier },
+                    modifier.apply_scaled(current, self.power),
+                );
+            }
+        }
+        self
+    }
+
+    /// Returns the power the table was computed at.
+    #[must_use]
+    pub fn power(&self) -> f32 {
+        self.power
+    }
+
+    /// Returns the number of precomputed results.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns true if nothing was precomputed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Returns the precomputed result for `modifier` on `current`, if any.
+    #[must_use]
+    pub fn lookup(&self, current: f32, modifier: ValueModifier) -> Option<f32> {
+        self.results.get(&Input { current, modifier }).copied()
+    }
+
+    /// Applies `modifier` to `current` at the table's power, from the table if
+    /// precomputed and live otherwise.
+    #[must_use]
+    pub fn apply(&self, current: f32, modifier: ValueModifier) -> f32 {
+        self.lookup(current, modifier)
+            .unwrap_or_else(|| modifier.apply_scaled(current, self.power))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Armor;
+
+    #[test]
+    fn table_path_skips_live_computation() {
+        let config = StatusEffectApplication::<Armor>::sqrt();
+        let modifiers = [
+            ValueModifier::Val(5.0),
+            ValueModifier::Val(-5.0),
+            ValueModifier::Percent(25.0),
+        ];
+        let table =
+            ScalingTable::from_config(&config).with_inputs((0..1000).map(|v| v as f32), modifiers);
+        assert_eq!(table.len(), 3000);
+
+        // Every table input is answered without calling apply_scaled, and
+        // matches it bit for bit
+        for current in (0..1000).map(|v| v as f32) {
+            for modifier in modifiers {
+                let cached = table.lookup(current, modifier).unwrap();
+                let live = modifier.apply_scaled(current, config.power);
+                assert_eq!(cached.to_bits(), live.to_bits());
+            }
+        }
+
+        // Outside the table, apply falls back to the live result
+        assert_eq!(table.lookup(0.5, modifiers[0]), None);
+        assert_eq!(table.lookup(10.0, ValueModifier::Val(6.0)), None);
+        assert_eq!(
+            table.apply(0.5, modifiers[0]),
+            modifiers[0].apply_scaled(0.5, config.power)
+        );
+    }
+
+    #[test]
+    fn negative_zero_shares_an_entry_with_zero() {
+        let config = StatusEffectApplication::<Armor>::linear();
+        let table = ScalingTable::from_config(&config)
+            .with_inputs([0.0, f32::NAN], [ValueModifier::Val(1.0)]);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.lookup(-0.0, ValueModifier::Val(1.0)), Some(1.0));
+    }
+}
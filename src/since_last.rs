@@ -0,0 +1,114 @@
+//! Effects scaled by the time since their last application.
+//!
+//! Components configured with
+//! [`StatusEffectApplication::since_last`](crate::StatusEffectApplication::since_last)
+//! keep the time each effect type was last applied to an entity in a
+//! [`LastAppliedTime<E>`] component. The elapsed seconds are passed to
+//! [`StatusEffectApplicator::modifier_since_last`](crate::StatusEffectApplicator::modifier_since_last),
+//! so e.g. a heal can ramp up the longer it's been since the previous one.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+/// Time at which effect type `E` was last applied to this entity.
+#[derive(Component)]
+pub struct LastAppliedTime<E: Send + Sync + 'static> {
+    secs: Option<f64>,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Send + Sync + 'static> LastAppliedTime<E> {
+    /// Returns the elapsed app time of the last application, in seconds, if any.
+    #[must_use]
+    pub fn secs(&self) -> Option<f64> {
+        self.secs
+    }
+
+    /// Records an application at `now` and returns the seconds since the
+    /// previous one, or `None` for the first.
+    pub(crate) fn mark(&mut self, now: f64) -> Option<f32> {
+        let elapsed = self.secs.map(|secs| (now - secs) as f32);
+        self.secs = Some(now);
+        elapsed
+    }
+}
+
+impl<E: Send + Sync + 'static> Default for LastAppliedTime<E> {
+    fn default() -> Self {
+        Self {
+            secs: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    /// Heals 10 per second since the last heal, 10 the first time.
+    #[derive(Event, Clone, Copy)]
+    struct RampingHeal(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for RampingHeal {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn from_modifier(modifier: ValueModifier) -> Option<Self> {
+            Some(Self(modifier))
+        }
+
+        fn modifier_since_last(&self, elapsed: Option<f32>) -> ValueModifier {
+            self.0.scaled_by(elapsed.unwrap_or(1.0))
+        }
+    }
+
+    #[test]
+    fn second_application_scales_by_gap() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, RampingHeal>::new(
+            StatusEffectApplication::linear().since_last(),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+
+        let heal = |app: &mut App| {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, RampingHeal(ValueModifier::Val(10.0)));
+            app.world_mut().flush();
+            app.update();
+        };
+
+        // First application: baseline of one second
+        heal(&mut app);
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 110.0);
+
+        // 2 seconds later: twice the heal
+        for _ in 0..7 {
+            app.update();
+        }
+        heal(&mut app);
+        assert_eq!(app.world().get::<Health>(entity).unwrap().0, 130.0);
+        let last = app.world().get::<LastAppliedTime<RampingHeal>>(entity);
+        assert!(last.unwrap().secs().is_some());
+    }
+}
@@ -0,0 +1,165 @@
+//! Bounded effect slots with priority eviction.
+//!
+//! With [`EffectSlots<C>`] on an entity, at most `capacity` tracked effects
+//! can sit on its component `C`, across all effect types. When the slots are
+//! full, a new effect evicts the lowest-priority one, the oldest among equals,
+//! as long as its own [`priority`](crate::StatusEffectApplicator::priority) is
+//! at least as high. Otherwise it's
+//! [rejected](crate::RejectReason::SlotsFull).
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{AppliedEffects, MutableComponent};
+
+/// Limits the tracked effects on component `C` to `capacity` slots.
+///
+/// Needs a [tracked](crate::StatusEffectApplication::tracked) component.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Armor(f32);
+///
+/// fn spawn_boss(mut commands: Commands) {
+///     // Five debuff slots
+///     commands.spawn((Armor(50.0), EffectSlots::<Armor>::new(5)));
+/// }
+/// ```
+#[derive(Component)]
+pub struct EffectSlots<C: MutableComponent> {
+    /// Maximum number of tracked effects
+    pub capacity: usize,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> EffectSlots<C> {
+    /// Allows `capacity` tracked effects.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decides where an effect with `priority` goes.
+    pub(crate) fn check(&self, applied: Option<&AppliedEffects<C>>, priority: i32) -> SlotCheck {
+        let len = applied.map_or(0, AppliedEffects::len);
+        if len < self.capacity {
+            return SlotCheck::Free;
+        }
+        let lowest = applied.and_then(|applied| {
+            applied
+                .effects
+                .iter()
+                .enumerate()
+                .min_by_key(|(index, effect)| (effect.priority, *index))
+        });
+        match lowest {
+            Some((index, effect)) if effect.priority <= priority => SlotCheck::Evict(index),
+            _ => SlotCheck::Full,
+        }
+    }
+}
+
+/// Outcome of [`EffectSlots::check`].
+pub(crate) enum SlotCheck {
+    /// A slot is free
+    Free,
+    /// The effect at this index makes room
+    Evict(usize),
+    /// Every slot holds a higher-priority effect
+    Full,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Armor(f32);
+
+    impl StatValue for Armor {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    /// Armor debuff with a priority
+    #[derive(Event, Clone, Copy)]
+    struct Sunder(ValueModifier, i32);
+
+    impl StatusEffectApplicator<Armor> for Sunder {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Armor, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn priority(&self) -> i32 {
+            self.1
+        }
+    }
+
+    fn sunder(app: &mut App, entity: Entity, amount: f32, priority: i32) {
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Sunder(ValueModifier::Val(-amount), priority));
+        app.update();
+    }
+
+    fn priorities(app: &App, entity: Entity) -> Vec<i32> {
+        let applied = app.world().get::<AppliedEffects<Armor>>(entity).unwrap();
+        applied
+            .effects
+            .iter()
+            .map(|effect| effect.priority)
+            .collect()
+    }
+
+    #[test]
+    fn full_slots_evict_lowest_priority() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Armor, Sunder>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let entity = app
+            .world_mut()
+            .spawn((Armor(100.0), EffectSlots::<Armor>::new(5)))
+            .id();
+
+        for (amount, priority) in [(1.0, 3), (2.0, 1), (3.0, 2), (4.0, 1), (5.0, 4)] {
+            sunder(&mut app, entity, amount, priority);
+        }
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 85.0);
+
+        // Evicts the older of the two priority 1 debuffs
+        sunder(&mut app, entity, 10.0, 5);
+        assert_eq!(priorities(&app, entity), [3, 2, 1, 4, 5]);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 77.0);
+
+        // Lower than everything: rejected
+        sunder(&mut app, entity, 10.0, 0);
+        assert_eq!(priorities(&app, entity), [3, 2, 1, 4, 5]);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 77.0);
+
+        // Equal priority evicts the oldest
+        sunder(&mut app, entity, 20.0, 1);
+        assert_eq!(priorities(&app, entity), [3, 2, 4, 5, 1]);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 61.0);
+    }
+}
@@ -0,0 +1,309 @@
+//! Before/after snapshots of an entity's stats.
+//!
+//! [`snapshot_stats`] captures every stat-bearing component on an entity,
+//! i.e. components whose plugin was configured
+//! [`with_stat_value`](crate::StatusEffectApplication::with_stat_value) or
+//! [`tracked`](crate::StatusEffectApplication::tracked). [`diff_stats`]
+//! compares two snapshots, e.g. for "equipping this changes speed +20" tooltips.
+//! [`collect_effective`] reads one stat across every entity, e.g. for roster
+//! screens.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    AppliedEffects, MutableComponent, StatValue, StatusEffectApplication, StatusEffectRegistry,
+};
+
+/// Stat values of one entity at a point in time, keyed by component type name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatSnapshot {
+    stats: BTreeMap<&'static str, f32>,
+}
+
+impl StatSnapshot {
+    /// Returns the captured value of stat component `C`.
+    #[must_use]
+    pub fn get<C: Component>(&self) -> Option<f32> {
+        self.stats.get(std::any::type_name::<C>()).copied()
+    }
+
+    /// Iterates the captured stats in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, f32)> + '_ {
+        self.stats.iter().map(|(&name, &value)| (name, value))
+    }
+
+    /// Returns the number of captured stats.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stats.len()
+    }
+
+    /// Returns true if no stats were captured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stats.is_empty()
+    }
+}
+
+/// Change of one stat between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatDelta {
+    /// Type name of the stat component
+    pub stat: &'static str,
+    /// Value in the first snapshot, `None` if the component was added since
+    pub before: Option<f32>,
+    /// Value in the second snapshot, `None` if the component was removed since
+    pub after: Option<f32>,
+}
+
+impl StatDelta {
+    /// Returns `after - before`, counting a missing side as zero.
+    #[must_use]
+    pub fn delta(&self) -> f32 {
+        self.after.unwrap_or(0.0) - self.before.unwrap_or(0.0)
+    }
+
+    /// Returns true if this delta is for stat component `C`.
+    #[must_use]
+    pub fn is<C: Component>(&self) -> bool {
+        self.stat == std::any::type_name::<C>()
+    }
+}
+
+/// Captures every registered stat component on `entity`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn preview(world: &mut World, entity: Entity, apply_gear: impl FnOnce(&mut World)) {
+///     let before = snapshot_stats(world, entity);
+///     apply_gear(world);
+///     let after = snapshot_stats(world, entity);
+///     for change in diff_stats(&before, &after) {
+///         info!("{}: {:+}", change.stat, change.delta());
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn snapshot_stats(world: &World, entity: Entity) -> StatSnapshot {
+    let Some(registry) = world.get_resource::<StatusEffectRegistry>() else {
+        return StatSnapshot::default();
+    };
+    StatSnapshot {
+        stats: registry
+            .stats()
+            .filter_map(|(name, read)| read(world, entity).map(|value| (name, value)))
+            .collect(),
+    }
+}
+
+/// Returns the stats that changed, appeared or disappeared between two
+/// snapshots, in name order.
+#[must_use]
+pub fn diff_stats(before: &StatSnapshot, after: &StatSnapshot) -> Vec<StatDelta> {
+    let mut names: Vec<&'static str> = before
+        .stats
+        .keys()
+        .chain(after.stats.keys())
+        .copied()
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .map(|stat| StatDelta {
+            stat,
+            before: before.stats.get(stat).copied(),
+            after: after.stats.get(stat).copied(),
+        })
+        .filter(|delta| delta.before != delta.after)
+        .collect()
+}
+
+/// Returns the effective value of stat `C` for every entity with it, in
+/// entity index order.
+///
+/// Tracked entities report their effects replayed on the base, at the
+/// component's power and through its clamp and rounding. Entities without
+/// tracked effects report their raw component value. All of it is one query
+/// pass.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// impl StatValue for Speed {
+///     fn stat_value(&self) -> f32 { self.0 }
+///     fn set_stat_value(&mut self, value: f32) { self.0 = value; }
+/// }
+///
+/// fn roster(world: &mut World) {
+///     for (entity, speed) in collect_effective::<Speed>(world) {
+///         info!("{entity}: {speed:.0} speed");
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn collect_effective<C: MutableComponent + StatValue>(world: &mut World) -> Vec<(Entity, f32)> {
+    let config = world.get_resource::<StatusEffectApplication<C>>().cloned();
+    let mut query = world.query::<(Entity, &C, Option<&AppliedEffects<C>>)>();
+    let mut values: Vec<(Entity, f32)> = query
+        .iter(world)
+        .map(|(entity, component, applied)| {
+            let value = match (applied, &config) {
+                (Some(applied), Some(config)) if !applied.is_empty() => config.replay(applied),
+                _ => component.stat_value(),
+            };
+            (entity, value)
+        })
+        .collect();
+    values.sort_unstable_by_key(|&(entity, _)| entity.index());
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    macro_rules! stat {
+        ($name:ident, $effect:ident) => {
+            #[derive(Component, Default)]
+            struct $name(f32);
+
+            impl StatValue for $name {
+                fn stat_value(&self) -> f32 {
+                    self.0
+                }
+
+                fn set_stat_value(&mut self, value: f32) {
+                    self.0 = value;
+                }
+            }
+
+            #[derive(Event, Clone, Copy)]
+            struct $effect(ValueModifier);
+
+            impl StatusEffectApplicator<$name> for $effect {
+                fn modifier(&self) -> ValueModifier {
+                    self.0
+                }
+
+                fn apply(&self, component: &mut $name, power: f32) {
+                    component.0 = self.0.apply_scaled(component.0, power);
+                }
+            }
+        };
+    }
+
+    stat!(Speed, SpeedEffect);
+    stat!(Armor, ArmorEffect);
+    stat!(Mana, ManaEffect);
+
+    #[test]
+    fn diff_reports_changed_and_new_stats() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().with_stat_value(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::new(
+            StatusEffectApplication::linear().with_stat_value(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Mana, ManaEffect>::new(
+            StatusEffectApplication::linear().with_stat_value(),
+        ));
+        let entity = app.world_mut().spawn((Speed(100.0), Armor(30.0))).id();
+        app.update();
+
+        let before = snapshot_stats(app.world(), entity);
+        assert_eq!(before.len(), 2);
+        assert_eq!(before.get::<Speed>(), Some(100.0));
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(20.0)))
+            .apply_effect(entity, ArmorEffect(ValueModifier::Val(-5.0)))
+            .apply_effect(entity, ManaEffect(ValueModifier::Val(50.0)));
+        app.update();
+        let after = snapshot_stats(app.world(), entity);
+
+        let diff = diff_stats(&before, &after);
+        assert_eq!(diff.len(), 3);
+        let find = |f: fn(&StatDelta) -> bool| *diff.iter().find(|d| f(d)).unwrap();
+        assert_eq!(find(StatDelta::is::<Speed>).delta(), 20.0);
+        assert_eq!(find(StatDelta::is::<Armor>).delta(), -5.0);
+
+        // Auto-inserted between snapshots
+        let mana = find(StatDelta::is::<Mana>);
+        assert_eq!(mana.before, None);
+        assert_eq!(mana.after, Some(50.0));
+
+        assert!(diff_stats(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn registry_order_ignores_plugin_order() {
+        let names = |reversed: bool| {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins);
+            if reversed {
+                app.add_plugins(StatusEffectPlugin::<Mana, ManaEffect>::new(
+                    StatusEffectApplication::linear().with_stat_value(),
+                ));
+                app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+                    StatusEffectApplication::linear().with_stat_value(),
+                ));
+            } else {
+                app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+                    StatusEffectApplication::linear().with_stat_value(),
+                ));
+                app.add_plugins(StatusEffectPlugin::<Mana, ManaEffect>::new(
+                    StatusEffectApplication::linear().with_stat_value(),
+                ));
+            }
+            app.world()
+                .resource::<StatusEffectRegistry>()
+                .stats()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(false), names(true));
+        assert!(names(false).is_sorted());
+    }
+
+    #[test]
+    fn collect_effective_reports_every_entity() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        let fast = app.world_mut().spawn(Speed(100.0)).id();
+        let plain = app.world_mut().spawn(Speed(80.0)).id();
+        let slowed = app.world_mut().spawn(Speed(60.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(fast, SpeedEffect(ValueModifier::Percent(50.0)))
+            .apply_effect(slowed, SpeedEffect(ValueModifier::Val(-20.0)));
+        app.update();
+
+        assert_eq!(
+            collect_effective::<Speed>(app.world_mut()),
+            vec![(fast, 150.0), (plain, 80.0), (slowed, 40.0)]
+        );
+    }
+}
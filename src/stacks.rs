@@ -0,0 +1,277 @@
+//! Stack limits per effect type.
+//!
+//! A [`StackPolicy`] set with
+//! [`StatusEffectPlugin::with_stack_policy`](crate::StatusEffectPlugin::with_stack_policy)
+//! caps how many applications of an effect type can be active on an entity at
+//! once. The current count is kept in an [`ActiveStacks<E>`] component, which
+//! is required by `C` so it's present before the first application.
+//!
+//! Stacks end when a [tracked](crate::StatusEffectApplication::tracked) entry
+//! is removed, or when a [timed](crate::TimedStatusEffect) effect on an
+//! untracked component wears off. Untracked effects without a duration stay
+//! counted for good.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{AppliedEffects, MutableComponent, TemporaryEffects, TimedEffects};
+
+/// How many applications of an effect type can be active on an entity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StackPolicy {
+    /// Every application stacks
+    #[default]
+    Unlimited,
+    /// At most this many stacks; further applications are rejected with
+    /// [`RejectReason::StackCap`](crate::RejectReason::StackCap)
+    MaxStacks(u32),
+    /// A single stack; further applications restart its
+    /// [temporary](crate::StatusEffectCommandsExt::apply_temporary) or
+    /// [timed](crate::TimedStatusEffect) countdowns instead of stacking
+    RefreshOnly,
+}
+
+impl StackPolicy {
+    /// Returns the most stacks allowed, or `None` if unlimited.
+    #[must_use]
+    pub fn max(self) -> Option<u32> {
+        match self {
+            Self::Unlimited => None,
+            Self::MaxStacks(max) => Some(max),
+            Self::RefreshOnly => Some(1),
+        }
+    }
+}
+
+/// Stack policy of effect type `E`, inserted by its plugin.
+#[derive(Resource)]
+pub(crate) struct EffectStackPolicy<E: Send + Sync + 'static> {
+    pub(crate) policy: StackPolicy,
+    /// Applications are capped when triggered rather than when drained
+    pub(crate) immediate: bool,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Send + Sync + 'static> EffectStackPolicy<E> {
+    pub(crate) fn new(policy: StackPolicy, immediate: bool) -> Self {
+        Self {
+            policy,
+            immediate,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Number of active applications of effect type `E` on this entity.
+#[derive(Component)]
+pub struct ActiveStacks<E: Send + Sync + 'static> {
+    count: u32,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Send + Sync + 'static> ActiveStacks<E> {
+    /// Returns the number of active stacks.
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub(crate) fn set(&mut self, count: u32) {
+        self.count = count;
+    }
+
+    pub(crate) fn end_one(&mut self) {
+        self.count = self.count.saturating_sub(1);
+    }
+}
+
+impl<E: Send + Sync + 'static> Default for ActiveStacks<E> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Restarts the countdowns of `E` on an entity for a refreshed stack.
+pub(crate) fn refresh_countdowns<C: MutableComponent, E: Send + Sync + 'static>()
+-> impl FnOnce(EntityWorldMut) {
+    move |mut entity: EntityWorldMut| {
+        if let Some(mut pending) = entity.get_mut::<TemporaryEffects<E>>() {
+            pending.restart();
+        }
+        if let Some(mut pending) = entity.get_mut::<TimedEffects<C, E>>() {
+            pending.restart();
+        }
+    }
+}
+
+/// Recounts the stacks of `E` from the tracked entries of `C`, after removals.
+fn sync_active_stacks<C: MutableComponent, E: Send + Sync + 'static>(
+    mut q: Query<(Option<&AppliedEffects<C>>, &mut ActiveStacks<E>)>,
+) {
+    for (applied, mut stacks) in &mut q {
+        let count = applied.map_or(0, |applied| applied.of_type::<E>().count());
+        let count = u32::try_from(count).unwrap_or(u32::MAX);
+        if stacks.count != count {
+            stacks.count = count;
+        }
+    }
+}
+
+/// Keeps stack counts of `E` on tracked component `C` in sync once per app.
+pub(crate) struct StackSyncPlugin<C, E>(PhantomData<(C, E)>);
+
+impl<C, E> Default for StackSyncPlugin<C, E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent, E: Send + Sync + 'static> Plugin for StackSyncPlugin<C, E> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, sync_active_stacks::<C, E>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Poison(f32);
+
+    impl StatValue for Poison {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct PoisonStack(ValueModifier);
+
+    impl StatusEffectApplicator<Poison> for PoisonStack {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Poison, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app(config: StatusEffectApplication<Poison>, policy: StackPolicy) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(
+            StatusEffectPlugin::<Poison, PoisonStack>::new(config).with_stack_policy(policy),
+        );
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.update();
+        let entity = app.world_mut().spawn(Poison(0.0)).id();
+        (app, entity)
+    }
+
+    fn stacks(app: &App, entity: Entity) -> u32 {
+        app.world()
+            .get::<ActiveStacks<PoisonStack>>(entity)
+            .unwrap()
+            .count()
+    }
+
+    #[test]
+    fn applications_beyond_the_cap_are_ignored() {
+        let (mut app, entity) = app(
+            StatusEffectApplication::linear().with_stat_value(),
+            StackPolicy::MaxStacks(5),
+        );
+
+        for _ in 0..7 {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, PoisonStack(ValueModifier::Val(10.0)));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Poison>(entity).unwrap().0, 50.0);
+        assert_eq!(stacks(&app, entity), 5);
+    }
+
+    #[test]
+    fn tracked_removal_frees_a_stack() {
+        let (mut app, entity) = app(
+            StatusEffectApplication::linear().tracked(),
+            StackPolicy::MaxStacks(2),
+        );
+
+        for _ in 0..3 {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, PoisonStack(ValueModifier::Val(10.0)));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Poison>(entity).unwrap().0, 20.0);
+
+        app.world_mut()
+            .commands()
+            .trigger(RemoveStatusEffect::<PoisonStack>::new(entity));
+        app.update();
+        assert_eq!(stacks(&app, entity), 1);
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, PoisonStack(ValueModifier::Val(10.0)));
+        app.update();
+        assert_eq!(app.world().get::<Poison>(entity).unwrap().0, 20.0);
+        assert_eq!(stacks(&app, entity), 2);
+    }
+
+    #[test]
+    fn refresh_only_restarts_the_countdown() {
+        let (mut app, entity) = app(
+            StatusEffectApplication::linear().tracked(),
+            StackPolicy::RefreshOnly,
+        );
+        let poison = PoisonStack(ValueModifier::Val(10.0));
+
+        app.world_mut()
+            .commands()
+            .apply_temporary(entity, poison, 1.0);
+        for _ in 0..3 {
+            app.update();
+        }
+        app.world_mut()
+            .commands()
+            .apply_temporary(entity, poison, 1.0);
+        app.update();
+        assert_eq!(app.world().get::<Poison>(entity).unwrap().0, 10.0);
+        let pending = app
+            .world()
+            .get::<TemporaryEffects<PoisonStack>>(entity)
+            .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert!(pending.remaining_secs().next().unwrap() > 0.5);
+
+        // Still active past the original expiry, gone after the refreshed one
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Poison>(entity).unwrap().0, 10.0);
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Poison>(entity).unwrap().0, 0.0);
+        assert_eq!(stacks(&app, entity), 0);
+    }
+}
@@ -0,0 +1,189 @@
+//! Net stat changes per frame.
+//!
+//! Components configured with
+//! [`StatusEffectApplication::reports_changes`](crate::StatusEffectApplication::reports_changes)
+//! trigger a [`StatChanged<C>`] on each entity whose stat moved during the
+//! frame, e.g. for UI bars that tween toward the new value. Changes from any
+//! source count, effects or not. Several changes within one frame are reported
+//! as a single change from the value at the end of the previous frame.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{MutableComponent, StatusEffectApplication};
+
+/// Triggered in [`Last`] when an entity's stat `C` changed during the frame.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Health(f32);
+///
+/// fn tween_bar(on: On<StatChanged<Health>>) {
+///     info!("{}: {} -> {}", on.entity, on.old, on.new);
+/// }
+/// ```
+#[derive(EntityEvent)]
+pub struct StatChanged<C: Component> {
+    /// The entity whose stat changed
+    pub entity: Entity,
+    /// Value at the end of the previous frame
+    pub old: f32,
+    /// Value at the end of this frame
+    pub new: f32,
+    _marker: PhantomData<C>,
+}
+
+impl<C: Component> StatChanged<C> {
+    /// Returns `new - old`.
+    #[must_use]
+    pub fn delta(&self) -> f32 {
+        self.new - self.old
+    }
+}
+
+impl<C: Component> Clone for StatChanged<C> {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            old: self.old,
+            new: self.new,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Stat value of `C` at the end of the last frame it was checked.
+#[derive(Component)]
+struct LastStatValue<C> {
+    value: f32,
+    _marker: PhantomData<C>,
+}
+
+/// Triggers [`StatChanged<C>`] for stats whose value moved this frame.
+///
+/// Components added this frame are recorded without a change.
+fn report_stat_changes<C: MutableComponent>(
+    config: Res<StatusEffectApplication<C>>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &C, Option<&mut LastStatValue<C>>), Changed<C>>,
+) {
+    for (entity, component, last) in &mut q {
+        let Some(new) = config.stat_value(component) else {
+            continue;
+        };
+        match last {
+            Some(mut last) if last.value != new => {
+                commands.trigger(StatChanged::<C> {
+                    entity,
+                    old: last.value,
+                    new,
+                    _marker: PhantomData,
+                });
+                last.value = new;
+            }
+            Some(_) => {}
+            None => {
+                commands.entity(entity).insert(LastStatValue::<C> {
+                    value: new,
+                    _marker: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+/// Reports net changes of stat `C` once per app.
+pub(crate) struct StatChangePlugin<C>(PhantomData<C>);
+
+impl<C> Default for StatChangePlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for StatChangePlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, report_stat_changes::<C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health(f32);
+
+    impl StatValue for Health {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Heal(ValueModifier);
+
+    impl StatusEffectApplicator<Health> for Heal {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Health, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Changes(Vec<(f32, f32)>);
+
+    #[test]
+    fn changes_in_one_frame_coalesce() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, Heal>::new(
+            StatusEffectApplication::linear().reports_changes(),
+        ));
+        app.init_resource::<Changes>();
+        app.add_observer(
+            |on: On<StatChanged<Health>>, mut changes: ResMut<Changes>| {
+                changes.0.push((on.old, on.new));
+            },
+        );
+        let entity = app.world_mut().spawn(Health(100.0)).id();
+        app.update();
+        assert!(app.world().resource::<Changes>().0.is_empty());
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Heal(ValueModifier::Val(10.0)))
+            .apply_effect(entity, Heal(ValueModifier::Val(20.0)));
+        app.update();
+        assert_eq!(app.world().resource::<Changes>().0, vec![(100.0, 130.0)]);
+
+        // Changes that cancel out within a frame aren't reported
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Heal(ValueModifier::Val(-30.0)))
+            .apply_effect(entity, Heal(ValueModifier::Val(30.0)));
+        app.update();
+        // Direct writes count too, reported once
+        app.world_mut().get_mut::<Health>(entity).unwrap().0 = 50.0;
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Changes>().0,
+            vec![(100.0, 130.0), (130.0, 50.0)]
+        );
+    }
+}
@@ -0,0 +1,479 @@
+//! Effects that remove themselves after a duration.
+//!
+//! [`apply_temporary`](crate::StatusEffectCommandsExt::apply_temporary)
+//! applies an effect now and starts a one-shot timer in a
+//! [`TemporaryEffects<E>`] component on the target. When it finishes, a
+//! [`RemoveStatusEffect<E>`] removes the tracked stack of `E` that application
+//! created, tagged with the timer's [`expiry`](crate::AppliedEffect::expiry),
+//! leaving other stacks of `E` alone. The timers live on the target, so
+//! despawning it cancels pending removals. Countdowns can be
+//! [paused](crate::StatusEffectCommandsExt::pause_effect), e.g. for a time
+//! stop.
+//!
+//! [Fading](crate::StatusEffectCommandsExt::apply_fading) effects also weaken
+//! as their timer runs down: the tagged stack's
+//! [`weight`](crate::AppliedEffect::weight) follows the remaining fraction.
+
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bevy::prelude::*;
+
+use crate::stacks::EffectStackPolicy;
+use crate::{
+    ActiveStacks, AppliedEffects, ApplyStatusEffect, MutableComponent, PercentSum,
+    RemoveStatusEffect, StatusEffectApplication, tracking, with_override,
+};
+
+/// Expiry id of the temporary application currently being triggered.
+///
+/// Only present for the duration of one trigger.
+#[derive(Resource)]
+pub(crate) struct ExpiryTag(pub(crate) u64);
+
+/// Next expiry id, unique across worlds.
+static NEXT_EXPIRY: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a fresh expiry id.
+pub(crate) fn next_expiry() -> u64 {
+    NEXT_EXPIRY.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Triggers `event`, tagging the tracked entry it creates with `expiry`.
+pub(crate) fn trigger_expiring<E>(world: &mut World, event: ApplyStatusEffect<E>, expiry: u64)
+where
+    E: Event + Clone,
+{
+    with_override(world, ExpiryTag(expiry), |world| world.trigger(event));
+}
+
+/// A pending removal.
+struct Countdown {
+    timer: Timer,
+    fades: bool,
+    expiry: u64,
+}
+
+/// Pending removals of effect type `E` on this entity.
+#[derive(Component)]
+pub struct TemporaryEffects<E: Send + Sync + 'static> {
+    timers: Vec<Countdown>,
+    paused: bool,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Send + Sync + 'static> TemporaryEffects<E> {
+    /// Returns the number of pending removals.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns true if no removals are pending.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Returns true while the countdowns are paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Iterates the seconds left until each pending removal.
+    pub fn remaining_secs(&self) -> impl Iterator<Item = f32> + '_ {
+        self.timers
+            .iter()
+            .map(|countdown| countdown.timer.remaining_secs())
+    }
+
+    /// Restarts every pending countdown.
+    pub(crate) fn restart(&mut self) {
+        for countdown in &mut self.timers {
+            countdown.timer.reset();
+        }
+    }
+}
+
+/// Schedules the removal of one stack of `E` after `duration` seconds,
+/// weakening it along the way if `fades` is set.
+///
+/// Queued on the target, so a timer started in the same flush as another is
+/// appended rather than overwritten. Under a [stack
+/// policy](crate::StackPolicy), no timer is added for an application that was
+/// rejected or only refreshed the existing stacks. Deferred applications are
+/// capped after their timer started, so their timers are always added.
+pub(crate) fn schedule_removal<E: Send + Sync + 'static>(
+    duration: f32,
+    fades: bool,
+    expiry: u64,
+) -> impl FnOnce(EntityWorldMut) {
+    let timer = Countdown {
+        timer: Timer::from_seconds(duration, TimerMode::Once),
+        fades,
+        expiry,
+    };
+    move |mut entity: EntityWorldMut| {
+        let capped = entity
+            .world()
+            .get_resource::<EffectStackPolicy<E>>()
+            .is_some_and(|policy| policy.immediate);
+        let stacks = entity.get::<ActiveStacks<E>>().map(ActiveStacks::count);
+        let pending = entity
+            .get::<TemporaryEffects<E>>()
+            .map_or(0, TemporaryEffects::len);
+        if capped && stacks.is_some_and(|stacks| pending >= stacks as usize) {
+            return;
+        }
+        match entity.get_mut::<TemporaryEffects<E>>() {
+            Some(mut pending) => pending.timers.push(timer),
+            None => {
+                entity.insert(TemporaryEffects::<E> {
+                    timers: vec![timer],
+                    paused: false,
+                    _marker: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+/// Pauses or resumes the countdowns of `E` on an entity, if any are pending.
+pub(crate) fn set_paused<E: Send + Sync + 'static>(paused: bool) -> impl FnOnce(EntityWorldMut) {
+    move |mut entity: EntityWorldMut| {
+        if let Some(mut pending) = entity.get_mut::<TemporaryEffects<E>>() {
+            pending.paused = paused;
+        }
+    }
+}
+
+/// Removes expired temporary effects.
+fn tick_temporary_effects<E: Send + Sync + 'static>(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut TemporaryEffects<E>)>,
+) {
+    for (entity, mut pending) in &mut q {
+        if pending.paused {
+            continue;
+        }
+        for countdown in &mut pending.timers {
+            countdown.timer.tick(time.delta());
+            if countdown.timer.just_finished() {
+                commands.trigger(RemoveStatusEffect::<E>::expired(entity, countdown.expiry));
+            }
+        }
+        pending
+            .timers
+            .retain(|countdown| !countdown.timer.is_finished());
+        if pending.timers.is_empty() {
+            commands.entity(entity).remove::<TemporaryEffects<E>>();
+        }
+    }
+}
+
+/// Registers temporary effect timers for effect type `E` once per app.
+pub(crate) struct TemporaryPlugin<E>(PhantomData<E>);
+
+impl<E> Default for TemporaryPlugin<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: Send + Sync + 'static> Plugin for TemporaryPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, tick_temporary_effects::<E>);
+    }
+}
+
+/// Weights fading stacks of `E` by the time they'll have left after this
+/// frame's tick, so an expiring stack contributes nothing before its removal.
+fn fade_temporary_effects<C: MutableComponent, E: Send + Sync + 'static>(
+    time: Res<Time>,
+    config: Res<StatusEffectApplication<C>>,
+    mut q: Query<(
+        &mut C,
+        &mut AppliedEffects<C>,
+        &TemporaryEffects<E>,
+        Option<&mut PercentSum<C>>,
+    )>,
+) {
+    for (mut component, mut applied, pending, mut sum) in &mut q {
+        if pending.paused || !pending.timers.iter().any(|countdown| countdown.fades) {
+            continue;
+        }
+        let mut changed = false;
+        for countdown in &pending.timers {
+            if !countdown.fades {
+                continue;
+            }
+            let Some(stack) = applied
+                .effects
+                .iter_mut()
+                .find(|effect| effect.expiry == Some(countdown.expiry))
+            else {
+                continue;
+            };
+            let duration = countdown.timer.duration().as_secs_f32();
+            let left = countdown.timer.remaining_secs() - time.delta_secs();
+            let weight = if duration > 0.0 {
+                (left / duration).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            if stack.weight != weight {
+                stack.weight = weight;
+                changed = true;
+            }
+        }
+        if changed
+            && tracking::rebuild(
+                &config,
+                component.bypass_change_detection(),
+                &applied,
+                sum.as_deref_mut(),
+            )
+        {
+            component.set_changed();
+        }
+    }
+}
+
+/// Registers fading of effect type `E` on tracked component `C` once per app.
+pub(crate) struct FadePlugin<C, E>(PhantomData<(C, E)>);
+
+impl<C, E> Default for FadePlugin<C, E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent, E: Send + Sync + 'static> Plugin for FadePlugin<C, E> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            fade_temporary_effects::<C, E>.before(tick_temporary_effects::<E>),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.update();
+        app
+    }
+
+    #[test]
+    fn temporary_buff_expires() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut().commands().apply_temporary(
+            entity,
+            Haste(ValueModifier::Percent(50.0)),
+            1.0,
+        );
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 150.0);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+        assert!(app.world().get::<TemporaryEffects<Haste>>(entity).is_none());
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_none());
+    }
+
+    #[test]
+    fn expiry_leaves_permanent_stacks() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Haste(ValueModifier::Val(10.0)))
+            .apply_temporary(entity, Haste(ValueModifier::Percent(50.0)), 1.0);
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 165.0);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+
+        // Timed effects and fading on tracked components too
+        app.world_mut().commands().trigger(TimedStatusEffect::new(
+            entity,
+            Haste(ValueModifier::Percent(50.0)),
+            1.0,
+        ));
+        app.world_mut()
+            .commands()
+            .apply_fading(entity, Haste(ValueModifier::Val(20.0)), 2.0);
+        app.world_mut().flush();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 185.0);
+        for _ in 0..4 {
+            app.update();
+        }
+        // The timed +50% is gone, the fade halfway done
+        let speed = app.world().get::<Speed>(entity).unwrap().0;
+        assert!((speed - 120.0).abs() <= 2.5, "{speed}");
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.effects[0].weight, 1.0);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+    }
+
+    #[test]
+    fn paused_countdown_resumes_where_it_stopped() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut().commands().apply_temporary(
+            entity,
+            Haste(ValueModifier::Percent(50.0)),
+            3.0,
+        );
+        for _ in 0..4 {
+            app.update();
+        }
+        let remaining = |app: &App| {
+            let pending = app.world().get::<TemporaryEffects<Haste>>(entity).unwrap();
+            pending.remaining_secs().next().unwrap()
+        };
+        assert!((remaining(&app) - 2.0).abs() <= 0.25);
+
+        // Pause at t=1, then let 5 seconds pass
+        app.world_mut().commands().pause_effect::<Haste>(entity);
+        let paused_at = {
+            app.update();
+            remaining(&app)
+        };
+        for _ in 0..20 {
+            app.update();
+        }
+        let pending = app.world().get::<TemporaryEffects<Haste>>(entity).unwrap();
+        assert!(pending.is_paused());
+        assert_eq!(remaining(&app), paused_at);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 150.0);
+
+        app.world_mut().commands().resume_effect::<Haste>(entity);
+        app.update();
+        assert!((remaining(&app) - 2.0).abs() <= 0.5);
+        for _ in 0..9 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+    }
+
+    #[test]
+    fn pausing_an_expired_effect_does_nothing() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_temporary(entity, Haste(ValueModifier::Val(10.0)), 0.5);
+        for _ in 0..4 {
+            app.update();
+        }
+        app.world_mut().commands().pause_effect::<Haste>(entity);
+        app.update();
+        assert!(app.world().get::<TemporaryEffects<Haste>>(entity).is_none());
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+    }
+
+    #[test]
+    fn fading_buff_weakens_until_expiry() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_fading(entity, Haste(ValueModifier::Val(40.0)), 2.0);
+        app.world_mut().flush();
+        // Full strength at t=0
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 140.0);
+
+        // Midpoint, 1 of 2 seconds gone
+        for _ in 0..4 {
+            app.update();
+        }
+        let speed = app.world().get::<Speed>(entity).unwrap().0;
+        assert!((speed - 120.0).abs() <= 5.0, "{speed}");
+
+        // The last frame before removal contributes nothing
+        let mut last = speed;
+        while app.world().get::<TemporaryEffects<Haste>>(entity).is_some() {
+            app.update();
+            let speed = app.world().get::<Speed>(entity).unwrap().0;
+            assert!(speed <= last);
+            last = speed;
+        }
+        assert_eq!(last, 100.0);
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_none());
+    }
+
+    #[test]
+    fn despawn_cancels_removal() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut()
+            .commands()
+            .apply_temporary(entity, Haste(ValueModifier::Val(10.0)), 1.0);
+        app.update();
+        app.world_mut().despawn(entity);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().get_entity(entity).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! Helpers for testing effects.
+//!
+//! [`apply_sync`] applies an effect and returns the resulting stat in one
+//! call, without counting `update()`s for auto-insertion or deferred drains.
+//! Requires the `test-util` feature.
+
+use bevy::prelude::*;
+
+use crate::{
+    ApplyStatusEffect, MutableComponent, StatValue, StatusEffectApplicator, StatusEffectQueue,
+    drain_status_effect_queue,
+};
+
+/// Applies `effect` to `entity` right away and returns `C`'s new value.
+///
+/// Pending commands are applied first, then the effect is triggered and every
+/// follow-up it queues, including an auto-insert of a missing component, runs
+/// before returning. Deferred configs have their whole
+/// [queue](StatusEffectQueue) drained, so effects queued earlier apply too.
+/// No schedules run, so timers don't advance.
+///
+/// # Panics
+///
+/// Panics if `entity` doesn't have `C` afterwards, e.g. because it was
+/// despawned or the effect doesn't
+/// [auto-insert](StatusEffectApplicator::should_auto_insert).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::apply_sync;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// impl StatValue for Speed {
+///     fn stat_value(&self) -> f32 { self.0 }
+///     fn set_stat_value(&mut self, value: f32) { self.0 = value; }
+/// }
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct Haste(ValueModifier);
+///
+/// impl StatusEffectApplicator<Speed> for Haste {
+///     fn modifier(&self) -> ValueModifier { self.0 }
+///     fn apply(&self, component: &mut Speed, power: f32) {
+///         component.0 = self.0.apply_scaled(component.0, power);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(StatusEffectPlugin::<Speed, Haste>::default());
+/// let entity = app.world_mut().spawn(Speed(100.0)).id();
+///
+/// assert_eq!(apply_sync(&mut app, entity, Haste(ValueModifier::Val(20.0))), 120.0);
+/// ```
+#[track_caller]
+pub fn apply_sync<C, E>(app: &mut App, entity: Entity, effect: E) -> f32
+where
+    C: MutableComponent + StatValue,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    let world = app.world_mut();
+    world.flush();
+    world.trigger(ApplyStatusEffect { effect, entity });
+    world.flush();
+    if world.contains_resource::<StatusEffectQueue>() {
+        drain_status_effect_queue(world);
+    }
+    match world.get::<C>(entity) {
+        Some(component) => component.stat_value(),
+        None => panic!(
+            "{entity} has no {} after applying {}",
+            std::any::type_name::<C>(),
+            std::any::type_name::<E>()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app(config: StatusEffectApplication<Speed>) -> App {
+        let mut app = App::new();
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(config));
+        app
+    }
+
+    #[test]
+    fn applies_to_present_component() {
+        let mut app = app(StatusEffectApplication::linear().tracked());
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        let speed = apply_sync(&mut app, entity, Haste(ValueModifier::Percent(50.0)));
+        assert_eq!(speed, 150.0);
+        let speed = apply_sync(&mut app, entity, Haste(ValueModifier::Val(-30.0)));
+        assert_eq!(speed, 120.0);
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.len(), 2);
+    }
+
+    #[test]
+    fn auto_inserts_missing_component() {
+        let mut app = app(StatusEffectApplication::linear().tracked());
+        let entity = app.world_mut().spawn_empty().id();
+
+        let speed = apply_sync(&mut app, entity, Haste(ValueModifier::Val(20.0)));
+        assert_eq!(speed, 20.0);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 20.0);
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.base, 0.0);
+    }
+
+    #[test]
+    fn drains_deferred_applications() {
+        let mut app = app(StatusEffectApplication::linear().deferred());
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        let speed = apply_sync(&mut app, entity, Haste(ValueModifier::Val(20.0)));
+        assert_eq!(speed, 120.0);
+        assert!(app.world().resource::<StatusEffectQueue>().is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+//! Effects that pick their modifier from the target's current state.
+//!
+//! [`ThresholdApplicator<C>`] holds two modifiers and a predicate over `C`.
+//! The predicate is evaluated at apply time, so "execute" effects like "double
+//! damage below 30% health" see the value as it is when they land.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::{CurrentMax, MutableComponent, StatusEffectApplicator, ValueModifier};
+
+type PredicateFn<C> = Arc<dyn Fn(&C) -> bool + Send + Sync>;
+
+/// Applies `below` when the predicate holds for the component, `above` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// impl CurrentMax for Health {
+///     fn current(&self) -> f32 { self.current }
+///     fn max(&self) -> f32 { self.max }
+///     fn set_current(&mut self, value: f32) { self.current = value; }
+/// }
+///
+/// // 10 damage, doubled while the target is under 30% health
+/// let execute = ThresholdApplicator::<Health>::below_fraction(
+///     0.3,
+///     ValueModifier::Val(-20.0),
+///     ValueModifier::Val(-10.0),
+/// );
+/// ```
+#[derive(Event)]
+pub struct ThresholdApplicator<C: MutableComponent> {
+    predicate: PredicateFn<C>,
+    /// Modifier applied when the predicate holds
+    pub below: ValueModifier,
+    /// Modifier applied otherwise
+    pub above: ValueModifier,
+}
+
+impl<C: MutableComponent> Clone for ThresholdApplicator<C> {
+    fn clone(&self) -> Self {
+        Self {
+            predicate: Arc::clone(&self.predicate),
+            below: self.below,
+            above: self.above,
+        }
+    }
+}
+
+impl<C: MutableComponent> ThresholdApplicator<C> {
+    /// Applies `below` when `predicate` returns true for the component.
+    #[must_use]
+    pub fn new(
+        predicate: impl Fn(&C) -> bool + Send + Sync + 'static,
+        below: ValueModifier,
+        above: ValueModifier,
+    ) -> Self {
+        Self {
+            predicate: Arc::new(predicate),
+            below,
+            above,
+        }
+    }
+
+    /// Returns the modifier this applicator would apply to `component`.
+    #[must_use]
+    pub fn select(&self, component: &C) -> ValueModifier {
+        if (self.predicate)(component) {
+            self.below
+        } else {
+            self.above
+        }
+    }
+}
+
+impl<C: MutableComponent + CurrentMax> ThresholdApplicator<C> {
+    /// Applies `below` while `current / max` is strictly under `fraction`.
+    ///
+    /// Exactly at the threshold, `above` applies. A non-positive `max` never
+    /// counts as below.
+    #[must_use]
+    pub fn below_fraction(fraction: f32, below: ValueModifier, above: ValueModifier) -> Self {
+        Self::new(
+            move |component: &C| {
+                component.max() > 0.0 && component.current() / component.max() < fraction
+            },
+            below,
+            above,
+        )
+    }
+}
+
+impl<C: MutableComponent + CurrentMax> StatusEffectApplicator<C> for ThresholdApplicator<C> {
+    /// Returns `above`, the modifier for the unconditioned case.
+    fn modifier(&self) -> ValueModifier {
+        self.above
+    }
+
+    fn apply(&self, component: &mut C, power: f32) {
+        let modifier = self.select(component);
+        let max = component.max().max(0.0);
+        let value = modifier.apply_scaled(component.current(), power);
+        component.set_current(value.clamp(0.0, max));
+    }
+
+    fn scaled_by(&self, factor: f32) -> Self {
+        Self {
+            predicate: Arc::clone(&self.predicate),
+            below: self.below.scaled_by(factor),
+            above: self.above.scaled_by(factor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    impl CurrentMax for Health {
+        fn current(&self) -> f32 {
+            self.current
+        }
+
+        fn max(&self) -> f32 {
+            self.max
+        }
+
+        fn set_current(&mut self, value: f32) {
+            self.current = value;
+        }
+    }
+
+    fn execute(current: f32) -> f32 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Health, ThresholdApplicator<Health>>::default());
+        let entity = app
+            .world_mut()
+            .spawn(Health {
+                current,
+                max: 100.0,
+            })
+            .id();
+
+        let effect = ThresholdApplicator::<Health>::below_fraction(
+            0.3,
+            ValueModifier::Val(-20.0),
+            ValueModifier::Val(-10.0),
+        );
+        app.world_mut().commands().apply_effect(entity, effect);
+        app.update();
+        app.world().get::<Health>(entity).unwrap().current
+    }
+
+    #[test]
+    fn damage_doubles_below_threshold() {
+        assert_eq!(execute(50.0), 40.0);
+        assert_eq!(execute(25.0), 5.0);
+
+        // Exactly at the threshold is not below it
+        assert_eq!(execute(30.0), 20.0);
+    }
+}
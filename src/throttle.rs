@@ -0,0 +1,279 @@
+//! Global cap on applications per frame.
+//!
+//! With [`StatusEffectThrottlePlugin`] added, at most
+//! [`max_per_frame`](StatusEffectThrottle::max_per_frame) applications are
+//! processed each frame, across every component and effect type. The rest wait
+//! in a backlog and are applied in trigger order at the start of the following
+//! frames, before anything triggered in those frames. Backlogged applications
+//! whose entity was despawned in the meantime are dropped.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+type ApplyFn = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// An application waiting for a later frame.
+struct Throttled {
+    entity: Entity,
+    apply: ApplyFn,
+}
+
+/// Limits how many applications are processed per frame.
+///
+/// Applications over the limit are deferred to later frames, keeping their
+/// order. Re-triggers for an auto-inserted component, drains of the
+/// [deferred queue](crate::StatusEffectApplication::deferred) and
+/// [timed effects](crate::TimedStatusEffect) on untracked components are
+/// never deferred, since they were counted when first triggered or must
+/// apply right away.
+///
+/// Inserted by [`StatusEffectThrottlePlugin`], which also drains the
+/// backlog. Inserting a new one later changes the cap and drops the backlog.
+#[derive(Resource)]
+pub struct StatusEffectThrottle {
+    max_per_frame: usize,
+    applied: usize,
+    backlog: VecDeque<Throttled>,
+    passing: bool,
+}
+
+impl StatusEffectThrottle {
+    /// Processes at most `max_per_frame` applications per frame.
+    #[must_use]
+    pub fn new(max_per_frame: usize) -> Self {
+        Self {
+            max_per_frame,
+            applied: 0,
+            backlog: VecDeque::new(),
+            passing: false,
+        }
+    }
+
+    /// Returns the most applications processed per frame.
+    #[must_use]
+    pub fn max_per_frame(&self) -> usize {
+        self.max_per_frame
+    }
+
+    /// Returns the number of applications processed so far this frame.
+    #[must_use]
+    pub fn applied_this_frame(&self) -> usize {
+        self.applied
+    }
+
+    /// Returns the number of applications waiting for a later frame.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.backlog.len()
+    }
+
+    /// Returns true if no applications are waiting.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.backlog.is_empty()
+    }
+
+    /// Counts an application, returning false if it has to wait instead.
+    ///
+    /// Nothing jumps the backlog, so later applications wait even when this
+    /// frame has room left.
+    pub(crate) fn admit(&mut self) -> bool {
+        if self.passing {
+            return true;
+        }
+        if !self.backlog.is_empty() || self.applied >= self.max_per_frame {
+            return false;
+        }
+        self.applied += 1;
+        true
+    }
+
+    /// Drops every backlogged application on `entity`.
+    pub(crate) fn forget(&mut self, entity: Entity) {
+        self.backlog.retain(|entry| entry.entity != entity);
+    }
+
+    /// Defers an application on `entity` to a later frame.
+    pub(crate) fn defer(
+        &mut self,
+        entity: Entity,
+        apply: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) {
+        self.backlog.push_back(Throttled {
+            entity,
+            apply: Box::new(apply),
+        });
+    }
+}
+
+/// Runs `f` with every application it triggers let through uncounted.
+pub(crate) fn pass(world: &mut World, f: impl FnOnce(&mut World)) {
+    let Some(mut throttle) = world.get_resource_mut::<StatusEffectThrottle>() else {
+        f(world);
+        return;
+    };
+    let passing = std::mem::replace(&mut throttle.passing, true);
+    f(world);
+    if let Some(mut throttle) = world.get_resource_mut::<StatusEffectThrottle>() {
+        throttle.passing = passing;
+    }
+}
+
+/// Starts a new frame's count and applies backlogged applications up to it.
+fn drain_throttled(world: &mut World) {
+    let Some(mut throttle) = world.get_resource_mut::<StatusEffectThrottle>() else {
+        return;
+    };
+    throttle.applied = 0;
+    loop {
+        let Some(mut throttle) = world.get_resource_mut::<StatusEffectThrottle>() else {
+            return;
+        };
+        if throttle.applied >= throttle.max_per_frame {
+            return;
+        }
+        let Some(next) = throttle.backlog.pop_front() else {
+            return;
+        };
+        if world.get_entity(next.entity).is_err() {
+            continue;
+        }
+        world.resource_mut::<StatusEffectThrottle>().applied += 1;
+        pass(world, next.apply);
+        // Flush so follow-ups, like auto-inserts, land before the next entry
+        world.flush();
+    }
+}
+
+/// Inserts a [`StatusEffectThrottle`] and drains its backlog in [`First`].
+///
+/// Not added by [`StatusEffectPlugin`](crate::StatusEffectPlugin); without it
+/// applications are never throttled.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// let mut app = App::new();
+/// // Spread bursts of applications over frames, 500 at a time
+/// app.add_plugins(StatusEffectThrottlePlugin::new(500));
+/// ```
+pub struct StatusEffectThrottlePlugin {
+    max_per_frame: usize,
+}
+
+impl StatusEffectThrottlePlugin {
+    /// Processes at most `max_per_frame` applications per frame.
+    #[must_use]
+    pub fn new(max_per_frame: usize) -> Self {
+        Self { max_per_frame }
+    }
+}
+
+impl Plugin for StatusEffectThrottlePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(StatusEffectThrottle::new(self.max_per_frame));
+        app.add_systems(First, drain_throttled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Gold(f32);
+
+    #[derive(Event, Clone, Copy)]
+    struct Loot(ValueModifier);
+
+    impl StatusEffectApplicator<Gold> for Loot {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Gold, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn app(max_per_frame: usize) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Gold, Loot>::default());
+        app.add_plugins(StatusEffectThrottlePlugin::new(max_per_frame));
+        app.update();
+        app
+    }
+
+    fn trigger(app: &mut App, entity: Entity, amount: f32) {
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: Loot(ValueModifier::Val(amount)),
+            entity,
+        });
+        app.world_mut().flush();
+    }
+
+    #[test]
+    fn backlog_drains_over_frames() {
+        let mut app = app(100);
+        let entity = app.world_mut().spawn(Gold(0.0)).id();
+
+        for _ in 0..1000 {
+            trigger(&mut app, entity, 1.0);
+        }
+        let gold = |app: &App| app.world().get::<Gold>(entity).unwrap().0;
+        assert_eq!(gold(&app), 100.0);
+        assert_eq!(app.world().resource::<StatusEffectThrottle>().len(), 900);
+
+        for frame in 2..=10 {
+            app.update();
+            assert_eq!(gold(&app), 100.0 * frame as f32);
+        }
+        assert!(app.world().resource::<StatusEffectThrottle>().is_empty());
+    }
+
+    #[test]
+    fn backlog_keeps_trigger_order() {
+        let mut app = app(1);
+        let entity = app.world_mut().spawn(Gold(10.0)).id();
+
+        // Order matters: +10 then x2 is 40, x2 then +10 would be 30
+        trigger(&mut app, entity, 0.0);
+        trigger(&mut app, entity, 10.0);
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: Loot(ValueModifier::Multiply(2.0)),
+            entity,
+        });
+        app.update();
+        // Triggered with a backlog waiting, so it queues behind it
+        trigger(&mut app, entity, -5.0);
+        assert_eq!(app.world().get::<Gold>(entity).unwrap().0, 20.0);
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Gold>(entity).unwrap().0, 35.0);
+    }
+
+    #[test]
+    fn despawned_entities_are_dropped() {
+        let mut app = app(1);
+        let kept = app.world_mut().spawn(Gold(0.0)).id();
+        let gone = app.world_mut().spawn(Gold(0.0)).id();
+
+        trigger(&mut app, kept, 1.0);
+        trigger(&mut app, gone, 1.0);
+        trigger(&mut app, kept, 1.0);
+        app.world_mut().despawn(gone);
+
+        // The despawned entry doesn't use up the frame's budget
+        app.update();
+        assert_eq!(app.world().get::<Gold>(kept).unwrap().0, 2.0);
+        assert!(app.world().resource::<StatusEffectThrottle>().is_empty());
+    }
+}
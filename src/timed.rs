@@ -0,0 +1,420 @@
+//! Effects that wear off after a duration.
+//!
+//! Triggering a [`TimedStatusEffect<E>`] applies the effect and undoes it once
+//! its duration has passed. How it is undone depends on the component:
+//!
+//! - [Tracked](crate::StatusEffectApplication::tracked) components behave like
+//!   [`apply_temporary`](crate::StatusEffectCommandsExt::apply_temporary): the
+//!   stack this effect created is removed and the stat rebuilt from its base.
+//! - Other components with a readable stat record the change the effect made
+//!   in a [`TimedEffects<C, E>`] and subtract it again on expiry.
+//!   `apply_scaled` has no general inverse under non-linear powers, so the
+//!   change is measured instead of recomputed.
+//!
+//! # Stacking
+//!
+//! Untracked stacks each remember their own change. Under a non-linear power
+//! that change depends on what was applied before, e.g. two sqrt `Val(30)` on
+//! 40 add 10 and then about 8.3. Expiries subtract those amounts in whatever
+//! order they run out, so the value in between can differ from applying the
+//! remaining stacks afresh, but it is back at its baseline once every stack
+//! has expired. Changes made by other effects in the meantime are kept.
+//! Tracked components don't have this drift, since they rebuild from the base.
+//!
+//! # Limitations
+//!
+//! Deferred components must be tracked to be timed; otherwise the effect is
+//! [rejected](crate::RejectReason::Untimed) rather than applied for good.
+//! [`pause_effect`](crate::StatusEffectCommandsExt::pause_effect) only reaches
+//! tracked countdowns, so untracked [`TimedEffects`] keep ticking while paused.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::{
+    ActiveStacks, ApplyStatusEffect, AutoInsert, AutoInsertMode, MutableComponent, RejectReason,
+    StatusEffectApplication, StatusEffectApplicator, StatusEffectCommandsExt, StatusEffectEvent,
+    activity,
+};
+
+/// Applies an effect to an entity and undoes it after `duration` seconds.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Event, Clone, Copy)]
+/// struct SpeedModifier(ValueModifier);
+///
+/// fn sprint(mut commands: Commands, player: Entity) {
+///     // +50% speed for 5 seconds
+///     commands.trigger(TimedStatusEffect::new(
+///         player,
+///         SpeedModifier(ValueModifier::Percent(50.0)),
+///         5.0,
+///     ));
+/// }
+/// ```
+#[derive(EntityEvent, Clone, Copy)]
+pub struct TimedStatusEffect<E: Event + Clone> {
+    /// The target entity
+    pub entity: Entity,
+    /// The effect to apply
+    pub effect: E,
+    /// Seconds until the effect wears off
+    pub duration: f32,
+}
+
+impl<E: Event + Clone> TimedStatusEffect<E> {
+    /// Applies `effect` to `entity` for `secs` seconds.
+    #[must_use]
+    pub fn new(entity: Entity, effect: E, secs: f32) -> Self {
+        Self {
+            entity,
+            effect,
+            duration: secs,
+        }
+    }
+}
+
+/// A change to restore once its timer finishes.
+struct Restore {
+    delta: f32,
+    timer: Timer,
+}
+
+/// Pending restores of timed effect `E` on untracked component `C`.
+///
+/// These countdowns can't be [paused](crate::StatusEffectCommandsExt::pause_effect).
+#[derive(Component)]
+pub struct TimedEffects<C: MutableComponent, E: Send + Sync + 'static> {
+    restores: Vec<Restore>,
+    _marker: PhantomData<(C, E)>,
+}
+
+impl<C: MutableComponent, E: Send + Sync + 'static> TimedEffects<C, E> {
+    /// Returns the number of pending restores.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.restores.len()
+    }
+
+    /// Returns true if no restores are pending.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.restores.is_empty()
+    }
+
+    /// Iterates the change each pending restore will subtract.
+    pub fn deltas(&self) -> impl Iterator<Item = f32> + '_ {
+        self.restores.iter().map(|restore| restore.delta)
+    }
+
+    /// Iterates the seconds left until each pending restore.
+    pub fn remaining_secs(&self) -> impl Iterator<Item = f32> + '_ {
+        self.restores
+            .iter()
+            .map(|restore| restore.timer.remaining_secs())
+    }
+
+    /// Restarts every pending countdown.
+    pub(crate) fn restart(&mut self) {
+        for restore in &mut self.restores {
+            restore.timer.reset();
+        }
+    }
+}
+
+pub(crate) fn timed_status_effect_observer<C, E>(
+    on: On<TimedStatusEffect<E>>,
+    config: Res<StatusEffectApplication<C>>,
+    mut commands: Commands,
+    mut events: Option<ResMut<Messages<StatusEffectEvent>>>,
+) where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    if config.is_tracked() {
+        commands.apply_temporary(on.entity, on.effect.clone(), on.duration);
+        return;
+    }
+    if config.deferred {
+        strict_assert!(
+            false,
+            "{} on {} can't wear off: deferred {} must be tracked to be timed",
+            std::any::type_name::<E>(),
+            on.entity,
+            std::any::type_name::<C>()
+        );
+        log_warn!(
+            "Rejected {} on {}: deferred {} must be tracked to be timed",
+            std::any::type_name::<E>(),
+            on.entity,
+            std::any::type_name::<C>()
+        );
+        activity::reject::<C, _>(
+            &on.effect,
+            RejectReason::Untimed,
+            &mut commands,
+            on.entity,
+            events.as_deref_mut(),
+        );
+        return;
+    }
+    commands.queue(apply_and_measure::<C, E>(
+        on.entity,
+        on.effect.clone(),
+        on.duration,
+    ));
+}
+
+/// Applies `effect` right away and records the change it made to `C`.
+fn apply_and_measure<C, E>(entity: Entity, effect: E, duration: f32) -> impl FnOnce(&mut World)
+where
+    C: MutableComponent + Default,
+    E: Event + Clone + StatusEffectApplicator<C>,
+{
+    move |world: &mut World| {
+        let Some(config) = world.get_resource::<StatusEffectApplication<C>>().cloned() else {
+            return;
+        };
+        let read = |world: &World| {
+            world
+                .get::<C>(entity)
+                .and_then(|component| config.stat_value(component))
+        };
+        let stacks = |world: &World| {
+            world
+                .get::<ActiveStacks<E>>(entity)
+                .map(ActiveStacks::count)
+        };
+        let stacks_before = stacks(world);
+        let before = read(world).or_else(|| {
+            // Measure auto-inserted components from the value they start at
+            let inserted = match config.auto_insert {
+                AutoInsertMode::Default => C::default(),
+                AutoInsertMode::Resource => world.get_resource::<AutoInsert<C>>()?.value(),
+            };
+            config.stat_value(&inserted)
+        });
+        crate::throttle::pass(world, |world| {
+            world.trigger(ApplyStatusEffect { effect, entity });
+        });
+        world.flush();
+        // Capped or refreshed by the stack policy, nothing new to restore
+        if stacks_before.is_some() && stacks(world) <= stacks_before {
+            return;
+        }
+
+        let (Some(before), Some(after)) = (before, read(world)) else {
+            log_warn!(
+                "{} on {entity} won't wear off: {} has no readable stat value",
+                std::any::type_name::<E>(),
+                std::any::type_name::<C>()
+            );
+            return;
+        };
+        let restore = Restore {
+            delta: after - before,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        };
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        match entity.get_mut::<TimedEffects<C, E>>() {
+            Some(mut pending) => pending.restores.push(restore),
+            None => {
+                entity.insert(TimedEffects::<C, E> {
+                    restores: vec![restore],
+                    _marker: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+/// Subtracts the changes of expired timed effects.
+pub(crate) fn tick_timed_effects<C: MutableComponent, E: Send + Sync + 'static>(
+    time: Res<Time>,
+    config: Res<StatusEffectApplication<C>>,
+    mut commands: Commands,
+    mut q: Query<(
+        Entity,
+        &mut C,
+        &mut TimedEffects<C, E>,
+        Option<&mut ActiveStacks<E>>,
+    )>,
+) {
+    for (entity, mut component, mut pending, mut stacks) in &mut q {
+        let mut restored = 0.0;
+        for restore in &mut pending.restores {
+            restore.timer.tick(time.delta());
+            if restore.timer.just_finished() {
+                restored += restore.delta;
+                if let Some(stacks) = stacks.as_mut() {
+                    stacks.end_one();
+                }
+            }
+        }
+        if restored != 0.0
+            && let Some(value) = config.stat_value(&component)
+        {
+            let value = config.pipeline(config.power, 0.0).finish(value - restored);
+            config.set_stat_value(&mut component, value);
+        }
+        pending
+            .restores
+            .retain(|restore| !restore.timer.is_finished());
+        if pending.restores.is_empty() {
+            commands.entity(entity).remove::<TimedEffects<C, E>>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    #[derive(Resource, Default)]
+    struct Rejections(Vec<RejectReason>);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+
+        fn on_rejected(&self, reason: RejectReason, commands: &mut Commands, _entity: Entity) {
+            commands.queue(move |world: &mut World| {
+                if let Some(mut rejections) = world.get_resource_mut::<Rejections>() {
+                    rejections.0.push(reason);
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn untracked_stacks_restore_to_baseline() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::sqrt().with_stat_value(),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.update();
+        let entity = app.world_mut().spawn(Speed(40.0)).id();
+
+        for secs in [0.5, 1.0] {
+            app.world_mut().commands().trigger(TimedStatusEffect::new(
+                entity,
+                Haste(ValueModifier::Val(30.0)),
+                secs,
+            ));
+        }
+        app.world_mut().flush();
+        // sqrt(40^2 + 30^2 + 30^2)
+        let speed = |app: &App| app.world().get::<Speed>(entity).unwrap().0;
+        assert!((speed(&app) - 3400f32.sqrt()).abs() < 0.001);
+
+        // The first stack takes off the 10 it added
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!((speed(&app) - (3400f32.sqrt() - 10.0)).abs() < 0.001);
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!((speed(&app) - 40.0).abs() < 0.001);
+        assert!(
+            app.world()
+                .get::<TimedEffects<Speed, Haste>>(entity)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn tracked_effect_is_removed_on_expiry() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.update();
+        let entity = app.world_mut().spawn(Speed(40.0)).id();
+
+        app.world_mut().commands().trigger(TimedStatusEffect::new(
+            entity,
+            Haste(ValueModifier::Val(30.0)),
+            0.5,
+        ));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 50.0);
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 40.0);
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_none());
+    }
+
+    // Strict builds assert on this misconfiguration instead
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn deferred_untracked_effect_is_rejected() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::default()
+                .with_stat_value()
+                .deferred(),
+        ));
+        app.init_resource::<Rejections>();
+        app.update();
+        let entity = app.world_mut().spawn(Speed(40.0)).id();
+
+        app.world_mut().commands().trigger(TimedStatusEffect::new(
+            entity,
+            Haste(ValueModifier::Val(30.0)),
+            0.5,
+        ));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 40.0);
+        assert_eq!(
+            app.world().resource::<Rejections>().0,
+            [RejectReason::Untimed]
+        );
+    }
+}
@@ -0,0 +1,1001 @@
+//! Per-entity tracking of applied effects.
+//!
+//! Components configured with [`StatusEffectApplication::tracked`](crate::StatusEffectApplication::tracked)
+//! record every applied modifier in an [`AppliedEffects<C>`] component, along
+//! with the stat value from before the first tracked effect. This lets the
+//! crate compare the current value against its base and rebuild it later.
+//!
+//! Entries are kept in application order, which is also the order a rebuild
+//! replays them in, so recomputing gives the same value on every run.
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    EPSILON, EffectActivity, EffectFlags, MutableComponent, PercentSum, RebalanceOnRemove,
+    StatusEffectApplication, StatusEffectEvent, StatusEffectRegistry, ValueModifier, rebalance,
+    value_bits,
+};
+
+/// Scalar view of a stat component.
+///
+/// Implement this so the crate can read and rebuild the component's value.
+/// Required for [tracking](crate::StatusEffectApplication::tracked).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Speed(f32);
+///
+/// impl StatValue for Speed {
+///     fn stat_value(&self) -> f32 { self.0 }
+///     fn set_stat_value(&mut self, value: f32) { self.0 = value; }
+/// }
+/// ```
+pub trait StatValue {
+    /// Returns the current stat value.
+    fn stat_value(&self) -> f32;
+
+    /// Overwrites the stat value.
+    fn set_stat_value(&mut self, value: f32);
+}
+
+/// Function pointers reading and writing a component's stat value.
+pub(crate) struct StatAccessor<C> {
+    pub get: fn(&C) -> f32,
+    pub set: fn(&mut C, f32),
+}
+
+impl<C> Clone for StatAccessor<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for StatAccessor<C> {}
+
+impl<C: StatValue> StatAccessor<C> {
+    pub fn of() -> Self {
+        Self {
+            get: C::stat_value,
+            set: C::set_stat_value,
+        }
+    }
+}
+
+/// A single tracked application.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AppliedEffect {
+    /// Type name of the effect
+    pub effect: Cow<'static, str>,
+    /// Modifier carried by the effect
+    pub modifier: ValueModifier,
+    /// Power the effect applied with, when it overrode the component's
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power: Option<f32>,
+    /// Categories of the effect, for dispels
+    #[serde(default, skip_serializing_if = "EffectFlags::is_empty")]
+    pub flags: EffectFlags,
+    /// Why the effect was applied, empty if no reason was given
+    #[serde(default, skip_serializing_if = "str::is_empty")]
+    pub reason: Cow<'static, str>,
+    /// Id of the [item](crate::StatusEffectCommandsExt::apply_item_stats)
+    /// that granted the effect, empty if none
+    #[serde(default, skip_serializing_if = "str::is_empty")]
+    pub source: Cow<'static, str>,
+    /// Priority of the effect, for [`EffectSlots`](crate::EffectSlots)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub priority: i32,
+    /// Fraction of the modifier in effect, below 1 while a
+    /// [fading](crate::StatusEffectCommandsExt::apply_fading) effect runs out
+    #[serde(default = "full_weight", skip_serializing_if = "is_full_weight")]
+    pub weight: f32,
+    /// Id of the [temporary](crate::StatusEffectCommandsExt::apply_temporary)
+    /// removal that ends this entry, if any. Not saved, like the removal
+    #[serde(skip)]
+    pub expiry: Option<u64>,
+}
+
+fn is_zero(priority: &i32) -> bool {
+    *priority == 0
+}
+
+fn full_weight() -> f32 {
+    1.0
+}
+
+fn is_full_weight(weight: &f32) -> bool {
+    *weight == 1.0
+}
+
+impl AppliedEffect {
+    /// Creates an entry for effect type `E`.
+    #[must_use]
+    pub fn of<E>(modifier: ValueModifier) -> Self {
+        Self {
+            effect: Cow::Borrowed(std::any::type_name::<E>()),
+            modifier,
+            power: None,
+            flags: EffectFlags::empty(),
+            reason: Cow::Borrowed(""),
+            source: Cow::Borrowed(""),
+            priority: 0,
+            weight: 1.0,
+            expiry: None,
+        }
+    }
+
+    /// Returns the modifier scaled by [`weight`](Self::weight).
+    #[must_use]
+    pub fn weighted_modifier(&self) -> ValueModifier {
+        if self.weight == 1.0 {
+            self.modifier
+        } else {
+            self.modifier.scaled_by(self.weight)
+        }
+    }
+
+    /// Returns true if this entry was applied by effect type `E`.
+    #[must_use]
+    pub fn is<E>(&self) -> bool {
+        self.effect == std::any::type_name::<E>()
+    }
+
+    /// Returns true if both entries are the same effect type with the
+    /// [same](ValueModifier::same_effect) modifier and power.
+    #[must_use]
+    pub fn same_effect(&self, other: &Self) -> bool {
+        self.effect == other.effect
+            && self.modifier.same_effect(&other.modifier)
+            && self.power.map(value_bits) == other.power.map(value_bits)
+    }
+}
+
+/// Effects applied to component `C` on this entity.
+///
+/// Inserted on the first tracked application.
+#[derive(Component)]
+pub struct AppliedEffects<C: MutableComponent> {
+    /// Stat value before the first tracked effect was applied
+    pub base: f32,
+    /// Tracked effects in application order
+    pub effects: Vec<AppliedEffect>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: MutableComponent> AppliedEffects<C> {
+    /// Creates an empty tracker for the given base value.
+    #[must_use]
+    pub fn new(base: f32) -> Self {
+        Self {
+            base,
+            effects: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of tracked effects.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns true if no effects are tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Returns the tracked effects applied by effect type `E`.
+    pub fn of_type<E>(&self) -> impl Iterator<Item = &AppliedEffect> {
+        self.effects.iter().filter(|effect| effect.is::<E>())
+    }
+
+    /// Returns true if a tracked entry is the [same effect](AppliedEffect::same_effect)
+    /// as `effect`.
+    #[must_use]
+    pub fn contains_same(&self, effect: &AppliedEffect) -> bool {
+        self.effects
+            .iter()
+            .any(|tracked| tracked.same_effect(effect))
+    }
+
+    /// Replays all tracked modifiers on the base value with the given power.
+    ///
+    /// Entries that applied with their own power replay with it instead, and
+    /// fading entries replay at their current [weight](AppliedEffect::weight).
+    #[must_use]
+    pub fn effective_value(&self, power: f32) -> f32 {
+        self.effects.iter().fold(self.base, |value, effect| {
+            effect
+                .weighted_modifier()
+                .apply_scaled(value, effect.power.unwrap_or(power))
+        })
+    }
+}
+
+/// Records the first tracked effect on an entity.
+///
+/// Queued from the observer when `AppliedEffects<C>` doesn't exist yet. If
+/// another application inserted it in the meantime, the entry is appended.
+pub(crate) fn track_first_effect<C: MutableComponent>(
+    base: f32,
+    effect: AppliedEffect,
+) -> impl FnOnce(EntityWorldMut) {
+    move |mut entity: EntityWorldMut| {
+        if let Some(mut applied) = entity.get_mut::<AppliedEffects<C>>() {
+            applied.effects.push(effect);
+        } else {
+            let mut applied = AppliedEffects::<C>::new(base);
+            applied.effects.push(effect);
+            entity.insert(applied);
+        }
+    }
+}
+
+/// Removes every tracked effect of type `E` from all entities.
+///
+/// Each affected stat is rebuilt from its base with the remaining effects.
+/// Usually triggered through
+/// [`remove_effect_everywhere`](crate::StatusEffectCommandsExt::remove_effect_everywhere).
+#[derive(Event)]
+pub struct RemoveEffectEverywhere<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E> Default for RemoveEffectEverywhere<E> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn remove_effect_everywhere_observer<C: MutableComponent, E: Send + Sync + 'static>(
+    _on: On<RemoveEffectEverywhere<E>>,
+    config: Res<StatusEffectApplication<C>>,
+    mut q: Query<(
+        Entity,
+        &mut C,
+        &mut AppliedEffects<C>,
+        Option<&RebalanceOnRemove<C>>,
+        Option<&mut PercentSum<C>>,
+    )>,
+    mut events: Option<ResMut<Messages<StatusEffectEvent>>>,
+) {
+    for (entity, mut component, mut applied, policy, mut sum) in &mut q {
+        if applied.of_type::<E>().next().is_none() {
+            continue;
+        }
+        let removed: Vec<_> = applied
+            .effects
+            .extract_if(.., |effect| effect.is::<E>())
+            .collect();
+        write_removed(&config, events.as_deref_mut(), entity, &removed);
+        if rebuild_after_removal(
+            &config,
+            component.bypass_change_detection(),
+            &mut applied,
+            policy,
+            sum.as_deref_mut(),
+        ) {
+            component.set_changed();
+        }
+    }
+}
+
+/// Removes the oldest tracked stack of effect type `E` from an entity.
+///
+/// The stat is rebuilt from its base with the remaining effects. Untracked
+/// components can't be rebuilt and are left as they are.
+#[derive(EntityEvent)]
+pub struct RemoveStatusEffect<E: Send + Sync + 'static> {
+    /// The entity to remove the effect from
+    pub entity: Entity,
+    expiry: Option<u64>,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Send + Sync + 'static> RemoveStatusEffect<E> {
+    /// Removes the oldest stack of `E` from `entity`.
+    #[must_use]
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            expiry: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Removes the stack of `E` tagged with `expiry` from `entity` because its
+    /// time ran out.
+    pub(crate) fn expired(entity: Entity, expiry: u64) -> Self {
+        Self {
+            expiry: Some(expiry),
+            ..Self::new(entity)
+        }
+    }
+}
+
+pub(crate) fn remove_status_effect_observer<C: MutableComponent, E: Send + Sync + 'static>(
+    on: On<RemoveStatusEffect<E>>,
+    config: Res<StatusEffectApplication<C>>,
+    mut q: Query<(
+        &mut C,
+        &mut AppliedEffects<C>,
+        Option<&RebalanceOnRemove<C>>,
+        Option<&mut PercentSum<C>>,
+    )>,
+    events: Option<ResMut<Messages<StatusEffectEvent>>>,
+) {
+    let Ok((mut component, mut applied, policy, mut sum)) = q.get_mut(on.entity) else {
+        return;
+    };
+    // An expired stack that was never tracked or is already gone leaves the
+    // other stacks alone
+    let index = match on.expiry {
+        Some(expiry) => applied
+            .effects
+            .iter()
+            .position(|effect| effect.expiry == Some(expiry)),
+        None => applied.effects.iter().position(AppliedEffect::is::<E>),
+    };
+    let Some(index) = index else {
+        return;
+    };
+    let removed = applied.effects.remove(index);
+    if let Some(mut events) = events.filter(|_| config.events) {
+        let activity = EffectActivity::tracked::<C>(on.entity, &removed);
+        events.write(if on.expiry.is_some() {
+            StatusEffectEvent::Expired(activity)
+        } else {
+            StatusEffectEvent::Removed(activity)
+        });
+    }
+    if rebuild_after_removal(
+        &config,
+        component.bypass_change_detection(),
+        &mut applied,
+        policy,
+        sum.as_deref_mut(),
+    ) {
+        component.set_changed();
+    }
+}
+
+/// Writes a [`StatusEffectEvent::Removed`] for each removed entry.
+pub(crate) fn write_removed<C: MutableComponent>(
+    config: &StatusEffectApplication<C>,
+    events: Option<&mut Messages<StatusEffectEvent>>,
+    entity: Entity,
+    removed: &[AppliedEffect],
+) {
+    let Some(events) = events.filter(|_| config.events) else {
+        return;
+    };
+    for effect in removed {
+        events.write(StatusEffectEvent::Removed(EffectActivity::tracked::<C>(
+            entity, effect,
+        )));
+    }
+}
+
+/// Rebuilds a stat from its base with the tracked effects.
+///
+/// Only writes the value if it changed, and returns whether it did. Callers
+/// pass the component through `bypass_change_detection` and mark it changed
+/// on `true`, so change detection stays quiet when the value holds.
+pub(crate) fn rebuild<C: MutableComponent>(
+    config: &StatusEffectApplication<C>,
+    component: &mut C,
+    applied: &AppliedEffects<C>,
+    sum: Option<&mut PercentSum<C>>,
+) -> bool {
+    let value = match sum {
+        Some(sum) => config.replay_into(applied, sum),
+        None => config.replay(applied),
+    };
+    if config.stat_value(component) == Some(value) {
+        return false;
+    }
+    config.set_stat_value(component, value);
+    true
+}
+
+/// Removes every tracked effect on `C` from `entity` and sets the stat back
+/// to its base.
+///
+/// The sum of summed percents is reset with them.
+pub(crate) fn reset_stat<C: MutableComponent>(world: &mut World, entity: Entity) {
+    let Some(config) = world.get_resource::<StatusEffectApplication<C>>().cloned() else {
+        return;
+    };
+    let Ok((mut component, mut applied, mut sum)) = world
+        .query::<(&mut C, &mut AppliedEffects<C>, Option<&mut PercentSum<C>>)>()
+        .get_mut(world, entity)
+    else {
+        return;
+    };
+    let removed = std::mem::take(&mut applied.effects);
+    if rebuild(
+        &config,
+        component.bypass_change_detection(),
+        &applied,
+        sum.as_deref_mut(),
+    ) {
+        component.set_changed();
+    }
+    let mut events = world.get_resource_mut::<Messages<StatusEffectEvent>>();
+    write_removed(&config, events.as_deref_mut(), entity, &removed);
+}
+
+/// Resets every tracked stat on `entity` to its base, across all registered
+/// components.
+pub(crate) fn reset_all_stats(world: &mut World, entity: Entity) {
+    let Some(registry) = world.get_resource::<StatusEffectRegistry>() else {
+        return;
+    };
+    let resets: Vec<_> = registry.resets().collect();
+    for reset in resets {
+        reset(world, entity);
+    }
+}
+
+/// Removes `AppliedEffects<C>` once every effect is gone and the stat is
+/// back at its base.
+///
+/// An empty tracker whose stat moved through untracked changes is kept, since
+/// removing it would lose the base.
+fn collect_empty_applied_effects<C: MutableComponent>(
+    config: Res<StatusEffectApplication<C>>,
+    q: Query<(Entity, &C, &AppliedEffects<C>), Or<(Changed<AppliedEffects<C>>, Changed<C>)>>,
+    mut commands: Commands,
+) {
+    for (entity, component, applied) in &q {
+        let at_base = config
+            .stat_value(component)
+            .is_some_and(|value| (value - applied.base).abs() <= EPSILON);
+        if applied.is_empty() && at_base {
+            commands.entity(entity).try_remove::<AppliedEffects<C>>();
+        }
+    }
+}
+
+/// Cleans up empty trackers for component `C` once per app.
+pub(crate) struct TrackingGcPlugin<C: MutableComponent>(PhantomData<C>);
+
+impl<C: MutableComponent> Default for TrackingGcPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: MutableComponent> Plugin for TrackingGcPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, collect_empty_applied_effects::<C>);
+    }
+}
+
+/// Rebuilds a stat after tracked effects were removed, rebalancing the rest
+/// first if the entity has a [`RebalanceOnRemove<C>`] policy.
+pub(crate) fn rebuild_after_removal<C: MutableComponent>(
+    config: &StatusEffectApplication<C>,
+    component: &mut C,
+    applied: &mut AppliedEffects<C>,
+    policy: Option<&RebalanceOnRemove<C>>,
+    sum: Option<&mut PercentSum<C>>,
+) -> bool {
+    if let Some(policy) = policy {
+        rebalance::rebalance(applied, policy);
+    }
+    rebuild(config, component, applied, sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, Default)]
+    struct Speed(f32);
+
+    impl StatValue for Speed {
+        fn stat_value(&self) -> f32 {
+            self.0
+        }
+
+        fn set_stat_value(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct SpeedEffect(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for SpeedEffect {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    fn tracked_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        app
+    }
+
+    #[test]
+    fn tracked_application_records_base_and_effects() {
+        let mut app = tracked_app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        for modifier in [ValueModifier::Percent(50.0), ValueModifier::Val(30.0)] {
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: SpeedEffect(modifier),
+                entity,
+            });
+            app.update();
+        }
+
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.base, 100.0);
+        assert_eq!(applied.len(), 2);
+        assert_eq!(applied.of_type::<SpeedEffect>().count(), 2);
+        assert_eq!(applied.effects[0].modifier, ValueModifier::Percent(50.0));
+
+        // Replaying from base matches the live value
+        let speed = app.world().get::<Speed>(entity).unwrap().0;
+        assert!((applied.effective_value(scaling::SQRT) - speed).abs() < 0.001);
+    }
+
+    fn rebuilt_after_many_effects() -> (u32, u32, Vec<ValueModifier>) {
+        let mut app = tracked_app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        for step in 0..40u8 {
+            let modifier = match step % 3 {
+                0 => ValueModifier::Val(f32::from(step)),
+                1 => ValueModifier::Percent(f32::from(step) - 20.0),
+                _ => ValueModifier::Val(-f32::from(step) / 2.0),
+            };
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: SpeedEffect(modifier),
+                entity,
+            });
+        }
+        app.update();
+
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        let speed = app.world().get::<Speed>(entity).unwrap().0;
+        let modifiers = applied
+            .effects
+            .iter()
+            .map(|effect| effect.modifier)
+            .collect();
+        (
+            speed.to_bits(),
+            applied.effective_value(scaling::SQRT).to_bits(),
+            modifiers,
+        )
+    }
+
+    #[test]
+    fn recompute_is_deterministic_across_runs() {
+        let first = rebuilt_after_many_effects();
+        assert_eq!(first.2.len(), 40);
+        assert_eq!(first.2[1], ValueModifier::Percent(-19.0));
+        for _ in 0..5 {
+            assert_eq!(rebuilt_after_many_effects(), first);
+        }
+    }
+
+    #[test]
+    fn first_applications_in_same_flush_share_one_base() {
+        let mut app = tracked_app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        for _ in 0..3 {
+            app.world_mut().commands().trigger(ApplyStatusEffect {
+                effect: SpeedEffect(ValueModifier::Val(10.0)),
+                entity,
+            });
+        }
+        app.update();
+
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.base, 100.0);
+        assert_eq!(applied.len(), 3);
+    }
+
+    #[test]
+    fn removal_restores_pre_effect_value_under_sqrt() {
+        let mut app = tracked_app();
+        let entity = app.world_mut().spawn(Speed(40.0)).id();
+        let bystander = app.world_mut().spawn(Speed(40.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(30.0)));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 50.0);
+
+        app.world_mut()
+            .commands()
+            .trigger(RemoveStatusEffect::<SpeedEffect>::new(entity));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 40.0);
+
+        // Removing what isn't applied does nothing
+        for target in [entity, bystander] {
+            app.world_mut()
+                .commands()
+                .trigger(RemoveStatusEffect::<SpeedEffect>::new(target));
+        }
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 40.0);
+        assert_eq!(app.world().get::<Speed>(bystander).unwrap().0, 40.0);
+    }
+
+    #[test]
+    fn untracked_components_are_not_tracked() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::default());
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+
+        app.world_mut().trigger(ApplyStatusEffect {
+            effect: SpeedEffect(ValueModifier::Val(10.0)),
+            entity,
+        });
+        app.update();
+
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_none());
+    }
+
+    #[derive(Event, Clone, Copy)]
+    struct Haste(ValueModifier);
+
+    impl StatusEffectApplicator<Speed> for Haste {
+        fn modifier(&self) -> ValueModifier {
+            self.0
+        }
+
+        fn apply(&self, component: &mut Speed, power: f32) {
+            component.0 = self.0.apply_scaled(component.0, power);
+        }
+    }
+
+    #[test]
+    fn remove_effect_everywhere_rebuilds_each_entity() {
+        let mut app = tracked_app();
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(
+            StatusEffectApplication::sqrt().tracked(),
+        ));
+        let hasted: Vec<Entity> = [100.0, 80.0, 60.0]
+            .into_iter()
+            .map(|speed| app.world_mut().spawn(Speed(speed)).id())
+            .collect();
+        let bystander = app.world_mut().spawn(Speed(50.0)).id();
+        app.update();
+
+        for &entity in &hasted {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, Haste(ValueModifier::Percent(30.0)));
+        }
+        app.world_mut()
+            .commands()
+            .apply_effect(hasted[0], SpeedEffect(ValueModifier::Val(20.0)));
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .remove_effect_everywhere::<Haste>();
+        app.update();
+
+        for (&entity, base) in hasted.iter().zip([100.0, 80.0, 60.0]) {
+            let speed = app.world().get::<Speed>(entity).unwrap().0;
+            // Emptied trackers are collected once the stat is back at base
+            let Some(applied) = app.world().get::<AppliedEffects<Speed>>(entity) else {
+                assert_ne!(entity, hasted[0]);
+                assert!((speed - base).abs() < 0.001);
+                continue;
+            };
+            assert_eq!(applied.of_type::<Haste>().count(), 0);
+            let expected = applied.effective_value(scaling::SQRT);
+            assert!((speed - expected).abs() < 0.001);
+        }
+        // The other effect type survives
+        let speed = app.world().get::<Speed>(hasted[0]).unwrap().0;
+        assert!(
+            (speed - ValueModifier::Val(20.0).apply_scaled(100.0, scaling::SQRT)).abs() < 0.001
+        );
+        assert_eq!(app.world().get::<Speed>(bystander).unwrap().0, 50.0);
+    }
+
+    #[test]
+    fn rebuild_clamps_after_each_entry_like_live_applications() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let config = StatusEffectApplication::linear()
+            .tracked()
+            .clamped(0.0, 100.0);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            config.clone(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Speed, Haste>::new(config));
+        let entity = app.world_mut().spawn(Speed(90.0)).id();
+        app.update();
+
+        // +20 clamps to 100 live, so -20 lands on 80
+        for val in [20.0, -20.0] {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, SpeedEffect(ValueModifier::Val(val)));
+        }
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, Haste(ValueModifier::Val(5.0)));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 85.0);
+
+        app.world_mut()
+            .commands()
+            .trigger(RemoveStatusEffect::<Haste>::new(entity));
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 80.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct SpeedChanges(usize);
+
+    fn count_speed_changes(q: Query<(), Changed<Speed>>, mut changes: ResMut<SpeedChanges>) {
+        changes.0 += q.iter().count();
+    }
+
+    #[test]
+    fn speed_is_marked_changed_only_when_its_value_moves() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Speed, SpeedEffect>::new(
+            StatusEffectApplication::linear().tracked(),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.init_resource::<SpeedChanges>();
+        app.add_systems(Update, count_speed_changes);
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+        let changes = |app: &App| app.world().resource::<SpeedChanges>().0;
+        assert_eq!(changes(&app), 1);
+
+        let apply = |app: &mut App, modifier| {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, SpeedEffect(modifier));
+            app.update();
+            app.update();
+        };
+
+        // A no-op modifier leaves the value and the change tick alone
+        apply(&mut app, ValueModifier::Val(0.0));
+        assert_eq!(changes(&app), 1);
+
+        apply(&mut app, ValueModifier::Val(10.0));
+        assert_eq!(changes(&app), 2);
+
+        // Removing rebuilds 110 back to base
+        app.world_mut()
+            .commands()
+            .remove_effect_everywhere::<SpeedEffect>();
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+        assert_eq!(changes(&app), 3);
+
+        // Nothing left to remove
+        app.world_mut()
+            .commands()
+            .remove_effect_everywhere::<SpeedEffect>();
+        app.update();
+        assert_eq!(changes(&app), 3);
+
+        // Removing a stack that contributed nothing leaves the tick alone
+        apply(&mut app, ValueModifier::Val(0.0));
+        apply(&mut app, ValueModifier::Val(10.0));
+        assert_eq!(changes(&app), 4);
+        app.world_mut()
+            .commands()
+            .trigger(RemoveStatusEffect::<SpeedEffect>::new(entity));
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+        assert_eq!(changes(&app), 4);
+
+        // So does such a stack expiring
+        app.world_mut().commands().apply_temporary(
+            entity,
+            SpeedEffect(ValueModifier::Val(0.0)),
+            0.5,
+        );
+        for _ in 0..4 {
+            app.update();
+        }
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 110.0);
+        assert_eq!(changes(&app), 4);
+    }
+
+    #[test]
+    fn contains_same_detects_duplicate_stacks() {
+        let mut app = tracked_app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(10.0)));
+        app.update();
+
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        let same = AppliedEffect::of::<SpeedEffect>(ValueModifier::Val(10.0));
+        assert!(applied.contains_same(&same));
+        assert!(!applied.contains_same(&AppliedEffect::of::<Speed>(ValueModifier::Val(10.0))));
+        assert!(
+            !applied.contains_same(&AppliedEffect::of::<SpeedEffect>(ValueModifier::Val(10.5)))
+        );
+        let overridden = AppliedEffect {
+            power: Some(scaling::SQUARE),
+            ..same
+        };
+        assert!(!applied.contains_same(&overridden));
+    }
+
+    #[test]
+    fn empty_trackers_are_collected_only_at_base() {
+        let mut app = tracked_app();
+        let entity = app.world_mut().spawn(Speed(100.0)).id();
+        let apply = |app: &mut App| {
+            app.world_mut()
+                .commands()
+                .apply_effect(entity, SpeedEffect(ValueModifier::Val(10.0)));
+            app.update();
+        };
+        let remove = |app: &mut App| {
+            app.world_mut()
+                .commands()
+                .trigger(RemoveStatusEffect::<SpeedEffect>::new(entity));
+            app.update();
+        };
+
+        apply(&mut app);
+        apply(&mut app);
+        remove(&mut app);
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_some());
+        remove(&mut app);
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_none());
+
+        // An untracked change moved the stat off its base: keep the tracker
+        apply(&mut app);
+        app.world_mut().get_mut::<Speed>(entity).unwrap().0 += 5.0;
+        app.world_mut()
+            .get_mut::<AppliedEffects<Speed>>(entity)
+            .unwrap()
+            .effects
+            .clear();
+        app.update();
+        let applied = app.world().get::<AppliedEffects<Speed>>(entity).unwrap();
+        assert_eq!(applied.base, 100.0);
+    }
+
+    #[test]
+    fn reset_all_stats_resets_every_tracked_component() {
+        #[derive(Component, Default)]
+        struct Armor(f32);
+
+        impl StatValue for Armor {
+            fn stat_value(&self) -> f32 {
+                self.0
+            }
+
+            fn set_stat_value(&mut self, value: f32) {
+                self.0 = value;
+            }
+        }
+
+        #[derive(Event, Clone, Copy)]
+        struct ArmorEffect(ValueModifier);
+
+        impl StatusEffectApplicator<Armor> for ArmorEffect {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+
+            fn apply(&self, component: &mut Armor, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
+        }
+
+        #[derive(Component, Default)]
+        struct Gold(f32);
+
+        #[derive(Event, Clone, Copy)]
+        struct Loot(ValueModifier);
+
+        impl StatusEffectApplicator<Gold> for Loot {
+            fn modifier(&self) -> ValueModifier {
+                self.0
+            }
+
+            fn apply(&self, component: &mut Gold, power: f32) {
+                component.0 = self.0.apply_scaled(component.0, power);
+            }
+        }
+
+        let mut app = tracked_app();
+        app.add_plugins(StatusEffectPlugin::<Armor, ArmorEffect>::new(
+            StatusEffectApplication::linear()
+                .tracked()
+                .additive_percents(),
+        ));
+        app.add_plugins(StatusEffectPlugin::<Gold, Loot>::default());
+        let entity = app
+            .world_mut()
+            .spawn((Speed(100.0), Armor(20.0), Gold(0.0)))
+            .id();
+        let other = app.world_mut().spawn(Speed(100.0)).id();
+        app.update();
+
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, SpeedEffect(ValueModifier::Percent(50.0)))
+            .apply_effect(entity, SpeedEffect(ValueModifier::Val(30.0)))
+            .apply_effect(entity, ArmorEffect(ValueModifier::Val(15.0)))
+            .apply_effect(entity, ArmorEffect(ValueModifier::Percent(20.0)))
+            .apply_effect(entity, Loot(ValueModifier::Val(50.0)))
+            .apply_effect(other, SpeedEffect(ValueModifier::Val(30.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 42.0);
+
+        app.world_mut().commands().reset_all_stats(entity);
+        app.update();
+        assert_eq!(app.world().get::<Speed>(entity).unwrap().0, 100.0);
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 20.0);
+        assert!(app.world().get::<AppliedEffects<Speed>>(entity).is_none());
+        assert!(app.world().get::<AppliedEffects<Armor>>(entity).is_none());
+        // Untracked stats and other entities are untouched
+        assert_eq!(app.world().get::<Gold>(entity).unwrap().0, 50.0);
+        assert!(app.world().get::<Speed>(other).unwrap().0 > 100.0);
+
+        // Percents still sum after the reset
+        app.world_mut()
+            .commands()
+            .apply_effect(entity, ArmorEffect(ValueModifier::Percent(50.0)))
+            .apply_effect(entity, ArmorEffect(ValueModifier::Percent(30.0)));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(entity).unwrap().0, 36.0);
+    }
+}
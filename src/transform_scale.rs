@@ -0,0 +1,117 @@
+//! Prebuilt applicator for [`Transform`] scale.
+//!
+//! `Transform` belongs to Bevy, so it can't implement [`StatValue`](crate::StatValue)
+//! here and its scale is a vector rather than a single stat.
+//! [`TransformScaleApplicator`] applies a [`ValueModifier`] to one chosen axis,
+//! or all of them, for "grow" or "shrink" effects.
+
+use bevy::prelude::*;
+
+use crate::{StatusEffectApplicator, ValueModifier};
+
+/// Smallest scale an axis is clamped to, so shrinking never produces a
+/// zero or mirrored transform.
+pub const MIN_SCALE: f32 = 1e-3;
+
+/// Axis of [`Transform::scale`] an effect changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleAxis {
+    /// The x axis
+    X,
+    /// The y axis
+    Y,
+    /// The z axis
+    Z,
+    /// Every axis, each modified on its own
+    All,
+}
+
+/// Applies a modifier to an axis of [`Transform::scale`], clamped to at least
+/// [`MIN_SCALE`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use msg_status_effect::prelude::*;
+///
+/// fn app(app: &mut App) {
+///     app.add_plugins(StatusEffectPlugin::<Transform, TransformScaleApplicator>::default());
+/// }
+///
+/// fn enlarge(mut commands: Commands, entity: Entity) {
+///     commands.apply_effect(
+///         entity,
+///         TransformScaleApplicator::new(ScaleAxis::All, ValueModifier::Percent(50.0)),
+///     );
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct TransformScaleApplicator {
+    /// Axis to modify
+    pub axis: ScaleAxis,
+    /// Modifier applied to the axis
+    pub modifier: ValueModifier,
+}
+
+impl TransformScaleApplicator {
+    /// Applies `modifier` to `axis`.
+    #[must_use]
+    pub fn new(axis: ScaleAxis, modifier: ValueModifier) -> Self {
+        Self { axis, modifier }
+    }
+}
+
+impl StatusEffectApplicator<Transform> for TransformScaleApplicator {
+    fn modifier(&self) -> ValueModifier {
+        self.modifier
+    }
+
+    fn apply(&self, component: &mut Transform, power: f32) {
+        let scale = |value: f32| self.modifier.apply_scaled(value, power).max(MIN_SCALE);
+        let s = &mut component.scale;
+        match self.axis {
+            ScaleAxis::X => s.x = scale(s.x),
+            ScaleAxis::Y => s.y = scale(s.y),
+            ScaleAxis::Z => s.z = scale(s.z),
+            ScaleAxis::All => *s = Vec3::new(scale(s.x), scale(s.y), scale(s.z)),
+        }
+    }
+
+    fn scaled_by(&self, factor: f32) -> Self {
+        Self::new(self.axis, self.modifier.scaled_by(factor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn scales_chosen_axis_and_never_collapses() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(StatusEffectPlugin::<Transform, TransformScaleApplicator>::default());
+        let entity = app
+            .world_mut()
+            .spawn(Transform::from_scale(Vec3::splat(2.0)))
+            .id();
+
+        app.world_mut().commands().apply_effect(
+            entity,
+            TransformScaleApplicator::new(ScaleAxis::X, ValueModifier::Percent(50.0)),
+        );
+        app.update();
+        let scale = app.world().get::<Transform>(entity).unwrap().scale;
+        assert_eq!(scale, Vec3::new(3.0, 2.0, 2.0));
+
+        app.world_mut().commands().apply_effect(
+            entity,
+            TransformScaleApplicator::new(ScaleAxis::All, ValueModifier::Percent(-100.0)),
+        );
+        app.update();
+        let scale = app.world().get::<Transform>(entity).unwrap().scale;
+        assert_eq!(scale, Vec3::splat(MIN_SCALE));
+    }
+}